@@ -5,7 +5,9 @@
 
 use crate::memory::map::mmio;
 use core::sync::atomic::{AtomicBool, Ordering};
-use driver::{driver_manager, DeviceDriverDescriptor, VIRT16550AUart};
+use driver::{
+    driver_manager, CFIPflash, DeviceDriverDescriptor, VIRT16550AUart,
+};
 
 //--------------------------------------------------------------------------------------------------
 // Global instances
@@ -16,6 +18,10 @@ use driver::{driver_manager, DeviceDriverDescriptor, VIRT16550AUart};
 static VIRT16550A_UART: VIRT16550AUart =
     unsafe { VIRT16550AUart::new(mmio::VIRT16550A_UART_START) };
 
+/// Instantiation of the second pflash bank, the first is left for firmware
+/// Safe as the MMIO is known for this board satisfying the safety warning
+static PFLASH: CFIPflash = unsafe { CFIPflash::new(mmio::PFLASH_START) };
+
 //--------------------------------------------------------------------------------------------------
 // Private Code
 //--------------------------------------------------------------------------------------------------
@@ -34,6 +40,21 @@ fn driver_uart() -> Result<(), &'static str> {
     Ok(())
 }
 
+/// After initialisation register the flash as persistent storage, it cannot
+/// be hidden from the kernel so it never holds a device secret
+fn post_init_pflash() -> Result<(), &'static str> {
+    driver::register_storage(&PFLASH);
+    Ok(())
+}
+
+/// Registers flash driver with driver manager
+fn driver_pflash() -> Result<(), &'static str> {
+    let pflash_descriptor =
+        DeviceDriverDescriptor::new(&PFLASH, Some(post_init_pflash));
+    driver_manager().register_driver(pflash_descriptor);
+    Ok(())
+}
+
 //--------------------------------------------------------------------------------------------------
 // Public Code
 //--------------------------------------------------------------------------------------------------
@@ -52,6 +73,11 @@ pub unsafe fn init() -> Result<(), &'static str> {
         Err(_) => return Err("UART Initialisation fail!")
     }
 
+    match driver_pflash() {
+        Ok(_) => {}
+        Err(_) => return Err("Flash Initialisation fail!")
+    }
+
     INIT_DONE.store(true, Ordering::Relaxed);
     Ok(())
 }
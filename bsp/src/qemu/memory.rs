@@ -13,18 +13,35 @@ pub mod map {
     pub mod mmio {
         /// UART start address
         pub const VIRT16550A_UART_START: usize = 0x1000_0000;
+        /// Second pflash bank start address
+        pub const PFLASH_START: usize = 0x2200_0000;
     }
 
     /// Kernel entry point address
     pub mod kernel {
-        /// Kernel signature
-        pub const SIGNATURE: usize = 0x801F_FF00;
+        /// Start of physical memory
+        pub const RAM: usize = 0x8000_0000;
+        /// Optional signed revocation update
+        pub const REVOCATION: usize = 0x801F_E000;
+        /// Signed image header
+        pub const SIGNATURE: usize = 0x801F_F000;
         /// Kernel entry point
         pub const KERNEL: usize = 0x8020_0000;
         /// Kernel dtb
         pub const DTB: usize = 0x84A0_0000;
+        /// DICE handover record written for the kernel
+        pub const DICE: usize = 0x84FE_F000;
+        /// DICE handover length
+        pub const DICE_LEN: usize = 0x1000;
+        /// Measured boot event log, the top of the dtb's space
+        pub const EVENT_LOG: usize = 0x84FF_0000;
+        /// Measured boot event log length
+        pub const EVENT_LOG_LEN: usize = 0x1_0000;
         /// Kernel ramfs
         pub const RAMFS: usize = 0x8500_0000;
+        /// End of physical memory on the smallest configuration the board is
+        /// run with, the ramfs must end below it
+        pub const RAM_END: usize = 0x9000_0000;
         /// Kernel HART
         pub const HART: usize = 0;
     }
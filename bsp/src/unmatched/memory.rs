@@ -17,14 +17,29 @@ pub mod map {
 
     /// Kernel entry point address
     pub mod kernel {
-        /// Kernel signature
-        pub const SIGNATURE: usize = 0x401F_FF00;
+        /// Start of physical memory
+        pub const RAM: usize = 0x4000_0000;
+        /// Optional signed revocation update
+        pub const REVOCATION: usize = 0x401F_E000;
+        /// Signed image header
+        pub const SIGNATURE: usize = 0x401F_F000;
         /// Kernel entry point
         pub const KERNEL: usize = 0x4020_0000;
         /// Kernel dtb
         pub const DTB: usize = 0x44A0_0000;
+        /// DICE handover record written for the kernel
+        pub const DICE: usize = 0x44FE_F000;
+        /// DICE handover length
+        pub const DICE_LEN: usize = 0x1000;
+        /// Measured boot event log, the top of the dtb's space
+        pub const EVENT_LOG: usize = 0x44FF_0000;
+        /// Measured boot event log length
+        pub const EVENT_LOG_LEN: usize = 0x1_0000;
         /// Kernel ramfs
         pub const RAMFS: usize = 0x4500_0000;
+        /// End of physical memory on the smallest configuration the board is
+        /// run with, the ramfs must end below it
+        pub const RAM_END: usize = 0x4800_0000;
         /// Kernel HART
         pub const HART: usize = 1;
     }
@@ -0,0 +1,169 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! CFI parallel flash driver.
+//!
+//! Drives the Intel command set flash QEMU virt exposes as `pflash`. Only the
+//! first erase block from the start address is used, it is treated as a
+//! single record which is erased and reprogrammed as a whole.
+use synchronisation::interface::Mutex;
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+// Intel command set
+const CMD_READ_ARRAY: u32 = 0xFF;
+const CMD_READ_STATUS: u32 = 0x70;
+const CMD_CLEAR_STATUS: u32 = 0x50;
+const CMD_PROGRAM: u32 = 0x40;
+const CMD_BLOCK_ERASE: u32 = 0x20;
+const CMD_CONFIRM: u32 = 0xD0;
+
+// Status register
+const STATUS_READY: u32 = 1 << 7;
+const STATUS_ERASE_ERROR: u32 = 1 << 5;
+const STATUS_PROGRAM_ERROR: u32 = 1 << 4;
+const STATUS_LOCKED: u32 = 1 << 1;
+
+// QEMU virt flash is four bytes wide with 256 KiB erase blocks
+const WIDTH: usize = 4;
+const BLOCK_SIZE: usize = 0x4_0000;
+
+struct CFIPflashInner {
+    start_addr: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Representation of the flash.
+pub struct CFIPflash {
+    inner: synchronisation::NullLock<CFIPflashInner>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+impl CFIPflashInner {
+    pub const unsafe fn new(mmio_start_addr: usize) -> Self {
+        Self {
+            start_addr: mmio_start_addr,
+        }
+    }
+
+    fn write_word(&self, offset: usize, value: u32) {
+        // The address is defined by the MMIO and offsets are bounded by the
+        // caller to the first block
+        unsafe {
+            core::ptr::write_volatile(
+                (self.start_addr + offset) as *mut u32,
+                value,
+            )
+        }
+    }
+
+    fn read_word(&self, offset: usize) -> u32 {
+        // The address is defined by the MMIO and offsets are bounded by the
+        // caller to the first block
+        unsafe {
+            core::ptr::read_volatile((self.start_addr + offset) as *const u32)
+        }
+    }
+
+    /// Busy loops until the flash is ready then checks for `errors`
+    fn wait(&self, errors: u32) -> Result<(), &'static str> {
+        self.write_word(0, CMD_READ_STATUS);
+        let mut status = self.read_word(0);
+        while status & STATUS_READY == 0 {
+            riscv64::nop();
+            status = self.read_word(0);
+        }
+        self.write_word(0, CMD_CLEAR_STATUS);
+        self.write_word(0, CMD_READ_ARRAY);
+
+        if status & STATUS_LOCKED != 0 {
+            Err("Flash block locked")
+        } else if status & errors != 0 {
+            Err("Flash operation failed")
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read(&self, buf: &mut [u8]) -> Result<(), &'static str> {
+        if buf.len() > BLOCK_SIZE {
+            return Err("Read exceeds flash block");
+        }
+        self.write_word(0, CMD_READ_ARRAY);
+        for (i, chunk) in buf.chunks_mut(WIDTH).enumerate() {
+            let word = self.read_word(i * WIDTH).to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+        Ok(())
+    }
+
+    fn write(&self, data: &[u8]) -> Result<(), &'static str> {
+        if data.len() > BLOCK_SIZE || !data.len().is_multiple_of(WIDTH) {
+            return Err("Write does not fit flash block");
+        }
+        self.write_word(0, CMD_BLOCK_ERASE);
+        self.write_word(0, CMD_CONFIRM);
+        self.wait(STATUS_ERASE_ERROR)?;
+
+        for (i, chunk) in data.chunks(WIDTH).enumerate() {
+            let mut word = [0; WIDTH];
+            word.copy_from_slice(chunk);
+            self.write_word(i * WIDTH, CMD_PROGRAM);
+            self.write_word(i * WIDTH, u32::from_le_bytes(word));
+            self.wait(STATUS_PROGRAM_ERROR)?;
+        }
+        Ok(())
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Implements struct for flash
+impl CFIPflash {
+    /// Driver friendly name
+    pub const NAME: &'static str = "CFI (Parallel Flash)";
+
+    /// Instantiates new flash driver with given address
+    /// # Safety
+    /// Caller must ensure mmio start address is valid for the target hardware
+    pub const unsafe fn new(mmio_start_addr: usize) -> Self {
+        Self {
+            inner: synchronisation::NullLock::new(CFIPflashInner::new(
+                mmio_start_addr,
+            )),
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+// OS Interface Code
+//------------------------------------------------------------------------------
+
+/// Implementes DeviceDriver trait for flash
+impl super::interface::DeviceDriver for CFIPflash {
+    /// Returns a reference to the driver's friendly name
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+}
+
+impl super::interface::Storage for CFIPflash {
+    /// Reads the start of the record guarded by mutex
+    fn read(&self, buf: &mut [u8]) -> Result<(), &'static str> {
+        self.inner.lock(|inner| inner.read(buf))
+    }
+
+    /// Replaces the record guarded by mutex
+    fn write(&self, data: &[u8]) -> Result<(), &'static str> {
+        self.inner.lock(|inner| inner.write(data))
+    }
+}
@@ -17,6 +17,18 @@ mod virt16550_a_uart;
 #[cfg(feature = "qemu_vector")]
 pub use virt16550_a_uart::*;
 
+#[cfg(feature = "qemu")]
+mod cfi_pflash;
+
+#[cfg(feature = "qemu")]
+pub use cfi_pflash::*;
+
+#[cfg(feature = "qemu_vector")]
+mod cfi_pflash;
+
+#[cfg(feature = "qemu_vector")]
+pub use cfi_pflash::*;
+
 #[cfg(feature = "visionfive")]
 mod dw8250_a_uart;
 
@@ -61,6 +73,25 @@ pub mod interface {
             Ok(())
         }
     }
+
+    /// Small record which survives a reset
+    pub trait Storage {
+        /// Fills `buf` from the start of the record
+        fn read(&self, buf: &mut [u8]) -> Result<(), &'static str>;
+
+        /// Replaces the record with `data`
+        fn write(&self, data: &[u8]) -> Result<(), &'static str>;
+    }
+
+    /// Secret unique to the device, provisioned once and only ever read
+    pub trait DeviceSecret {
+        /// Fills `buf` from the start of the secret's record
+        fn read(&self, buf: &mut [u8]) -> Result<(), &'static str>;
+
+        /// Hides the secret from everything that runs after SentinelBoot
+        /// until the next reset
+        fn lock(&self) -> Result<(), &'static str>;
+    }
 }
 
 /// Function pointer for post initialisation
@@ -87,6 +118,14 @@ pub struct DriverManager {
 
 static DRIVER_MANAGER: DriverManager = DriverManager::new();
 
+static CUR_STORAGE: NullLock<
+    Option<&'static (dyn interface::Storage + Sync)>,
+> = NullLock::new(None);
+
+static CUR_DEVICE_SECRET: NullLock<
+    Option<&'static (dyn interface::DeviceSecret + Sync)>,
+> = NullLock::new(None);
+
 //--------------------------------------------------------------------------------------------------
 // Private Code
 //--------------------------------------------------------------------------------------------------
@@ -124,6 +163,31 @@ pub fn driver_manager() -> &'static DriverManager {
     &DRIVER_MANAGER
 }
 
+/// Register the board's persistent storage
+pub fn register_storage(
+    new_storage: &'static (dyn interface::Storage + Sync),
+) {
+    CUR_STORAGE.lock(|storage| *storage = Some(new_storage));
+}
+
+/// Return the board's persistent storage, if it has any
+pub fn storage() -> Option<&'static (dyn interface::Storage + Sync)> {
+    CUR_STORAGE.lock(|storage| *storage)
+}
+
+/// Register the board's device secret storage
+pub fn register_device_secret(
+    new_secret: &'static (dyn interface::DeviceSecret + Sync),
+) {
+    CUR_DEVICE_SECRET.lock(|secret| *secret = Some(new_secret));
+}
+
+/// Return the board's device secret storage, if it has any
+pub fn device_secret() -> Option<&'static (dyn interface::DeviceSecret + Sync)>
+{
+    CUR_DEVICE_SECRET.lock(|secret| *secret)
+}
+
 impl DriverManager {
     /// Instantiates an empty manager with mutex
     pub const fn new() -> Self {
@@ -1,6 +1,39 @@
 # Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
 
-import pyfiglet, subprocess
+import hashlib, json, os, pyfiglet, subprocess
+
+KEYRING = "./tftp/keyring.json"
+REVOKED = "./tftp/revoked.json"
+# Optional, from tftp/generate_payload_key.py
+PAYLOAD_KEY = "./tftp/payload_key"
+
+# Must match src/verification/keyring.rs
+KEY_USAGES = {
+    "image": "crate::verification::keyring::KEY_USAGE_IMAGE",
+    "revocation": "crate::verification::keyring::KEY_USAGE_REVOCATION",
+    "certificate": "crate::verification::keyring::KEY_USAGE_CERTIFICATE",
+}
+
+# Must match SignatureAlgorithm in src/verification/header.rs
+SIG_ALGS = {
+    "ed25519": "crate::verification::keyring::SignatureAlgorithm::Ed25519",
+    "p256": "crate::verification::keyring::SignatureAlgorithm::EcdsaP256Sha256",
+    "rsa-pkcs1v15": "crate::verification::keyring::SignatureAlgorithm::"
+    "RsaPkcs1v15Sha256",
+    "rsa-pss": "crate::verification::keyring::SignatureAlgorithm::RsaPssSha256",
+    "hss": "crate::verification::keyring::SignatureAlgorithm::HssSha256",
+    "ml-dsa-65": "crate::verification::keyring::SignatureAlgorithm::MlDsa65",
+    "ed25519+ml-dsa-65": "crate::verification::keyring::SignatureAlgorithm::"
+    "Ed25519MlDsa65",
+}
+# Algorithms told apart by the raw public key length, RSA keys must name one
+KEY_LENS = {
+    32: "ed25519",
+    60: "hss",
+    65: "p256",
+    1952: "ml-dsa-65",
+    1984: "ed25519+ml-dsa-65",
+}
 
 
 def generate_header():
@@ -44,15 +77,65 @@ def generate_version():
     return f'pub const SHA: &str = "{sha}";\n'
 
 
-def add_public_key():
-    file_path = "../tftp/public_key.pem"
-    return f'pub const PUBLIC_KEY: &[u8] = include_bytes!("{file_path}");\n'
+def add_keyring():
+    with open(KEYRING) as file:
+        keyring = json.load(file)
+    text = "pub const KEYRING: &[TrustedKey] = &[\n"
+    ids = set()
+    for entry in keyring:
+        with open(f"./tftp/{entry['key']}", "rb") as file:
+            key = file.read()
+        algorithm = entry.get("algorithm", KEY_LENS.get(len(key)))
+        if algorithm not in SIG_ALGS:
+            raise ValueError(f"no known algorithm for {entry['name']}")
+        key_id = hashlib.sha256(key).digest()[:8]
+        if key_id in ids:
+            raise ValueError(f"duplicate key ID for {entry['name']}")
+        ids.add(key_id)
+        usage = " | ".join(KEY_USAGES[x] for x in entry["usage"]) or "0"
+        text += "\tTrustedKey {\n"
+        text += f'\t\tname: "{entry["name"]}",\n'
+        text += f"\t\tid: [{', '.join(hex(x) for x in key_id)}],\n"
+        text += f"\t\tusage: {usage},\n"
+        text += f"\t\tsig_alg: {SIG_ALGS[algorithm]},\n"
+        text += f'\t\tkey: include_bytes!("../tftp/{entry["key"]}"),\n'
+        text += "\t},\n"
+    text += "];\n"
+    return text
+
+
+def add_revoked():
+    with open(REVOKED) as file:
+        revoked = json.load(file)
+    text = "pub const REVOKED: &[[u8; 32]] = &[\n"
+    for entry in revoked:
+        fingerprint = bytes.fromhex(entry["fingerprint"])
+        if len(fingerprint) != 32:
+            raise ValueError(f"bad fingerprint for {entry['name']}")
+        text += f"\t// {entry['name']}\n"
+        text += f"\t[{', '.join(hex(x) for x in fingerprint)}],\n"
+    text += "];\n"
+    return text
+
+
+def add_payload_key():
+    # Writable so it can be wiped before the kernel runs
+    text = "pub static PAYLOAD_KEY: NullLock<Option<[u8; 32]>> = NullLock::new("
+    if not os.path.exists(PAYLOAD_KEY):
+        return text + "None);\n"
+    if os.path.getsize(PAYLOAD_KEY) != 32:
+        raise ValueError("payload key must be 32 bytes")
+    return text + 'Some(*include_bytes!("../tftp/payload_key")));\n'
 
 
 if __name__ == "__main__":
     with open("./src/helper.rs", "w") as f:
         f.write(generate_header() + "\n")
         f.write("use crate::println;" + "\n")
+        f.write("use crate::verification::keyring::TrustedKey;" + "\n")
+        f.write("use synchronisation::NullLock;" + "\n")
         f.write(generate_boot_logo() + "\n")
         f.write(generate_version())
-        f.write(add_public_key())
+        f.write(add_keyring())
+        f.write(add_revoked())
+        f.write(add_payload_key())
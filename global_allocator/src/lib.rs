@@ -17,21 +17,42 @@ use core::mem::size_of;
 // Private Definitions
 //--------------------------------------------------------------------------------------------------
 
-// extern "C" {
-//     /// Start of HEAP address space
-//     static HEAP_START: usize;
-//     /// Size of HEAP address space
-//     static HEAP_SIZE: usize;
-// }
-
-// Temporary until I can get help with linking ^
-const HEAP_START: usize = bsp::memory::map::kernel::SIGNATURE - 0x80000;
+extern "C" {
+    /// Start of HEAP address space, directly after the stack
+    static _heap_start: u8;
+    /// End of the bootloader's RAM, which stops short of the signed image
+    static _memory_end: u8;
+}
 
-static ALLOC_HEAP_SIZE: usize = 0x100000;
+/// Space at the start of the heap for `Alloc` records
+static ALLOC_HEAP_SIZE: usize = 0x10000;
 static mut CURR_ALLOC_OFFSET: usize = 0x0;
 
-static HEAP_PUBLIC_START: usize = HEAP_START + ALLOC_HEAP_SIZE;
-static HEAP_SIZE: usize = 0x7F7CF38 - ALLOC_HEAP_SIZE;
+/// Every allocation is rounded up to this so addresses stay aligned
+const MIN_ALIGN: usize = size_of::<usize>();
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+fn heap_start() -> usize {
+    // Only the address of the linker symbol is taken
+    unsafe { core::ptr::addr_of!(_heap_start) as usize }
+}
+
+fn heap_public_start() -> usize {
+    heap_start() + ALLOC_HEAP_SIZE
+}
+
+fn heap_end() -> usize {
+    // Only the address of the linker symbol is taken
+    unsafe { core::ptr::addr_of!(_memory_end) as usize }
+}
+
+/// Bytes an Alloc holds for `layout`
+fn alloc_size(layout: &Layout) -> usize {
+    (layout.size() + MIN_ALIGN - 1) & !(MIN_ALIGN - 1)
+}
 
 //--------------------------------------------------------------------------------------------------
 // Public Definitions
@@ -118,8 +139,8 @@ impl Display for Alloc {
 impl Alloc {
     /// Finds the first available Alloc location
     pub fn find_alloc_space() -> *mut Alloc {
-        let mut ptr = HEAP_START;
-        while ptr < (HEAP_PUBLIC_START) {
+        let mut ptr = heap_start();
+        while ptr < heap_public_start() {
             // A reasonable guess it's not used
             if unsafe {
                 core::ptr::read(ptr as *mut u128) == 0
@@ -202,7 +223,7 @@ impl Alloc {
     /// Returns the final address the Alloc controls
     pub fn get_end_address(&self) -> usize {
         if self.get_next().is_none() {
-            return HEAP_PUBLIC_START + HEAP_SIZE;
+            return heap_end();
         }
         // Safe as we just checked the pointer exists
         let next = self.get_next_deref();
@@ -269,11 +290,14 @@ impl Allocator {
     /// Programmer must ensure HEAP space exists and the pointer is correct for
     /// the board.
     pub unsafe fn init() {
+        // Free Alloc records are found by being zero, which RAM is not
+        // guaranteed to be at reset
+        core::ptr::write_bytes(heap_start() as *mut u8, 0, ALLOC_HEAP_SIZE);
         let root_alloc =
-            Alloc::new(AllocFlags::Root, HEAP_PUBLIC_START, None, None);
+            Alloc::new(AllocFlags::Root, heap_public_start(), None, None);
         let temp_alloc = Alloc::new(
             AllocFlags::Free,
-            HEAP_PUBLIC_START,
+            heap_public_start(),
             Some(root_alloc as usize),
             None,
         );
@@ -283,7 +307,7 @@ impl Allocator {
     /// Returns the number of addresses marked taken
     pub fn get_alloc_count() -> usize {
         let mut temp_alloc =
-            Allocator::get_ptr_alloc(HEAP_PUBLIC_START as *mut u8);
+            Allocator::get_ptr_alloc(heap_public_start() as *mut u8);
         let mut count: usize = 0;
         // Checks ensure this is safe
         unsafe {
@@ -306,12 +330,11 @@ impl Allocator {
 
         // Make sure that the address makes sense
         assert!(
-            (ptr as usize) >= HEAP_START
-                && (ptr as usize) < HEAP_START + HEAP_SIZE
+            (ptr as usize) >= heap_start() && (ptr as usize) < heap_end()
         );
 
         // Need to reason about this line but works for now
-        let mut temp_alloc = HEAP_START as *mut Alloc;
+        let mut temp_alloc = heap_start() as *mut Alloc;
         // This is safe due to pointer checks
         unsafe {
             logln!("(get_ptr_alloc) ROOT ALLOC: {}", (*(temp_alloc)));
@@ -339,7 +362,7 @@ impl Allocator {
     /// For all allocations deallocates them and zeroes their memory
     pub fn flush() {
         let mut temp_alloc =
-            Allocator::get_ptr_alloc(HEAP_PUBLIC_START as *mut u8);
+            Allocator::get_ptr_alloc(heap_public_start() as *mut u8);
         unsafe {
             while (*(temp_alloc)).get_next().is_some() {
                 if (*(temp_alloc)).get_flag() == AllocFlags::Allocated {
@@ -368,11 +391,17 @@ unsafe impl GlobalAlloc for Allocator {
         assert!(layout.size() > 0, "Received allocation for 0 bytes!");
         logln!("(alloc) ALLOCATING {} BYTES", layout.size());
 
+        // Alloc start addresses are only ever usize aligned
+        if layout.align() > MIN_ALIGN {
+            return core::ptr::null_mut();
+        }
+
         // Find an alloc with enough bytes which is marked free
         let mut temp_alloc =
-            Allocator::get_ptr_alloc(HEAP_PUBLIC_START as *mut u8);
+            Allocator::get_ptr_alloc(heap_public_start() as *mut u8);
 
-        while ((*(temp_alloc)).get_size() < layout.size())
+        let size = alloc_size(&layout);
+        while ((*(temp_alloc)).get_size() < size)
             | ((*(temp_alloc)).get_flag() != AllocFlags::Free)
             && ((*(temp_alloc)).get_next().is_some())
         {
@@ -382,12 +411,14 @@ unsafe impl GlobalAlloc for Allocator {
         logln!("(alloc) GOT ALLOC: {}", (*(temp_alloc)));
 
         // No memory was available
-        if (*(temp_alloc)).get_flag() == AllocFlags::Allocated {
+        if (*(temp_alloc)).get_flag() != AllocFlags::Free
+            || (*(temp_alloc)).get_size() < size
+        {
             return core::ptr::null_mut();
         }
 
         // Calculate the alloc boundary
-        let new_end = (*(temp_alloc)).get_start_address() + layout.size();
+        let new_end = (*(temp_alloc)).get_start_address() + size;
 
         // Set the Alloc as allocated
         (*(temp_alloc)).set_flag(AllocFlags::Allocated);
@@ -411,7 +442,7 @@ unsafe impl GlobalAlloc for Allocator {
             }
             // Else create a new free Alloc between
             else {
-                if new_end - (*(x)).get_start_address() != 0 {
+                if new_end != (*(x)).get_start_address() {
                     logln!(
                         "(alloc) CREATING ALLOC {:#018x} -> {:#018x}",
                         (*(temp_alloc)).get_start_address(),
@@ -455,7 +486,7 @@ unsafe impl GlobalAlloc for Allocator {
         let mut temp_alloc = Allocator::get_ptr_alloc(ptr);
 
         // Make sure dealloc makes sense
-        assert!((*(temp_alloc)).get_size() == layout.size(), "Alloc size does not match layour size!");
+        assert!((*(temp_alloc)).get_size() == alloc_size(&layout), "Alloc size does not match layour size!");
 
         logln!("(dealloc) GOT ALLOC: {}", (*(temp_alloc)));
 
@@ -531,11 +562,17 @@ unsafe impl GlobalAlloc for Allocator {
         assert!(layout.size() > 0, "Received allocation for 0 bytes!");
         logln!("(alloc_zeroed) ALLOCATING {} BYTES", layout.size());
 
+        // Alloc start addresses are only ever usize aligned
+        if layout.align() > MIN_ALIGN {
+            return core::ptr::null_mut();
+        }
+
         // Find an alloc with enough bytes which is marked free
         let mut temp_alloc =
-            Allocator::get_ptr_alloc(HEAP_PUBLIC_START as *mut u8);
+            Allocator::get_ptr_alloc(heap_public_start() as *mut u8);
 
-        while ((*(temp_alloc)).get_size() < layout.size())
+        let size = alloc_size(&layout);
+        while ((*(temp_alloc)).get_size() < size)
             | ((*(temp_alloc)).get_flag() != AllocFlags::Free)
             && ((*(temp_alloc)).get_next().is_some())
         {
@@ -545,12 +582,14 @@ unsafe impl GlobalAlloc for Allocator {
         logln!("(alloc_zeroed) GOT ALLOC: {}", (*(temp_alloc)));
 
         // No memory was available
-        if (*(temp_alloc)).get_flag() == AllocFlags::Allocated {
+        if (*(temp_alloc)).get_flag() != AllocFlags::Free
+            || (*(temp_alloc)).get_size() < size
+        {
             return core::ptr::null_mut();
         }
 
         // Calculate the alloc boundary
-        let new_end = (*(temp_alloc)).get_start_address() + layout.size();
+        let new_end = (*(temp_alloc)).get_start_address() + size;
 
         logln!(
             "(alloc_zeroed) ADDRESS: {:#018x} -> {:#018x}",
@@ -636,85 +675,31 @@ unsafe impl GlobalAlloc for Allocator {
             new_size
         );
 
-        let temp_alloc = Allocator::get_ptr_alloc(ptr);
-        let old_ptr = (*(temp_alloc)).get_start_address();
-        logln!("(realloc) DEALLOCATING {}", (*(temp_alloc)));
-
-        // Stops dealloc from zeroing
-        (*(temp_alloc)).set_flag(AllocFlags::Free);
-        self.dealloc(ptr, layout);
-
-        // Get a new Alloc
+        // Take the new Alloc while the old one is still held so the two
+        // cannot overlap, then copy across and free the old one which zeroes
+        // it
         let new_layout =
             core::alloc::Layout::from_size_align(new_size, layout.align())
                 .unwrap();
-        let new_alloc = Allocator::get_ptr_alloc(self.alloc(new_layout));
-        logln!("(realloc) NEW ALLOC {}", (*(new_alloc)));
-
-        // Transfer data from old Alloc to new Alloc
-        let offset: usize;
-        logln!(
-            "(realloc) OLD_PTR: {:#018x} - NEW_PTR: {:#018x}",
-            old_ptr,
-            (*(new_alloc)).get_start_address()
-        );
-        let new_start = (*(new_alloc)).get_start_address();
-        if old_ptr >= new_start {
-            offset = old_ptr - new_start;
-            logln!("(realloc) 1 OFFSET: {:#018x}", offset);
-            for address in old_ptr..old_ptr + layout.size() {
-                logln!(
-                    "(realloc) COPYING {:#018x} ({:#02x})-> {:#018x}",
-                    address,
-                    core::ptr::read(address as *mut u8),
-                    address - offset
-                );
-                (*(new_alloc)).set_value(
-                    address - offset,
-                    core::ptr::read(address as *mut u8),
-                );
-            }
-        } else {
-            offset = new_start - old_ptr;
-            logln!("(realloc) 2 OFFSET: {:#018x}", offset);
-            for address in old_ptr..old_ptr + layout.size() {
-                logln!(
-                    "(realloc) COPYING {:#018x} ({:#02x})-> {:#018x}",
-                    address,
-                    core::ptr::read(address as *mut u8),
-                    address + offset
-                );
-                (*(new_alloc)).set_value(
-                    address + offset,
-                    core::ptr::read(address as *mut u8),
-                );
-            }
-        }
-
-        // Zero the old memory addresses
-        if (*(temp_alloc)).get_start_address() + layout.size() < new_start
-            || (*(temp_alloc)).get_start_address() - layout.size()
-                < (*(new_alloc)).get_end_address()
-        {
-            for address in (*(temp_alloc)).get_start_address()
-                ..(*(temp_alloc)).get_start_address() + layout.size()
-            {
-                core::ptr::write_bytes(address as *mut u8, 0, 1);
-                logln!("(realloc) ZEROING {:#018x}", address);
-            }
+        let new_ptr = self.alloc(new_layout);
+        if new_ptr.is_null() {
+            return new_ptr;
         }
+        core::ptr::copy_nonoverlapping(
+            ptr,
+            new_ptr,
+            core::cmp::min(layout.size(), new_size),
+        );
+        self.dealloc(ptr, layout);
 
         logln!(
-            "(realloc) REALLOCATED {} BYTES: {:#018x} -> {:#018x} to {:#018x} \
-             -> {:#018x}",
+            "(realloc) REALLOCATED {} BYTES: {:#018x} to {:#018x}",
             layout.size(),
-            old_ptr,
-            old_ptr,
-            new_start,
-            (*(new_alloc)).get_end_address()
+            ptr as usize,
+            new_ptr as usize
         );
 
-        new_start as *mut u8
+        new_ptr
     }
 }
 
@@ -51,6 +51,11 @@ init_rust_state:
 	li		t0, 0b01 << 11
 	csrw 	sstatus, t0
 
+	# Set sstatus.VS to initial so vector instructions do not trap
+	# Reads back as off when the hart has no vector unit
+	li		t0, 0b01 << 9
+	csrs	sstatus, t0
+
 	# MEPC set to main
 	la		t1, main
 	csrw	sepc, t1
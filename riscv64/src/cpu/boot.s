@@ -33,6 +33,11 @@ init_rust_state:
 	li		t0, 0b11 << 11
 	csrw 	mstatus, t0
 
+	# Set mstatus.VS to initial so vector instructions do not trap
+	# Reads back as off when the hart has no vector unit
+	li		t0, 0b01 << 9
+	csrs	mstatus, t0
+
 	# Do not allow interrupts while running main
 	csrw 	mie, zero
 
@@ -48,3 +48,16 @@ pub fn nop() {
         asm!("nop");
     }
 }
+
+/// Whether the boot code managed to turn on the vector unit, `sstatus.VS`
+/// stays off on harts without one
+/// ```
+/// if vector_enabled() { /* vector instructions will not trap */ }
+/// ```
+pub fn vector_enabled() -> bool {
+    let sstatus: usize;
+    unsafe {
+        asm!("csrr {}, sstatus", out(reg) sstatus);
+    }
+    (sstatus >> 9) & 0b11 != 0
+}
@@ -1,12 +1,12 @@
 #!/bin/bash
 (cd tftp &&
 python3 generate_ed25519_keys.py && \
-gzip --decompress --keep -f Image.gz && \
-python3 hash.py Image && \
+gzip --decompress --keep -f Image.gz rootfs.cpio.gz && \
+python3 hash.py qemu Image qemu.dtb rootfs.cpio && \
 gzip -f Image_signed && rm Image)
 
 (cd tftp &&
 gzip --decompress --keep -f Image-vf2.gz && \
-python3 hash.py Image-vf2 && \
+python3 hash.py visionfive Image-vf2 jh7110-visionfive-v2.dtb rootfs.cpio && \
 mv Image-vf2_signed Image_vf2_signed && \
-gzip -f Image_vf2_signed && rm Image-vf2)
+gzip -f Image_vf2_signed && rm Image-vf2 rootfs.cpio)
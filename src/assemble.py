@@ -1,6 +1,40 @@
 # Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
 
-ops = ["vsha2ms.vv", "vsha2cl.vv", "vsha2ch.vv", "vrev8.v"]
+import sys
+
+ops = [
+    "vsha2ms.vv",
+    "vsha2cl.vv",
+    "vsha2ch.vv",
+    "vrev8.v",
+    "vsm3me.vv",
+    "vsm3c.vi",
+    "vaeskf1.vi",
+    "vaeskf2.vi",
+    "vaesz.vs",
+    "vaesem.vs",
+    "vaesef.vs",
+    "vghsh.vv",
+    "sha256sum0",
+    "sha256sum1",
+    "sha256sig0",
+    "sha256sig1",
+]
+
+# Zvkned .vs forms, funct6 and the vs1 field that selects the operation
+vaes_ops = {
+    "vaesz.vs": ("101001", "00111"),
+    "vaesem.vs": ("101001", "00010"),
+    "vaesef.vs": ("101001", "00011"),
+}
+
+# Zknh funct12, the whole immediate field of the I-type encoding
+scalar_ops = {
+    "sha256sum0": "000100000000",
+    "sha256sum1": "000100000001",
+    "sha256sig0": "000100000010",
+    "sha256sig1": "000100000011",
+}
 
 
 def register_map(reg_mnemonic):
@@ -76,7 +110,10 @@ def register_map(reg_mnemonic):
         raise ValueError("Invalid register mnemonic")
 
 
-with open("./verification.rs", "r") as f:
+# Source holding the commented instructions, e.g. verification/scalar.rs
+path = sys.argv[1] if len(sys.argv) > 1 else "./verification.rs"
+
+with open(path, "r") as f:
     instructions = []
     for line in f.readlines():
         line = line.strip()
@@ -111,6 +148,47 @@ with open("./verification.rs", "r") as f:
             bits[2] = format(register_map(instruction[2]), "05b")
             bits[5] = format(register_map(instruction[1]), "05b")
             processed_instructions.append(bits)
+        elif instruction[0] == "vsm3me.vv":
+            bits = ["100000", "1", "xxxxx", "xxxxx", "010", "xxxxx", "1110111"]
+            bits[2] = format(register_map(instruction[2]), "05b")
+            bits[3] = format(register_map(instruction[3]), "05b")
+            bits[5] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
+        elif instruction[0] == "vsm3c.vi":
+            bits = ["101011", "1", "xxxxx", "xxxxx", "010", "xxxxx", "1110111"]
+            bits[2] = format(register_map(instruction[2]), "05b")
+            bits[3] = format(int(instruction[3]), "05b")
+            bits[5] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
+        elif instruction[0] == "vaeskf1.vi":
+            bits = ["100010", "1", "xxxxx", "xxxxx", "010", "xxxxx", "1110111"]
+            bits[2] = format(register_map(instruction[2]), "05b")
+            bits[3] = format(int(instruction[3]), "05b")
+            bits[5] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
+        elif instruction[0] == "vaeskf2.vi":
+            bits = ["101010", "1", "xxxxx", "xxxxx", "010", "xxxxx", "1110111"]
+            bits[2] = format(register_map(instruction[2]), "05b")
+            bits[3] = format(int(instruction[3]), "05b")
+            bits[5] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
+        elif instruction[0] in vaes_ops:
+            funct6, vs1 = vaes_ops[instruction[0]]
+            bits = [funct6, "1", "xxxxx", vs1, "010", "xxxxx", "1110111"]
+            bits[2] = format(register_map(instruction[2]), "05b")
+            bits[5] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
+        elif instruction[0] == "vghsh.vv":
+            bits = ["101100", "1", "xxxxx", "xxxxx", "010", "xxxxx", "1110111"]
+            bits[2] = format(register_map(instruction[2]), "05b")
+            bits[3] = format(register_map(instruction[3]), "05b")
+            bits[5] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
+        elif instruction[0] in scalar_ops:
+            bits = [scalar_ops[instruction[0]], "xxxxx", "001", "xxxxx", "0010011"]
+            bits[1] = format(register_map(instruction[2]), "05b")
+            bits[3] = format(register_map(instruction[1]), "05b")
+            processed_instructions.append(bits)
 
     with open("output", "w") as f:
         for i, instruction in enumerate(processed_instructions):
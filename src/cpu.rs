@@ -7,6 +7,7 @@
 pub use riscv64;
 
 mod boot;
+pub mod isa;
 
 //--------------------------------------------------------------------------------------------------
 // Architectural Public Reexports
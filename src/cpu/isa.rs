@@ -0,0 +1,198 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Instruction set extensions of the harts.
+//!
+//! `misa` is a machine mode CSR and SentinelBoot runs in supervisor mode
+//! beneath U-Boot, so the extensions are read from the `cpu@` nodes of the
+//! device tree instead: the `riscv,isa-extensions` list where the firmware
+//! provides one and the older `riscv,isa` string, whose single letters mirror
+//! `misa`, otherwise. An extension only counts when every hart has it.
+//!
+//! The device tree has not been verified when this runs. Naming extensions
+//! the harts lack can only halt the boot on an illegal instruction, as each
+//! implementation chosen from these computes the same digests.
+
+use crate::fdt::DeviceTree;
+#[cfg(target_arch = "riscv64")]
+use console::println;
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Extensions that select a crypto implementation
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Extensions {
+    /// Scalar SHA-256 and SHA-512
+    pub zknh: bool,
+    /// Vector
+    pub v: bool,
+    /// Vector SHA-256
+    pub zvknha: bool,
+    /// Vector SHA-256 and SHA-512
+    pub zvknhb: bool,
+    /// Vector SM3
+    pub zvksh: bool,
+    /// Vector AES
+    pub zvkned: bool,
+    /// Vector GHASH
+    pub zvkg: bool,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+impl Extensions {
+    fn insert(&mut self, name: &str) {
+        match name {
+            // Zkn and Zk include Zknh
+            "zknh" | "zkn" | "zk" => self.zknh = true,
+            "v" => self.v = true,
+            "zvknha" => self.zvknha = true,
+            "zvknhb" => self.zvknhb = true,
+            // Zvks and Zvksg include Zvksh
+            "zvksh" | "zvks" | "zvksg" => self.zvksh = true,
+            // Zvkn, Zvknc and Zvkng include Zvkned
+            "zvkned" | "zvkn" | "zvknc" | "zvkng" => self.zvkned = true,
+            "zvkg" => self.zvkg = true,
+            _ => (),
+        }
+        // Zvkng and Zvksg include Zvkg
+        if matches!(name, "zvkng" | "zvksg") {
+            self.zvkg = true;
+        }
+    }
+
+    fn union(self, other: Extensions) -> Extensions {
+        Extensions {
+            zknh: self.zknh || other.zknh,
+            v: self.v || other.v,
+            zvknha: self.zvknha || other.zvknha,
+            zvknhb: self.zvknhb || other.zvknhb,
+            zvksh: self.zvksh || other.zvksh,
+            zvkned: self.zvkned || other.zvkned,
+            zvkg: self.zvkg || other.zvkg,
+        }
+    }
+
+    fn intersection(self, other: Extensions) -> Extensions {
+        Extensions {
+            zknh: self.zknh && other.zknh,
+            v: self.v && other.v,
+            zvknha: self.zvknha && other.zvknha,
+            zvknhb: self.zvknhb && other.zvknhb,
+            zvksh: self.zvksh && other.zvksh,
+            zvkned: self.zvkned && other.zvkned,
+            zvkg: self.zvkg && other.zvkg,
+        }
+    }
+
+    /// Parses a `riscv,isa` string such as `rv64imafdcv_zicsr_zvknhb`
+    fn from_isa_string(isa: &str) -> Extensions {
+        let mut extensions = Extensions::default();
+        let isa = isa.trim_start_matches("rv32").trim_start_matches("rv64");
+        let mut parts = isa.split('_');
+        let letters = parts.next().unwrap_or("");
+        // The first multi-letter extension may follow the letters directly
+        let end = letters.find(['z', 's', 'x']).unwrap_or(letters.len());
+        for (i, _) in letters[..end].char_indices() {
+            extensions.insert(&letters[i..i + 1]);
+        }
+        extensions.insert(&letters[end..]);
+        for part in parts {
+            extensions.insert(part);
+        }
+        extensions
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl Extensions {
+    /// Extensions common to every hart described by `tree`, `None` when it
+    /// describes none
+    pub fn from_device_tree(tree: &DeviceTree) -> Option<Extensions> {
+        let mut common: Option<Extensions> = None;
+        let mut hart: Option<(&str, Extensions)> = None;
+        for property in tree.properties() {
+            if !property.node.starts_with("cpu@") {
+                continue;
+            }
+            let found = match property.name {
+                "riscv,isa" => match property.strings().next() {
+                    Some(x) => Extensions::from_isa_string(x),
+                    None => continue,
+                },
+                "riscv,isa-extensions" => {
+                    let mut extensions = Extensions::default();
+                    for name in property.strings() {
+                        extensions.insert(name);
+                    }
+                    extensions
+                }
+                _ => continue,
+            };
+            hart = match hart {
+                Some((node, x)) if node == property.node => {
+                    Some((node, x.union(found)))
+                }
+                Some((_, x)) => {
+                    common = Some(common.map_or(x, |y| y.intersection(x)));
+                    Some((property.node, found))
+                }
+                None => Some((property.node, found)),
+            };
+        }
+        let (_, last) = hart?;
+        Some(common.map_or(last, |x| x.intersection(last)))
+    }
+
+    /// Extensions of the harts from the device tree U-Boot loaded for the
+    /// kernel, none if there is no readable one, and no vector ones if the
+    /// boot code could not turn on the vector unit
+    pub fn detect() -> Extensions {
+        use bsp::memory::map::kernel::{DICE, DTB};
+        #[allow(unused_mut)]
+        let mut extensions = DeviceTree::load(DTB, DICE - DTB)
+            .and_then(|x| Extensions::from_device_tree(&x))
+            .unwrap_or_default();
+        #[cfg(target_arch = "riscv64")]
+        if extensions.v && !riscv64::vector_enabled() {
+            println!("! -- Vector unit listed but could not be enabled");
+            extensions = Extensions {
+                zknh: extensions.zknh,
+                ..Extensions::default()
+            };
+        }
+        extensions
+    }
+}
+
+impl fmt::Display for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names = [
+            ("zknh", self.zknh),
+            ("v", self.v),
+            ("zvknha", self.zvknha),
+            ("zvknhb", self.zvknhb),
+            ("zvksh", self.zvksh),
+            ("zvkned", self.zvkned),
+            ("zvkg", self.zvkg),
+        ];
+        let mut first = true;
+        for (name, present) in names {
+            if present {
+                write!(f, "{}{}", if first { "" } else { " " }, name)?;
+                first = false;
+            }
+        }
+        if first {
+            write!(f, "none")?;
+        }
+        Ok(())
+    }
+}
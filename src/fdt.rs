@@ -0,0 +1,528 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Flattened device tree reader and writer.
+//!
+//! Walks the structure block of a version 17 devicetree blob as laid out in
+//! the Devicetree Specification, handing out every token, or every property
+//! along with the name of the node holding it. Nothing is copied, both borrow
+//! from the blob. `Builder` goes the other way, writing a new blob a token at
+//! a time so a tree can be copied with nodes and properties added, as
+//! `with_reservation` does to hand regions of memory to the kernel.
+
+use alloc::{format, vec::Vec};
+use core::{ptr, slice, str};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Devicetree blob that passed the header checks
+pub struct DeviceTree<'a> {
+    structure: &'a [u8],
+    strings: &'a [u8],
+    reserve: &'a [u8],
+    boot_cpuid: u32,
+}
+
+/// Structure block token
+pub enum Token<'a> {
+    /// Start of a node, the name includes any unit address
+    BeginNode(&'a str),
+    /// End of the most recently started node
+    EndNode,
+    /// Property of the current node, name and raw value
+    Property(&'a str, &'a [u8]),
+}
+
+/// Iterator over the tokens of a `DeviceTree`, less `FDT_NOP`
+pub struct Tokens<'a> {
+    tree: &'a DeviceTree<'a>,
+    offset: usize,
+}
+
+/// Property of a node
+pub struct Property<'a> {
+    /// Name of the node, including any unit address
+    pub node: &'a str,
+    /// Name of the property
+    pub name: &'a str,
+    /// Raw value
+    pub value: &'a [u8],
+}
+
+/// Iterator over every property of a `DeviceTree` in blob order
+pub struct Properties<'a> {
+    tokens: Tokens<'a>,
+    nodes: [&'a str; MAX_DEPTH],
+    depth: usize,
+}
+
+/// Region kept from the kernel by a `/reserved-memory` child
+pub struct Reservation<'a> {
+    /// Node name, `address` gives the unit address
+    pub name: &'a str,
+    /// Binding of the region, if it has one
+    pub compatible: Option<&'a str>,
+    /// Physical start address
+    pub address: usize,
+    /// Length in bytes
+    pub len: usize,
+}
+
+/// Writes a blob a token at a time
+pub struct Builder {
+    structure: Vec<u8>,
+    strings: Vec<u8>,
+    reserve: Vec<u8>,
+    boot_cpuid: u32,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+const MAGIC: u32 = 0xD00D_FEED;
+const HEADER_LEN: usize = 0x28;
+/// Blob version this reader understands, and any compatible with it
+const VERSION: u32 = 17;
+/// Oldest version blobs from `Builder` are compatible with
+const LAST_COMP_VERSION: u32 = 16;
+/// Nodes nested deeper than this end the walk
+const MAX_DEPTH: usize = 16;
+/// Memory reservation entry, 64 bit address and size
+const RESERVE_ENTRY_LEN: usize = 16;
+
+const FDT_BEGIN_NODE: u32 = 0x1;
+const FDT_END_NODE: u32 = 0x2;
+const FDT_PROP: u32 = 0x3;
+const FDT_NOP: u32 = 0x4;
+const FDT_END: u32 = 0x9;
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+fn read_u32(raw: &[u8], offset: usize) -> Option<u32> {
+    let bytes = raw.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// NUL terminated string at the start of `raw`
+fn read_str(raw: &[u8]) -> Option<&str> {
+    let len = raw.iter().position(|x| *x == 0)?;
+    str::from_utf8(&raw[..len]).ok()
+}
+
+fn align(offset: usize) -> usize {
+    (offset + 3) & !3
+}
+
+/// Memory reservation block at the start of `raw`, up to and including the
+/// empty entry ending it
+fn reserve_block(raw: &[u8]) -> Option<&[u8]> {
+    let mut len: usize = 0;
+    loop {
+        let entry = raw.get(len..len.checked_add(RESERVE_ENTRY_LEN)?)?;
+        len += RESERVE_ENTRY_LEN;
+        if entry.iter().all(|x| *x == 0) {
+            return Some(&raw[..len]);
+        }
+    }
+}
+
+/// `value` as `count` big endian cells, zero extended
+fn cells(value: usize, count: u32) -> Vec<u8> {
+    (0..count)
+        .rev()
+        .flat_map(|x| {
+            let word = (value as u64).checked_shr(32 * x).unwrap_or(0);
+            (word as u32).to_be_bytes()
+        })
+        .collect()
+}
+
+fn read_cells(value: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(value.try_into().ok()?))
+}
+
+/// Node name without any unit address
+fn node_name(name: &str) -> &str {
+    name.split('@').next().unwrap_or(name)
+}
+
+impl Reservation<'_> {
+    /// Writes the node, `address_cells` and `size_cells` being those of
+    /// `/reserved-memory`
+    fn write(
+        &self,
+        builder: &mut Builder,
+        (address_cells, size_cells): (u32, u32),
+    ) {
+        let mut reg = cells(self.address, address_cells);
+        reg.extend(cells(self.len, size_cells));
+        builder.begin_node(&format!("{}@{:x}", self.name, self.address));
+        if let Some(compatible) = self.compatible {
+            let mut value = Vec::from(compatible.as_bytes());
+            value.push(0);
+            builder.property("compatible", &value);
+        }
+        builder.property("reg", &reg);
+        builder.property("no-map", &[]);
+        builder.end_node();
+    }
+}
+
+impl Builder {
+    fn push_u32(&mut self, value: u32) {
+        self.structure.extend_from_slice(&value.to_be_bytes());
+    }
+
+    /// Offset of `name` in the strings block, adding it if not yet there
+    fn string(&mut self, name: &str) -> u32 {
+        let mut offset = 0;
+        while let Some(existing) = read_str(&self.strings[offset..]) {
+            if existing == name {
+                return offset as u32;
+            }
+            offset += existing.len() + 1;
+        }
+        let offset = self.strings.len();
+        self.strings.extend_from_slice(name.as_bytes());
+        self.strings.push(0);
+        offset as u32
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl<'a> DeviceTree<'a> {
+    /// Checks the header of `raw`, which must hold the whole blob
+    pub fn new(raw: &'a [u8]) -> Option<Self> {
+        let field = |index: usize| read_u32(raw, index * 4).map(|x| x as usize);
+        if read_u32(raw, 0)? != MAGIC
+            || field(1)? > raw.len()
+            || field(6)? as u32 > VERSION
+        {
+            return None;
+        }
+        let raw = &raw[..field(1)?];
+        let structure =
+            raw.get(field(2)?..field(2)?.checked_add(field(9)?)?)?;
+        // The walk ends at the first token it cannot read, a structure block
+        // cut short of its end token would pass for a smaller tree
+        if read_u32(structure, structure.len().checked_sub(4)?)? != FDT_END {
+            return None;
+        }
+        let strings = raw.get(field(3)?..field(3)?.checked_add(field(8)?)?)?;
+        let reserve = reserve_block(raw.get(field(4)?..)?)?;
+        Some(DeviceTree {
+            structure,
+            strings,
+            reserve,
+            boot_cpuid: field(7)? as u32,
+        })
+    }
+
+    /// Reads the blob at `address`, which may be no longer than `max_len`
+    pub fn load(address: usize, max_len: usize) -> Option<DeviceTree<'static>> {
+        if max_len < HEADER_LEN {
+            return None;
+        }
+        // We have to form a data structure from the raw pointer as this
+        // pointer is defined by the MMIO we are forced to trust it; however,
+        // the slice is immutable so we can only read it.
+        let header = unsafe { slice::from_raw_parts(address as *const u8, 8) };
+        let len = read_u32(header, 4)? as usize;
+        if len > max_len {
+            return None;
+        }
+        DeviceTree::new(unsafe {
+            slice::from_raw_parts(address as *const u8, len)
+        })
+    }
+
+    /// Every token in the structure block
+    pub fn tokens(&'a self) -> Tokens<'a> {
+        Tokens {
+            tree: self,
+            offset: 0,
+        }
+    }
+
+    /// Every property in the tree
+    pub fn properties(&'a self) -> Properties<'a> {
+        Properties {
+            tokens: self.tokens(),
+            nodes: [""; MAX_DEPTH],
+            depth: 0,
+        }
+    }
+
+    /// Copy of the tree with `reservation` added to `/reserved-memory` and
+    /// the `chosen` properties set in `/chosen`, either node being created if
+    /// missing
+    pub fn with_reservation(
+        &'a self,
+        reservation: &Reservation,
+        chosen: &[(&str, &[u8])],
+    ) -> Vec<u8> {
+        let mut builder = Builder::new(self);
+        // Defaults of the Devicetree Specification
+        let mut root_cells = (2, 1);
+        let mut reserved_cells = root_cells;
+        let mut parent = "";
+        let mut depth: usize = 0;
+        let (mut chosen_found, mut reserved_found) = (false, false);
+        let mut chosen_pending = false;
+
+        for token in self.tokens() {
+            // Properties precede subnodes, so those for /chosen go before its
+            // first child or its end
+            if chosen_pending
+                && depth == 2
+                && !matches!(token, Token::Property(..))
+            {
+                for (name, value) in chosen {
+                    builder.property(name, value);
+                }
+                chosen_pending = false;
+            }
+            match token {
+                Token::BeginNode(name) => {
+                    depth += 1;
+                    if depth == 2 {
+                        parent = node_name(name);
+                        if parent == "chosen" {
+                            chosen_found = true;
+                            chosen_pending = true;
+                        } else if parent == "reserved-memory" {
+                            reserved_found = true;
+                            reserved_cells = root_cells;
+                        }
+                    }
+                }
+                Token::Property(name, value) => {
+                    let cells = read_cells(value);
+                    match (depth, parent) {
+                        (1, _) if name == "#address-cells" => {
+                            root_cells.0 = cells.unwrap_or(root_cells.0)
+                        }
+                        (1, _) if name == "#size-cells" => {
+                            root_cells.1 = cells.unwrap_or(root_cells.1)
+                        }
+                        (2, "reserved-memory") if name == "#address-cells" => {
+                            reserved_cells.0 = cells.unwrap_or(reserved_cells.0)
+                        }
+                        (2, "reserved-memory") if name == "#size-cells" => {
+                            reserved_cells.1 = cells.unwrap_or(reserved_cells.1)
+                        }
+                        // Replaced by the new values
+                        (2, "chosen") if chosen.iter().any(|x| x.0 == name) => {
+                            continue
+                        }
+                        _ => {}
+                    }
+                }
+                Token::EndNode => {
+                    if depth == 2 && parent == "reserved-memory" {
+                        reservation.write(&mut builder, reserved_cells);
+                    }
+                    if depth == 1 {
+                        if !chosen_found && !chosen.is_empty() {
+                            builder.begin_node("chosen");
+                            for (name, value) in chosen {
+                                builder.property(name, value);
+                            }
+                            builder.end_node();
+                        }
+                        if !reserved_found {
+                            builder.begin_node("reserved-memory");
+                            builder.property(
+                                "#address-cells",
+                                &root_cells.0.to_be_bytes(),
+                            );
+                            builder.property(
+                                "#size-cells",
+                                &root_cells.1.to_be_bytes(),
+                            );
+                            builder.property("ranges", &[]);
+                            reservation.write(&mut builder, root_cells);
+                            builder.end_node();
+                        }
+                    }
+                    depth = depth.saturating_sub(1);
+                }
+            }
+            builder.token(&token);
+        }
+        builder.finish()
+    }
+}
+
+impl<'a> Property<'a> {
+    /// Entries of a string list value
+    pub fn strings(&self) -> impl Iterator<Item = &'a str> {
+        self.value
+            .split(|x| *x == 0)
+            .filter(|x| !x.is_empty())
+            .filter_map(|x| str::from_utf8(x).ok())
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let structure = self.tree.structure;
+        loop {
+            match read_u32(structure, self.offset)? {
+                FDT_BEGIN_NODE => {
+                    let name = read_str(structure.get(self.offset + 4..)?)?;
+                    self.offset = align(self.offset + 4 + name.len() + 1);
+                    return Some(Token::BeginNode(name));
+                }
+                FDT_END_NODE => {
+                    self.offset += 4;
+                    return Some(Token::EndNode);
+                }
+                FDT_PROP => {
+                    let len = read_u32(structure, self.offset + 4)? as usize;
+                    let name_offset =
+                        read_u32(structure, self.offset + 8)? as usize;
+                    let start = self.offset + 12;
+                    let value =
+                        structure.get(start..start.checked_add(len)?)?;
+                    let name = read_str(self.tree.strings.get(name_offset..)?)?;
+                    self.offset = align(start + len);
+                    return Some(Token::Property(name, value));
+                }
+                FDT_NOP => self.offset += 4,
+                FDT_END => return None,
+                // Tokens this reader does not know
+                _ => return None,
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Properties<'a> {
+    type Item = Property<'a>;
+
+    fn next(&mut self) -> Option<Property<'a>> {
+        loop {
+            match self.tokens.next()? {
+                Token::BeginNode(name) => {
+                    *self.nodes.get_mut(self.depth)? = name;
+                    self.depth += 1;
+                }
+                Token::EndNode => self.depth = self.depth.checked_sub(1)?,
+                Token::Property(name, value) => {
+                    return Some(Property {
+                        node: self.nodes[self.depth.checked_sub(1)?],
+                        name,
+                        value,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl Builder {
+    /// Empty blob with the memory reservations and boot hart of `tree`
+    pub fn new(tree: &DeviceTree) -> Self {
+        Builder {
+            structure: Vec::new(),
+            strings: Vec::new(),
+            reserve: tree.reserve.to_vec(),
+            boot_cpuid: tree.boot_cpuid,
+        }
+    }
+
+    /// Writes `token` as read from another tree
+    pub fn token(&mut self, token: &Token) {
+        match token {
+            Token::BeginNode(name) => self.begin_node(name),
+            Token::EndNode => self.end_node(),
+            Token::Property(name, value) => self.property(name, value),
+        }
+    }
+
+    /// Starts a child of the current node
+    pub fn begin_node(&mut self, name: &str) {
+        self.push_u32(FDT_BEGIN_NODE);
+        self.structure.extend_from_slice(name.as_bytes());
+        self.structure.push(0);
+        self.structure.resize(align(self.structure.len()), 0);
+    }
+
+    /// Ends the current node
+    pub fn end_node(&mut self) {
+        self.push_u32(FDT_END_NODE);
+    }
+
+    /// Adds a property to the current node
+    pub fn property(&mut self, name: &str, value: &[u8]) {
+        let name_offset = self.string(name);
+        self.push_u32(FDT_PROP);
+        self.push_u32(value.len() as u32);
+        self.push_u32(name_offset);
+        self.structure.extend_from_slice(value);
+        self.structure.resize(align(self.structure.len()), 0);
+    }
+
+    /// Lays out the blob, the header followed by the memory reservation,
+    /// structure and strings blocks
+    pub fn finish(mut self) -> Vec<u8> {
+        self.push_u32(FDT_END);
+        let reserve_offset = HEADER_LEN.next_multiple_of(8);
+        let structure_offset = reserve_offset + self.reserve.len();
+        let strings_offset = structure_offset + self.structure.len();
+        let len = strings_offset + self.strings.len();
+
+        let mut blob = Vec::with_capacity(len);
+        for field in [
+            MAGIC,
+            len as u32,
+            structure_offset as u32,
+            strings_offset as u32,
+            reserve_offset as u32,
+            VERSION,
+            LAST_COMP_VERSION,
+            self.boot_cpuid,
+            self.strings.len() as u32,
+            self.structure.len() as u32,
+        ] {
+            blob.extend_from_slice(&field.to_be_bytes());
+        }
+        blob.resize(reserve_offset, 0);
+        blob.extend_from_slice(&self.reserve);
+        blob.extend_from_slice(&self.structure);
+        blob.extend_from_slice(&self.strings);
+        blob
+    }
+}
+
+/// Replaces the blob at `address`, which may be no longer than `max_len`,
+/// with `f` of it
+pub fn rewrite(
+    address: usize,
+    max_len: usize,
+    f: impl FnOnce(&DeviceTree) -> Vec<u8>,
+) -> Result<(), &'static str> {
+    let blob = {
+        let tree =
+            DeviceTree::load(address, max_len).ok_or("DTB is not readable")?;
+        f(&tree)
+    };
+    if blob.len() > max_len {
+        return Err("no room for the DTB to grow");
+    }
+    // The old blob is no longer borrowed and the new one fits its space
+    unsafe {
+        ptr::copy_nonoverlapping(blob.as_ptr(), address as *mut u8, blob.len())
+    };
+    Ok(())
+}
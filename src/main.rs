@@ -22,6 +22,7 @@ extern crate alloc;
 
 mod assert_hex;
 mod cpu;
+mod fdt;
 mod helper;
 mod panic_wait;
 mod run_time_checks;
@@ -36,6 +37,7 @@ use synchronisation::{interface::Mutex, NullLock};
 
 static TEST: bool = false;
 static BOOTABLE: NullLock<bool> = NullLock::new(false);
+static SECURITY_VERSION: NullLock<u32> = NullLock::new(0);
 
 /// Early init code.
 ///
@@ -75,6 +77,26 @@ fn loader_machine() {
 
     Allocator::flush();
 
+    let entry = match verification::kernel_entry() {
+        Ok(x) => x,
+        Err(x) => panic!("! -- KERNEL IMAGE REJECTED: {}", x),
+    };
+
+    // Failing to stage the version leaves this verified boot no less secure,
+    // the floor rises once the OS confirms the boot on the next run
+    if let Err(x) = verification::stage_security_version(
+        SECURITY_VERSION.lock(|x| *x),
+    ) {
+        println!("! -- Failed to record security version: {}", x);
+    }
+
+    // The kernel could otherwise read the build key out of the loader
+    verification::wipe_keys();
+    // or read the UDS and forge the device's identity
+    if let Err(x) = verification::dice::lock() {
+        panic!("! -- DEVICE SECRET NOT LOCKED: {}", x);
+    }
+
     println!("Handing execution to the kernel...");
     //
     unsafe {
@@ -88,7 +110,7 @@ fn loader_machine() {
             in("t0") 0,
             in("a0") bsp::memory::map::kernel::HART,
             in("a1") bsp::memory::map::kernel::DTB,
-            in("a2") bsp::memory::map::kernel::KERNEL
+            in("a2") entry
         );
     }
 }
@@ -106,6 +128,13 @@ fn loader_main() {
 
     bsp::print_info();
 
+    let extensions = cpu::isa::Extensions::detect();
+    println!("ISA extensions: {}", extensions);
+    println!(
+        "Crypto backend: {}",
+        verification::backend::select(extensions)
+    );
+
     println!("Drivers loaded:");
     driver::driver_manager().enumerate();
 
@@ -113,15 +142,29 @@ fn loader_main() {
         run_time_checks::suite();
     }
 
+    println!("Running cryptographic self-tests...");
+    if let Err(x) = verification::self_test::run() {
+        panic!("! -- CRYPTO SELF-TEST FAILED: {}", x)
+    }
+
     match verification::verify_kernel() {
-        Ok(_) => {
+        Ok(x) => {
             println!("Loaded kernel hash matches signed hash proceeding...");
+            SECURITY_VERSION.lock(|version| *version = x);
         }
-        Err(_) => {
-            panic!("! -- LOADED KERNEL HASH DOES NOT MATCH SIGNED HASH")
+        Err(x) => {
+            panic!("! -- KERNEL VERIFICATION FAILED: {}", x)
         }
     }
 
+    // Without the log the kernel boots unmeasured, as it would with no TPM
+    if let Err(x) = verification::measurement::publish() {
+        println!("! -- Event log not passed to the kernel: {}", x);
+    }
+    if let Err(x) = verification::dice::publish() {
+        println!("! -- DICE handover not passed to the kernel: {}", x);
+    }
+
     BOOTABLE.lock(|x| *x = true);
     // Safe but all assembly is unsafe this will send us to the trap vector
     unsafe {
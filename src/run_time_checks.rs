@@ -3,6 +3,16 @@
 use console::{logln, print};
 use alloc::{vec::Vec, format};
 use global_allocator::Allocator;
+use crate::verification::{self, backend::Sha256};
+use sha2::{Digest, Sha384, Sha512};
+use sm3::Sm3;
+
+#[cfg(feature = "lms")]
+mod lms_vectors;
+#[cfg(feature = "mldsa")]
+mod mldsa_vectors;
+#[cfg(feature = "zstd")]
+mod zstd_vectors;
 
 pub fn simple_alloc_check() {
     assert!(Allocator::get_alloc_count() == 0, "{}", format!("Expected no allocations got {}.", Allocator::get_alloc_count()));
@@ -145,6 +155,517 @@ pub fn large_alloc_check() {
     assert!(Allocator::get_alloc_count() == 0, "{}", format!("Vector out of scope expected no allocations got {}.", Allocator::get_alloc_count()));
 }
 
+/// NIST SHA256 examples through the selected backend, each passed whole and a byte at a time and the million
+/// 'a' message in 1000 byte pieces
+pub fn sha256_backend_check() {
+    let vectors: [(&[u8], &str); 4] = [
+        (b"", "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+        (b"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        (b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq", "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"),
+        (b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            "cf5b16a778af8380036ce59e7b0492370b249b11e8f07a51afac45037afee9d1"),
+    ];
+    let to_hex = |digest: &[u8]| digest.iter().map(|x| format!("{:02x}", x)).collect::<alloc::string::String>();
+
+    for (message, expected) in vectors {
+        let result = to_hex(&Sha256::digest(message));
+        assert!(result == expected, "{}", format!("SHA256 of {:?} expected {} got {}.", message, expected, result));
+
+        let mut hasher = Sha256::new();
+        for byte in message {
+            hasher.update([*byte]);
+        }
+        let result = to_hex(&hasher.finalize());
+        assert!(result == expected, "{}", format!("SHA256 of {:?} a byte at a time expected {} got {}.", message, expected, result));
+    }
+
+    let mut hasher = Sha256::new();
+    for _ in 0..1000 {
+        hasher.update([b'a'; 1000]);
+    }
+    let result = to_hex(&hasher.finalize());
+    let expected = "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0";
+    assert!(result == expected, "{}", format!("SHA256 of a million 'a' expected {} got {}.", expected, result));
+}
+
+/// Known answers for the selected SHA384 and SHA512 backend from the `sha2` crate, every length up to 300 bytes
+/// covers each padding case including the extra length block
+pub fn sha512_backend_check() {
+    let mut data = [0u8; 300];
+    for (i, byte) in data.iter_mut().enumerate() {
+        *byte = (i * 7 + 3) as u8;
+    }
+    let address = data.as_ptr() as usize;
+
+    for len in 0..data.len() {
+        let expected = Sha384::digest(&data[..len]);
+        let result = verification::sha384_region(address, len);
+        assert!(result[..] == expected[..], "{}", format!("SHA384 of {} bytes expected {:x?} got {:x?}.", len, expected, result));
+
+        let expected = Sha512::digest(&data[..len]);
+        let result = verification::sha512_region(address, len);
+        assert!(result[..] == expected[..], "{}", format!("SHA512 of {} bytes expected {:x?} got {:x?}.", len, expected, result));
+    }
+}
+
+/// GB/T 32905-2016 examples and the `sm3` crate against the selected SM3 backend, every length up to 300
+/// bytes covers each padding case
+pub fn sm3_backend_check() {
+    let vectors: [(&[u8], &str); 2] = [
+        (b"abc", "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0"),
+        (b"abcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcd", "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732"),
+    ];
+    for (message, expected) in vectors {
+        let result = verification::sm3_region(message.as_ptr() as usize, message.len());
+        let result = result.iter().map(|x| format!("{:02x}", x)).collect::<alloc::string::String>();
+        assert!(result == expected, "{}", format!("SM3 of {:?} expected {} got {}.", message, expected, result));
+    }
+
+    let mut data = [0u8; 300];
+    for (i, byte) in data.iter_mut().enumerate() {
+        *byte = (i * 7 + 3) as u8;
+    }
+    for len in 0..data.len() {
+        let expected = Sm3::digest(&data[..len]);
+        let result = verification::sm3_region(data.as_ptr() as usize, len);
+        assert!(result[..] == expected[..], "{}", format!("SM3 of {} bytes expected {:x?} got {:x?}.", len, expected, result));
+    }
+}
+
+#[cfg(feature = "aes-gcm")]
+pub fn aes_gcm_backend_check() {
+    use aes_gcm::{aead::{AeadInPlace, KeyInit}, Aes128Gcm, Aes256Gcm};
+    let mut key = [0u8; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = (i * 13 + 5) as u8;
+    }
+    let nonce = [0xA5u8; 12];
+    let mut data = [0u8; 300];
+    for (i, byte) in data.iter_mut().enumerate() {
+        *byte = (i * 7 + 3) as u8;
+    }
+    // Past 300 bytes for the batches of 64 blocks
+    let lens = (0..data.len()).chain([4096 + 77]);
+    for len in lens {
+        for key in [&key[..16], &key[..]] {
+            let plaintext: Vec<u8> = data.iter().cycle().take(len).copied().collect();
+            let aad = &data[..len % 20];
+            let mut ciphertext = plaintext.clone();
+            let tag = if key.len() == 16 {
+                Aes128Gcm::new(key.into()).encrypt_in_place_detached(&nonce.into(), aad, &mut ciphertext)
+            } else {
+                Aes256Gcm::new(key.into()).encrypt_in_place_detached(&nonce.into(), aad, &mut ciphertext)
+            };
+            let tag: [u8; 16] = tag.unwrap().into();
+            let mut buffer = ciphertext.clone();
+            let result = verification::backend::aes_gcm_decrypt(key, &nonce, aad, &mut buffer, &tag);
+            assert!(result && buffer == plaintext, "{}", format!("AES-{}-GCM of {} bytes failed to decrypt.", key.len() * 8, len));
+            let mut forged = tag;
+            forged[len % 16] ^= 1;
+            let mut buffer = ciphertext.clone();
+            let result = verification::backend::aes_gcm_decrypt(key, &nonce, aad, &mut buffer, &forged);
+            assert!(!result && buffer == ciphertext, "{}", format!("AES-{}-GCM of {} bytes accepted a forged tag.", key.len() * 8, len));
+        }
+    }
+}
+
+/// RFC 8554 Appendix F test cases 1 and 2 must verify, and each must fail once a byte of C, of a y[i] chain
+/// value or of an authentication path node is flipped at either level
+#[cfg(feature = "lms")]
+pub fn lms_kat_check() {
+    use lms_vectors::*;
+    use verification::lms;
+    let check = |case: usize, public_key: &[u8], message: &[u8], signature: &[u8], offsets: [usize; 6]| {
+        assert!(lms::verify(public_key, signature, message), "{}", format!("LMS test case {} was rejected.", case));
+        let mut altered = signature.to_vec();
+        for offset in offsets {
+            altered[offset] ^= 1;
+            assert!(!lms::verify(public_key, &altered, message), "{}", format!("LMS test case {} accepted a flipped byte at {}.", case, offset));
+            altered[offset] ^= 1;
+        }
+    };
+
+    // Offsets of C, y[10] and the second path node in the top then the bottom level signature
+    check(1, &TEST_CASE_1_PUBLIC_KEY, &TEST_CASE_1_MESSAGE, &TEST_CASE_1_SIGNATURE, [12, 364, 1168, 1360, 1712, 2516]);
+    check(2, &TEST_CASE_2_PUBLIC_KEY, &TEST_CASE_2_MESSAGE, &TEST_CASE_2_SIGNATURE, [12, 364, 2224, 2576, 2928, 3732]);
+}
+
+/// An ML-DSA-65 signature must verify, and must fail once c~ is altered or its hint is malformed, with
+/// indices out of order, a count past omega, a falling count or a set byte after the last index
+#[cfg(feature = "mldsa")]
+pub fn mldsa_kat_check() {
+    use mldsa_vectors::*;
+    use verification::mldsa;
+    assert!(mldsa::verify(&PUBLIC_KEY, &SIGNATURE, MESSAGE), "ML-DSA-65 signature was rejected.");
+
+    // The hint is the last 61 bytes, 55 indices then a running count for each of the 6 polynomials
+    let hint = mldsa::SIGNATURE_LEN - 61;
+    let alterations: [(&str, usize, u8); 5] = [
+        ("c~", 0, SIGNATURE[0] ^ 1),
+        ("hint order", hint + 1, SIGNATURE[hint]),
+        ("hint count", hint + 60, 56),
+        ("hint falling count", hint + 56, SIGNATURE[hint + 55] - 1),
+        ("hint padding", hint + SIGNATURE[hint + 60] as usize, 1),
+    ];
+    for (name, offset, value) in alterations {
+        let mut altered = SIGNATURE;
+        altered[offset] = value;
+        assert!(!mldsa::verify(&PUBLIC_KEY, &altered, MESSAGE), "{}", format!("ML-DSA-65 accepted an altered {}.", name));
+    }
+}
+
+/// A frame at the largest zstd window must decompress through the loader's allocator and give back all it took,
+/// and must be refused once its window descriptor asks for twice that
+#[cfg(feature = "zstd")]
+pub fn zstd_heap_check() {
+    use zstd_vectors::FRAME;
+    use verification::compression;
+    let mut expected = Vec::new();
+    let mut i = 0;
+    while expected.len() < 0x8000 {
+        expected.extend_from_slice(format!("{} ", i * 7919 % 10007).as_bytes());
+        i += 1;
+    }
+    expected.truncate(0x8000);
+    let mut output = alloc::vec![0; 0x8000];
+
+    let allocated = Allocator::get_alloc_count();
+    let written = compression::unzstd(&FRAME, &mut output);
+    assert!(written.is_ok_and(|x| x == expected.len()), "zstd frame was not decompressed whole.");
+    assert!(output == expected, "zstd frame decompressed wrongly.");
+    assert!(Allocator::get_alloc_count() == allocated, "{}", format!("zstd decoder left {} bytes allocated.", Allocator::get_alloc_count() - allocated));
+
+    // The window descriptor's exponent is bits 3 to 7 of the byte after the frame header descriptor
+    let mut altered = FRAME;
+    altered[5] += 1 << 3;
+    assert!(compression::unzstd(&altered, &mut output).is_err(), "zstd frame with a larger window was accepted.");
+}
+
+/// Image header signed with Ed25519 over SHA-256, holding `tlvs` and followed by a `signature_len` byte signature
+#[cfg(feature = "ed25519")]
+fn image_header(tlvs: &[(u16, &[u8])], signature_len: usize) -> Vec<u8> {
+    use verification::header::{FIXED_LEN, MAGIC, VERSION};
+    let mut raw = Vec::from(MAGIC);
+    raw.extend_from_slice(&VERSION.to_le_bytes());
+    raw.extend_from_slice(&[1, 1]);
+    raw.resize(0x20, 0);
+    raw.extend_from_slice(&0x1000u64.to_le_bytes());
+    raw.resize(FIXED_LEN, 0xAA);
+    for (tag, value) in tlvs {
+        raw.extend_from_slice(&tag.to_le_bytes());
+        raw.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        raw.extend_from_slice(value);
+    }
+    let signed_len = raw.len() as u32;
+    raw.resize(raw.len() + signature_len, 0);
+    let header_len = raw.len() as u32;
+    raw[0x0C..0x10].copy_from_slice(&header_len.to_le_bytes());
+    raw[0x10..0x14].copy_from_slice(&signed_len.to_le_bytes());
+    raw
+}
+
+/// Copy of `raw` with `bytes` written at `offset`
+fn altered(raw: &[u8], offset: usize, bytes: &[u8]) -> Vec<u8> {
+    let mut altered = raw.to_vec();
+    altered[offset..offset + bytes.len()].copy_from_slice(bytes);
+    altered
+}
+
+/// A well formed image header must parse, and one that is truncated, has a bad magic, version or algorithm, a
+/// header or signed length that disagrees with the image, or a TLV record running past the signed bytes must not
+#[cfg(feature = "ed25519")]
+pub fn header_parse_check() {
+    use verification::{header::{Header, FIXED_LEN, TLV_SECURITY_VERSION}, VerificationError};
+    let raw = image_header(&[(TLV_SECURITY_VERSION, &7u32.to_le_bytes())], 64);
+    let header = Header::parse(&raw).unwrap_or_else(|x| panic!("{}", format!("Well formed header was rejected as {}.", x)));
+    assert!(header.security_version().is_ok_and(|x| x == 7), "Header TLV record was misread.");
+    assert!(header.signature(&[0; 32]).is_ok_and(|x| x.len() == 64), "Header signature was misread.");
+    let short = image_header(&[], 63);
+    assert!(Header::parse(&short).is_ok_and(|x| matches!(x.signature(&[0; 32]), Err(VerificationError::Truncated))), "Header with a truncated signature was accepted.");
+
+    let len = raw.len() as u32;
+    let rejects = |name: &str, raw: &[u8], expected: fn(&VerificationError) -> bool| match Header::parse(raw) {
+        Err(x) if expected(&x) => (),
+        Err(x) => panic!("{}", format!("Header with {} was rejected as {}.", name, x)),
+        Ok(_) => panic!("{}", format!("Header with {} was accepted.", name)),
+    };
+    rejects("a truncated fixed part", &raw[..FIXED_LEN - 1], |x| matches!(x, VerificationError::Truncated));
+    rejects("a bad magic", &altered(&raw, 0, b"X"), |x| matches!(x, VerificationError::BadMagic));
+    rejects("an unknown version", &altered(&raw, 0x08, &2u16.to_le_bytes()), |x| matches!(x, VerificationError::UnsupportedVersion(2)));
+    rejects("an unknown signature algorithm", &altered(&raw, 0x0A, &[0xFF]), |x| matches!(x, VerificationError::UnsupportedSignatureAlgorithm(0xFF)));
+    rejects("an oversized header length", &altered(&raw, 0x0C, &(len + 1).to_le_bytes()), |x| matches!(x, VerificationError::HeaderLength(_)));
+    rejects("a truncated signature slot", &raw[..raw.len() - 1], |x| matches!(x, VerificationError::HeaderLength(_)));
+    rejects("an oversized signed length", &altered(&raw, 0x10, &(len + 1).to_le_bytes()), |x| matches!(x, VerificationError::Truncated));
+    rejects("a signed length inside the fixed part", &altered(&raw, 0x10, &(FIXED_LEN as u32 - 1).to_le_bytes()), |x| matches!(x, VerificationError::Truncated));
+    rejects("a truncated TLV header", &altered(&raw, 0x10, &(FIXED_LEN as u32 + 2).to_le_bytes()), |x| matches!(x, VerificationError::Truncated));
+    rejects("an oversized TLV length", &altered(&raw, FIXED_LEN + 2, &5u16.to_le_bytes()), |x| matches!(x, VerificationError::Truncated));
+}
+
+/// The components of a header must be collected, and a component record that is truncated, oversized, of an
+/// unknown kind, repeated or missing must be refused
+#[cfg(feature = "ed25519")]
+pub fn manifest_parse_check() {
+    use verification::{header::{Header, TLV_COMPONENT}, manifest::{ComponentKind, Manifest, COMPONENT_LEN}, VerificationError};
+    let component = |kind: u32, len: usize| {
+        let mut value = alloc::vec![0x55; len];
+        value[..0x04].copy_from_slice(&kind.to_le_bytes());
+        value[0x10..0x18].copy_from_slice(&(kind as u64 * 0x100).to_le_bytes());
+        value
+    };
+    let parse = |components: &[&[u8]]| {
+        let tlvs: Vec<(u16, &[u8])> = components.iter().map(|x| (TLV_COMPONENT, *x)).collect();
+        let raw = image_header(&tlvs, 64);
+        let header = Header::parse(&raw).unwrap_or_else(|x| panic!("{}", format!("Header with a manifest was rejected as {}.", x)));
+        Manifest::parse(&header).map(|x| [x.kernel.length, x.dtb.length, x.ramfs.length])
+    };
+    let (kernel, dtb, ramfs) = (component(1, COMPONENT_LEN), component(2, COMPONENT_LEN), component(3, COMPONENT_LEN));
+    assert!(parse(&[&ramfs, &kernel, &dtb]).is_ok_and(|x| x == [0x100, 0x200, 0x300]), "Well formed manifest was misread.");
+
+    let rejects = |name: &str, components: &[&[u8]], expected: fn(&VerificationError) -> bool| match parse(components) {
+        Err(x) if expected(&x) => (),
+        Err(x) => panic!("{}", format!("Manifest with {} was rejected as {}.", name, x)),
+        Ok(_) => panic!("{}", format!("Manifest with {} was accepted.", name)),
+    };
+    rejects("a truncated component", &[&kernel, &dtb, &component(3, COMPONENT_LEN - 1)], |x| matches!(x, VerificationError::Truncated));
+    rejects("an oversized component", &[&kernel, &dtb, &component(3, COMPONENT_LEN + 1)], |x| matches!(x, VerificationError::Truncated));
+    rejects("an unknown component", &[&kernel, &dtb, &component(4, COMPONENT_LEN)], |x| matches!(x, VerificationError::UnknownComponent(4)));
+    rejects("a repeated component", &[&kernel, &dtb, &kernel], |x| matches!(x, VerificationError::DuplicateComponent(ComponentKind::Kernel)));
+    rejects("a missing component", &[&kernel, &dtb], |x| matches!(x, VerificationError::MissingComponent(ComponentKind::Ramfs)));
+}
+
+/// A well formed key certificate must parse, and one that is truncated, has a bad magic, version or algorithm or
+/// a subject key cut short must not
+#[cfg(feature = "ed25519")]
+pub fn certificate_parse_check() {
+    use verification::certificate::{Certificate, FIXED_LEN, MAGIC, VERSION};
+    let mut raw = Vec::from(MAGIC);
+    raw.extend_from_slice(&VERSION.to_le_bytes());
+    raw.extend_from_slice(&[1, 1]);
+    raw.extend_from_slice(&1u32.to_le_bytes());
+    raw.resize(FIXED_LEN, 0x11);
+    raw.resize(FIXED_LEN + 32, 0x22);
+    raw.resize(FIXED_LEN + 32 + 64, 0x33);
+    let certificate = Certificate::parse(&raw).unwrap_or_else(|x| panic!("{}", format!("Well formed certificate was rejected as {}.", x)));
+    assert!(certificate.usage == 1 && certificate.subject_key() == [0x22; 32] && certificate.signature() == [0x33; 64], "Certificate was misread.");
+
+    let cases: [(&str, Vec<u8>, &str); 5] = [
+        ("a truncated fixed part", raw[..FIXED_LEN - 1].to_vec(), "not a certificate"),
+        ("a bad magic", altered(&raw, 0, b"X"), "not a certificate"),
+        ("an unknown version", altered(&raw, 0x08, &2u16.to_le_bytes()), "unsupported certificate version"),
+        ("an unknown key algorithm", altered(&raw, 0x0B, &[0xFF]), "unsupported key algorithm"),
+        ("a truncated subject key", raw[..FIXED_LEN + 31].to_vec(), "subject key malformed"),
+    ];
+    for (name, raw, expected) in cases {
+        match Certificate::parse(&raw) {
+            Err(x) if x == expected => (),
+            Err(x) => panic!("{}", format!("Certificate with {} was rejected as {}.", name, x)),
+            Ok(_) => panic!("{}", format!("Certificate with {} was accepted.", name)),
+        }
+    }
+}
+
+/// A well formed revocation update must parse, one with a bad magic or too short to hold the fixed part is taken
+/// as absent, and one with an unknown version, a count past its fingerprints or a truncated signature is refused
+#[cfg(feature = "ed25519")]
+pub fn revocation_parse_check() {
+    use verification::{revocation::{RevocationList, FIXED_LEN, MAGIC, VERSION}, VerificationError};
+    let mut raw = Vec::from(MAGIC);
+    raw.extend_from_slice(&VERSION.to_le_bytes());
+    raw.extend_from_slice(&[1, 0]);
+    raw.extend_from_slice(&2u32.to_le_bytes());
+    raw.extend_from_slice(&[0x11; 8]);
+    raw.extend_from_slice(&5u32.to_le_bytes());
+    raw.resize(FIXED_LEN, 0);
+    raw.resize(FIXED_LEN + 32, 0x44);
+    raw.resize(FIXED_LEN + 64, 0x55);
+    raw.resize(FIXED_LEN + 64 + 64, 0x66);
+    match RevocationList::parse(&raw) {
+        Ok(Some(x)) => {
+            assert!(x.sequence == 5 && x.count == 2, "Revocation update was misread.");
+            assert!(x.contains(&[0x55; 32]) && !x.contains(&[0x66; 32]), "Revocation update fingerprints were misread.");
+            assert!(x.signature(&[0; 32]).is_ok_and(|x| x == [0x66; 64]), "Revocation update signature was misread.");
+        }
+        _ => panic!("Well formed revocation update was rejected."),
+    }
+    assert!(RevocationList::parse(&altered(&raw, 0, b"X")).is_ok_and(|x| x.is_none()), "Revocation update with a bad magic was not taken as absent.");
+    assert!(RevocationList::parse(&raw[..FIXED_LEN - 1]).is_ok_and(|x| x.is_none()), "Truncated revocation update was not taken as absent.");
+    assert!(RevocationList::parse(&raw[..raw.len() - 1]).is_ok_and(|x| x.is_some_and(|x| x.signature(&[0; 32]).is_err())), "Revocation update with a truncated signature was accepted.");
+
+    let cases: [(&str, Vec<u8>, &str); 3] = [
+        ("an unknown version", altered(&raw, 0x08, &2u16.to_le_bytes()), "unsupported version"),
+        ("an oversized count", altered(&raw, 0x0C, &7u32.to_le_bytes()), "truncated"),
+        ("the largest count", altered(&raw, 0x0C, &u32::MAX.to_le_bytes()), "truncated"),
+    ];
+    for (name, raw, expected) in cases {
+        match RevocationList::parse(&raw) {
+            Err(VerificationError::InvalidRevocationList(x)) if x == expected => (),
+            Err(x) => panic!("{}", format!("Revocation update with {} was rejected as {}.", name, x)),
+            Ok(_) => panic!("{}", format!("Revocation update with {} was accepted.", name)),
+        }
+    }
+}
+
+/// The length of a DER RSA public key and of its modulus must be read, and a key that is truncated, has the wrong
+/// tag or a length too long to be read or past the key must not be
+pub fn pkcs1_der_check() {
+    use verification::pkcs1;
+    // SEQUENCE of a 2048 bit modulus, which needs a leading zero, and the exponent 65537
+    let mut key = Vec::from([0x30, 0x82, 0x01, 0x0A, 0x02, 0x82, 0x01, 0x01, 0x00]);
+    key.resize(key.len() + 256, 0xC5);
+    key.extend_from_slice(&[0x02, 0x03, 0x01, 0x00, 0x01]);
+    assert!(pkcs1::key_len(&key) == Some(270) && pkcs1::modulus_len(&key) == Some(256), "DER key was misread.");
+    let mut trailing = key.clone();
+    trailing.push(0);
+    assert!(pkcs1::key_len(&trailing) == Some(270), "DER key followed by other bytes was misread.");
+
+    let cases: [(&str, &[u8]); 8] = [
+        ("nothing", &[]),
+        ("a tag alone", &[0x30]),
+        ("a truncated length", &[0x30, 0x82, 0x01]),
+        ("truncated contents", &key[..key.len() - 1]),
+        ("a bad tag", &altered(&key, 0, &[0x31])),
+        ("a length of no bytes", &altered(&key, 1, &[0x80])),
+        ("a length of three bytes", &altered(&key, 1, &[0x83])),
+        ("an oversized length", &altered(&key, 2, &[0xFF, 0xFF])),
+    ];
+    for (name, raw) in cases {
+        assert!(pkcs1::key_len(raw).is_none(), "{}", format!("DER key with {} was accepted.", name));
+    }
+    assert!(pkcs1::modulus_len(&altered(&key, 4, &[0x03])).is_none(), "DER key with a bad modulus tag was accepted.");
+    assert!(pkcs1::modulus_len(&key[..0x100]).is_none(), "DER key with a truncated modulus was accepted.");
+}
+
+/// The compression record must parse, and gzip and LZ4 streams must decompress, and be refused when truncated,
+/// with a bad magic, or with a length that disagrees with their contents or the space given them
+pub fn compression_framing_check() {
+    use verification::{compression::{Compression, RECORD_LEN}, VerificationError};
+    let mut record = alloc::vec![0; RECORD_LEN];
+    record[0x08..0x10].copy_from_slice(&0x1234u64.to_le_bytes());
+    for (name, record) in [("a truncated", &record[..RECORD_LEN - 1]), ("an oversized", &[record.as_slice(), &[0]].concat()[..])] {
+        assert!(matches!(Compression::parse(record), Err(VerificationError::Truncated)), "{}", format!("{} compression record was accepted.", name));
+    }
+    assert!(matches!(Compression::parse(&altered(&record, 0, &[0xFF])), Err(VerificationError::UnsupportedCompression(0xFF))), "Unknown compression format was accepted.");
+    #[cfg(feature = "gzip")]
+    assert!(Compression::parse(&altered(&record, 0, &[1])).is_ok_and(|x| x.format == verification::compression::Format::Gzip && x.length == 0x1234), "gzip compression record was misread.");
+
+    #[cfg(any(feature = "gzip", feature = "lz4"))]
+    const DATA: &[u8] = b"SentinelBoot";
+    #[cfg(any(feature = "gzip", feature = "lz4"))]
+    let expect = |name: &str, result: Result<usize, VerificationError>, output: &[u8], reason: Option<&str>| match (result, reason) {
+        (Ok(x), None) if output[..x] == *DATA => (),
+        (Err(VerificationError::Decompression(x)), Some(y)) if x == y => (),
+        (Err(x), _) => panic!("{}", format!("{} was rejected as {}.", name, x)),
+        (Ok(_), _) => panic!("{}", format!("{} was not rejected.", name)),
+    };
+
+    #[cfg(feature = "gzip")]
+    {
+        use verification::compression::gunzip;
+        // A single stored deflate block, then the CRC-32, which is not checked, and the length
+        let mut stream = Vec::from([0x1F, 0x8B, 0x08, 0, 0, 0, 0, 0, 0, 0x03, 0x01]);
+        stream.extend_from_slice(&(DATA.len() as u16).to_le_bytes());
+        stream.extend_from_slice(&(!(DATA.len() as u16)).to_le_bytes());
+        stream.extend_from_slice(DATA);
+        stream.extend_from_slice(&[0; 4]);
+        stream.extend_from_slice(&(DATA.len() as u32).to_le_bytes());
+        let len = stream.len();
+
+        let cases: [(&str, &[u8], usize, Option<&str>); 8] = [
+            ("gzip stream", &stream, DATA.len(), None),
+            ("gzip stream with a bad magic", &altered(&stream, 0, &[0]), DATA.len(), Some("not a gzip stream")),
+            ("truncated gzip header", &stream[..5], DATA.len(), Some("truncated stream")),
+            ("gzip header with a truncated file name", &altered(&stream, 3, &[0x08])[..12], DATA.len(), Some("truncated stream")),
+            ("truncated deflate stream", &stream[..len - 9], DATA.len(), Some("corrupt gzip stream")),
+            ("truncated gzip trailer", &stream[..len - 1], DATA.len(), Some("corrupt gzip trailer")),
+            ("gzip trailer with an oversized length", &altered(&stream, len - 4, &[DATA.len() as u8 + 1]), DATA.len(), Some("corrupt gzip trailer")),
+            ("gzip stream longer than its space", &stream, DATA.len() - 1, Some("longer than its record")),
+        ];
+        for (name, input, space, reason) in cases {
+            let mut output = [0; DATA.len()];
+            expect(name, gunzip(input, &mut output[..space]), &output, reason);
+        }
+    }
+
+    #[cfg(feature = "lz4")]
+    {
+        use verification::compression::unlz4;
+        // Version 1 frame of 64 KiB blocks, with the content size if given, and a single uncompressed block
+        let frame = |content_size: Option<u64>, block_len: u32| {
+            let mut frame = Vec::from(0x184D_2204u32.to_le_bytes());
+            frame.extend_from_slice(&[if content_size.is_some() { 0x68 } else { 0x60 }, 0x40]);
+            if let Some(x) = content_size {
+                frame.extend_from_slice(&x.to_le_bytes());
+            }
+            frame.push(0);
+            frame.extend_from_slice(&(block_len | 0x8000_0000).to_le_bytes());
+            frame.extend_from_slice(DATA);
+            frame.extend_from_slice(&[0; 4]);
+            frame
+        };
+        let stream = frame(None, DATA.len() as u32);
+        let len = stream.len();
+
+        let cases: [(&str, &[u8], usize, Option<&str>); 8] = [
+            ("LZ4 frame", &stream, DATA.len(), None),
+            ("LZ4 frame with its content size", &frame(Some(DATA.len() as u64), DATA.len() as u32), DATA.len(), None),
+            ("LZ4 frame with a bad magic", &altered(&stream, 0, &[0]), DATA.len(), Some("not an LZ4 frame")),
+            ("truncated LZ4 frame", &stream[..len - 1], DATA.len(), Some("truncated stream")),
+            ("LZ4 block with an oversized length", &frame(None, DATA.len() as u32 + 8), DATA.len(), Some("truncated stream")),
+            ("LZ4 block longer than the frame allows", &frame(None, 0x1_0001), DATA.len(), Some("corrupt LZ4 frame")),
+            ("LZ4 frame with an oversized content size", &frame(Some(DATA.len() as u64 + 1), DATA.len() as u32), DATA.len(), Some("length does not match its record")),
+            ("LZ4 frame longer than its space", &stream, DATA.len() - 1, Some("longer than its record")),
+        ];
+        for (name, input, space, reason) in cases {
+            let mut output = [0; DATA.len()];
+            expect(name, unlz4(input, &mut output[..space]), &output, reason);
+        }
+    }
+}
+
+/// A devicetree must load and be rewritten with a reservation, and one that is too short for its header, has a
+/// bad magic, a length past its space, a block running past the blob or a structure block cut short of its end
+/// token must not, nor may a rewrite grow the blob past its space
+pub fn fdt_check() {
+    use crate::fdt::{self, DeviceTree, Reservation};
+    // Header, an empty memory reservation block and a structure block holding only the root node
+    let mut blob = Vec::new();
+    for field in [0xD00D_FEED, 0x48, 0x38, 0x48, 0x28, 17, 16, 0, 0, 0x10, 0, 0, 0, 0, 1, 0, 2, 9u32] {
+        blob.extend_from_slice(&field.to_be_bytes());
+    }
+    // Each blob is only read as far as `max_len`, which never exceeds it
+    let load = |blob: &[u8], max_len: usize| DeviceTree::load(blob.as_ptr() as usize, max_len.min(blob.len())).is_some();
+    assert!(load(&blob, blob.len()), "Well formed devicetree was rejected.");
+
+    let cases: [(&str, Vec<u8>, usize); 7] = [
+        ("a truncated header", blob.clone(), 0x27),
+        ("a bad magic", altered(&blob, 0, &[0]), blob.len()),
+        ("an oversized length", blob.clone(), blob.len() - 1),
+        ("an oversized structure block", altered(&blob, 0x24, &0x14u32.to_be_bytes()), blob.len()),
+        ("a structure block cut short", altered(&blob, 0x24, &0x0Cu32.to_be_bytes()), blob.len()),
+        ("a strings block past its end", altered(&blob, 0x0C, &0x49u32.to_be_bytes()), blob.len()),
+        ("an unterminated reservation block", altered(&blob, 0x10, &0x40u32.to_be_bytes()), blob.len()),
+    ];
+    for (name, blob, max_len) in cases {
+        assert!(!load(&blob, max_len), "{}", format!("Devicetree with {} was accepted.", name));
+    }
+
+    let reservation = Reservation { name: "sentinel", compatible: None, address: 0x8000_0000, len: 0x1000 };
+    let chosen: [(&str, &[u8]); 1] = [("bootargs", b"console=ttyS0\0")];
+    let mut space = blob.clone();
+    space.resize(0x200, 0);
+    let rewritten = fdt::rewrite(space.as_mut_ptr() as usize, space.len(), |x| x.with_reservation(&reservation, &chosen));
+    assert!(rewritten.is_ok(), "Devicetree was not rewritten.");
+    let tree = DeviceTree::new(&space).expect("Rewritten devicetree was rejected.");
+    assert!(tree.properties().any(|x| x.node == "sentinel@80000000" && x.name == "reg" && x.value == [0, 0, 0, 0, 0x80, 0, 0, 0, 0, 0, 0x10, 0]), "Rewritten devicetree lacks the reservation.");
+    assert!(tree.properties().any(|x| x.node == "chosen" && x.name == "bootargs" && x.value == chosen[0].1), "Rewritten devicetree lacks /chosen.");
+
+    let mut space = blob.clone();
+    let rewritten = fdt::rewrite(space.as_mut_ptr() as usize, space.len(), |x| x.with_reservation(&reservation, &chosen));
+    assert!(rewritten == Err("no room for the DTB to grow") && space == blob, "Devicetree grew past its space.");
+    let mut space = altered(&blob, 0, &[0]);
+    let rewritten = fdt::rewrite(space.as_mut_ptr() as usize, space.len(), |x| x.with_reservation(&reservation, &chosen));
+    assert!(rewritten == Err("DTB is not readable"), "Devicetree with a bad magic was rewritten.");
+}
 
 pub fn suite() {
     print!("SIMPLE ALLOC: ");
@@ -162,4 +683,75 @@ pub fn suite() {
     print!("LARGE ALLOC: ");
     large_alloc_check();
     print!("PASS\n");
+
+    print!("BACKEND SHA256: ");
+    sha256_backend_check();
+    print!("PASS\n");
+
+    print!("BACKEND SHA512: ");
+    sha512_backend_check();
+    print!("PASS\n");
+
+    print!("BACKEND SM3: ");
+    sm3_backend_check();
+    print!("PASS\n");
+
+    #[cfg(feature = "aes-gcm")]
+    {
+        print!("BACKEND AES-GCM: ");
+        aes_gcm_backend_check();
+        print!("PASS\n");
+    }
+
+    #[cfg(feature = "lms")]
+    {
+        print!("LMS KAT: ");
+        lms_kat_check();
+        print!("PASS\n");
+    }
+
+    #[cfg(feature = "mldsa")]
+    {
+        print!("ML-DSA KAT: ");
+        mldsa_kat_check();
+        print!("PASS\n");
+    }
+
+    #[cfg(feature = "zstd")]
+    {
+        print!("ZSTD HEAP: ");
+        zstd_heap_check();
+        print!("PASS\n");
+    }
+
+    #[cfg(feature = "ed25519")]
+    {
+        print!("HEADER PARSE: ");
+        header_parse_check();
+        print!("PASS\n");
+
+        print!("MANIFEST PARSE: ");
+        manifest_parse_check();
+        print!("PASS\n");
+
+        print!("CERTIFICATE PARSE: ");
+        certificate_parse_check();
+        print!("PASS\n");
+
+        print!("REVOCATION PARSE: ");
+        revocation_parse_check();
+        print!("PASS\n");
+    }
+
+    print!("PKCS1 DER: ");
+    pkcs1_der_check();
+    print!("PASS\n");
+
+    print!("COMPRESSION FRAMING: ");
+    compression_framing_check();
+    print!("PASS\n");
+
+    print!("FDT: ");
+    fdt_check();
+    print!("PASS\n");
 }
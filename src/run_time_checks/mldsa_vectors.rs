@@ -0,0 +1,341 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! ML-DSA-65 vector, the FIPS 204 deterministic signature with an empty context under the key pair generated
+//! from the seed 00 01 .. 1f, as RustCrypto's `ml-dsa` signs it and OpenSSL verifies it
+
+pub static MESSAGE: &[u8] = b"SentinelBoot ML-DSA-65 known answer";
+
+pub static PUBLIC_KEY: [u8; 1952] = [
+    0x48, 0x68, 0x3d, 0x91, 0x97, 0x8e, 0x31, 0xeb, 0x3d, 0xdd, 0xb8, 0xb0, 0x47, 0x34, 0x82, 0xd2,
+    0xb8, 0x8a, 0x5f, 0x62, 0x59, 0x49, 0xfd, 0x8f, 0x58, 0xa5, 0x61, 0xe6, 0x96, 0xbd, 0x4c, 0x27,
+    0xd0, 0x5b, 0x38, 0xdb, 0xb2, 0xed, 0xf0, 0x1e, 0x66, 0x4e, 0xfd, 0x81, 0xbe, 0x1e, 0xa8, 0x93,
+    0x68, 0x8c, 0xe6, 0x8a, 0xa2, 0xd5, 0x1c, 0x59, 0x58, 0xf8, 0xbb, 0xc6, 0xeb, 0x4e, 0x89, 0xee,
+    0x67, 0xd2, 0xc0, 0x32, 0x09, 0x54, 0xd5, 0x72, 0x12, 0xca, 0xc7, 0x22, 0x9f, 0xf1, 0xd6, 0xea,
+    0xf0, 0x39, 0x28, 0xbd, 0x51, 0x51, 0x1f, 0x8d, 0x88, 0xd8, 0x47, 0x73, 0x6c, 0x7d, 0xe2, 0x73,
+    0x0d, 0x59, 0x78, 0xe5, 0x41, 0x07, 0x13, 0x16, 0x09, 0x78, 0x86, 0x77, 0x11, 0xbf, 0x55, 0x39,
+    0xa0, 0xbf, 0xc4, 0xc3, 0x50, 0xc2, 0xbe, 0x57, 0x2b, 0xaf, 0x0e, 0xe2, 0xe2, 0xfb, 0x16, 0xcc,
+    0xfe, 0xa0, 0x80, 0x28, 0xd9, 0x9a, 0xc4, 0x9a, 0xeb, 0xb7, 0x59, 0x37, 0xdd, 0xce, 0x11, 0x1c,
+    0xda, 0xb6, 0x2f, 0xff, 0x3c, 0xea, 0x8b, 0xa2, 0x23, 0x3d, 0x1e, 0x56, 0xfb, 0xc5, 0xc5, 0xa1,
+    0xe7, 0x26, 0xde, 0x63, 0xfa, 0xdd, 0x2a, 0xf0, 0x16, 0xb1, 0x19, 0x17, 0x7f, 0xa3, 0xd9, 0x71,
+    0xa2, 0xd9, 0x27, 0x71, 0x73, 0xfc, 0xe5, 0x5b, 0x67, 0x74, 0x5a, 0xf0, 0xb7, 0xc2, 0x1d, 0x59,
+    0x7d, 0xbe, 0xb9, 0x3e, 0x6a, 0x32, 0xf3, 0x41, 0xc4, 0x9a, 0x5a, 0x8b, 0xe9, 0xe8, 0x25, 0x08,
+    0x8d, 0x1f, 0x2a, 0xa4, 0x51, 0x55, 0xd6, 0xc8, 0xae, 0x15, 0x36, 0x7e, 0x4e, 0xb0, 0x03, 0xb8,
+    0xfd, 0xf7, 0x85, 0x10, 0x71, 0x94, 0x97, 0x39, 0xf9, 0xff, 0xf0, 0x90, 0x23, 0xea, 0xf4, 0x51,
+    0x04, 0xd2, 0xa8, 0x4a, 0x45, 0x90, 0x6e, 0xed, 0x46, 0x71, 0xa4, 0x4d, 0xc2, 0x8d, 0x27, 0x98,
+    0x7b, 0xb5, 0x5d, 0xf6, 0x9e, 0x9e, 0x85, 0x61, 0xf6, 0x1a, 0x80, 0xa7, 0x26, 0x99, 0x50, 0x38,
+    0x65, 0xfe, 0xd9, 0xb7, 0xee, 0x72, 0xa8, 0xe1, 0x7a, 0x19, 0xc4, 0x08, 0x14, 0x4f, 0x4b, 0x29,
+    0xaf, 0xef, 0x70, 0x31, 0xc3, 0xa6, 0xd8, 0x57, 0x16, 0x10, 0xb4, 0x2c, 0x9f, 0x42, 0x12, 0x45,
+    0xa8, 0x8f, 0x19, 0x7e, 0x16, 0x81, 0x2b, 0x03, 0x11, 0x59, 0xb6, 0x5b, 0x96, 0x87, 0xe5, 0xb3,
+    0xe9, 0x34, 0xc5, 0x22, 0x5a, 0xe9, 0x8a, 0x79, 0xba, 0x73, 0xd2, 0xb3, 0x99, 0xd7, 0x35, 0x10,
+    0xef, 0xfa, 0xd1, 0x9e, 0x53, 0xb8, 0x45, 0x0f, 0x0b, 0xa8, 0xfc, 0xe1, 0x01, 0x2f, 0xd9, 0x8d,
+    0x26, 0x0a, 0x74, 0xaa, 0xaa, 0x13, 0xfa, 0xe2, 0x49, 0xa0, 0x06, 0xb1, 0xc3, 0x4f, 0x5b, 0xa0,
+    0xb8, 0x82, 0xf2, 0x63, 0x78, 0x22, 0x2f, 0xb3, 0x6f, 0x22, 0x83, 0xc2, 0x43, 0xf0, 0xff, 0xeb,
+    0x5f, 0x1b, 0xb4, 0x14, 0xa0, 0xa7, 0x0d, 0x55, 0xe3, 0xd4, 0x0a, 0x56, 0xb6, 0xcb, 0xc8, 0x8a,
+    0xe1, 0xf0, 0x3b, 0x7b, 0x28, 0x82, 0xd9, 0x8d, 0xee, 0xa2, 0x8e, 0x14, 0x5c, 0x9d, 0xed, 0xfd,
+    0x8e, 0xaf, 0x1c, 0xef, 0x2e, 0xd9, 0x4a, 0x8b, 0x05, 0x0f, 0x89, 0x64, 0xf4, 0x6d, 0x1e, 0xa0,
+    0xd0, 0xc2, 0xa4, 0x3e, 0x0d, 0xda, 0x61, 0x82, 0xad, 0xbf, 0x4f, 0x6e, 0xd1, 0x75, 0xb6, 0x74,
+    0x22, 0x57, 0x85, 0x9b, 0xf2, 0x2f, 0x3a, 0x41, 0x7e, 0xcf, 0x1f, 0x9d, 0x89, 0x31, 0x7b, 0x5e,
+    0x53, 0x9d, 0x58, 0x7a, 0xf1, 0x6b, 0x9e, 0x13, 0x13, 0xe0, 0x45, 0x14, 0xff, 0xa6, 0x4b, 0xa8,
+    0xb3, 0xff, 0x2b, 0x83, 0x21, 0xf8, 0x81, 0x1c, 0xb3, 0xfb, 0x02, 0x2c, 0x8f, 0x64, 0x4e, 0x70,
+    0xa4, 0xb8, 0x0a, 0x2f, 0xbf, 0xee, 0x60, 0x4a, 0xbb, 0x73, 0x79, 0x09, 0x1e, 0xa8, 0xe6, 0xc5,
+    0xc7, 0x4d, 0xfc, 0x02, 0x83, 0x66, 0x6b, 0x40, 0xc0, 0x79, 0x38, 0x70, 0x02, 0x82, 0x04, 0xa1,
+    0x36, 0xbf, 0x5d, 0xa9, 0x56, 0x8e, 0xb7, 0x98, 0xd3, 0x49, 0x03, 0x8b, 0xdb, 0x0c, 0x11, 0xe0,
+    0x34, 0x45, 0xe7, 0x84, 0x7c, 0xb5, 0x06, 0x9c, 0x75, 0xcf, 0x28, 0xac, 0x60, 0x1c, 0x77, 0x99,
+    0xd9, 0x58, 0x21, 0x0d, 0xdb, 0xcb, 0x22, 0x6e, 0x51, 0xaf, 0xef, 0x9f, 0x1d, 0xe4, 0x7b, 0x07,
+    0x38, 0x73, 0xd6, 0xd3, 0xf9, 0x74, 0x56, 0xbe, 0xde, 0x08, 0x50, 0x82, 0xe7, 0x4a, 0x29, 0x8b,
+    0x2c, 0xd4, 0x8f, 0x4b, 0x30, 0x93, 0x15, 0x5f, 0x36, 0x6c, 0x8f, 0xa6, 0x01, 0xc6, 0xaf, 0x85,
+    0x8d, 0xfa, 0x32, 0xc0, 0x84, 0x91, 0xb2, 0xa2, 0x98, 0x87, 0xf9, 0x03, 0x35, 0x94, 0x9a, 0x5d,
+    0x6e, 0xda, 0xa6, 0x79, 0x88, 0x2a, 0x3a, 0x95, 0xd6, 0xbf, 0x6d, 0x97, 0x0a, 0x22, 0x1f, 0x4b,
+    0x9d, 0x3d, 0x8c, 0xbf, 0x38, 0x4a, 0xf8, 0x1a, 0xac, 0x95, 0xe2, 0xb3, 0x29, 0x4e, 0x04, 0x78,
+    0x9a, 0xc8, 0x37, 0x27, 0xa5, 0xdc, 0x04, 0x55, 0x9f, 0x96, 0xaf, 0x41, 0xd8, 0xa0, 0x53, 0x51,
+    0x6f, 0xee, 0xee, 0xbc, 0x52, 0x74, 0x6e, 0xb6, 0xab, 0x28, 0x19, 0xe0, 0x91, 0x08, 0x71, 0x0d,
+    0x83, 0x5f, 0x01, 0x1f, 0xa6, 0x30, 0x65, 0x87, 0x2a, 0xd3, 0x34, 0xd5, 0xcd, 0xff, 0xb2, 0xb2,
+    0x31, 0x05, 0x07, 0xe9, 0x2f, 0xc9, 0x93, 0xae, 0x31, 0x7d, 0xa9, 0x7f, 0x4f, 0x30, 0x9c, 0xda,
+    0xf0, 0xf6, 0x7e, 0xd9, 0x9d, 0x90, 0x21, 0x55, 0x76, 0x08, 0x38, 0x49, 0xf9, 0x53, 0xb2, 0x46,
+    0xd7, 0xfe, 0xdb, 0x3f, 0xdb, 0x67, 0x67, 0x98, 0x50, 0xa5, 0xad, 0x40, 0x4e, 0x64, 0x14, 0x7f,
+    0xb7, 0xcf, 0x4f, 0x6a, 0xed, 0xdd, 0x05, 0xaf, 0xb4, 0xb8, 0x34, 0x96, 0x8d, 0x1f, 0xe8, 0x80,
+    0x14, 0x96, 0x0d, 0xce, 0x5d, 0x94, 0x22, 0x36, 0x52, 0x6e, 0x12, 0xa4, 0x78, 0xd6, 0x9e, 0x5f,
+    0xbe, 0x69, 0x70, 0x31, 0x0b, 0x30, 0x8c, 0x06, 0x84, 0x50, 0x18, 0xcf, 0xc7, 0xb2, 0xab, 0x43,
+    0x0a, 0x13, 0xa6, 0xb1, 0xac, 0x7b, 0xb0, 0x2c, 0xcc, 0xbb, 0x3d, 0x91, 0x1a, 0xc2, 0xf1, 0x10,
+    0x68, 0x61, 0x3f, 0xbe, 0x02, 0x9b, 0xfd, 0xce, 0x02, 0xcf, 0x5c, 0xd3, 0x89, 0x50, 0xed, 0x72,
+    0xc8, 0x39, 0x44, 0xed, 0xfb, 0xc7, 0x56, 0x15, 0xaf, 0x87, 0xf8, 0x64, 0xc0, 0x51, 0xf3, 0xc5,
+    0x54, 0x56, 0xc5, 0x41, 0x28, 0x63, 0xa4, 0x0c, 0x06, 0xd1, 0xda, 0xb5, 0x62, 0xbd, 0xff, 0x05,
+    0x71, 0xb8, 0xd3, 0xc3, 0x91, 0x7b, 0xbd, 0x30, 0x08, 0x80, 0xbb, 0xa5, 0xe9, 0x98, 0x23, 0x9b,
+    0x95, 0xfa, 0x91, 0xb7, 0xd6, 0x41, 0x6d, 0x4f, 0x39, 0x8b, 0x3a, 0xdb, 0xcd, 0x30, 0x98, 0x3e,
+    0xd3, 0x59, 0x2b, 0x4d, 0x9e, 0xf7, 0xd4, 0x23, 0x6f, 0xd0, 0x0f, 0x50, 0xd9, 0x8a, 0xa5, 0x3a,
+    0x23, 0x5a, 0xc4, 0x17, 0x27, 0x20, 0xf7, 0x7d, 0x96, 0x17, 0x26, 0x72, 0x98, 0x0c, 0xfe, 0x8f,
+    0xf7, 0xa5, 0xa7, 0x02, 0x78, 0x3e, 0xdc, 0x2b, 0xa3, 0x1b, 0x22, 0x59, 0x01, 0x5a, 0x11, 0x2f,
+    0xc7, 0xf4, 0x68, 0xa9, 0xc2, 0xf9, 0x46, 0x40, 0x39, 0x00, 0x2d, 0x30, 0xef, 0x67, 0x8b, 0x4c,
+    0xb7, 0x98, 0xbc, 0x11, 0x62, 0x16, 0xbf, 0x7a, 0x9a, 0x7c, 0x18, 0xba, 0x03, 0xb7, 0xb5, 0x8f,
+    0xd0, 0x75, 0x15, 0xd3, 0x11, 0x50, 0x49, 0xd3, 0x61, 0x4b, 0xe7, 0xa0, 0x7e, 0x74, 0x43, 0x00,
+    0x75, 0x0d, 0xf1, 0xd2, 0xc5, 0x87, 0x53, 0x38, 0x90, 0x59, 0xea, 0xfc, 0x3d, 0x78, 0x5c, 0xcd,
+    0xd3, 0x1c, 0x07, 0x64, 0x8b, 0xed, 0xc0, 0x3a, 0x5c, 0x3b, 0x8a, 0xd4, 0x6d, 0x06, 0x4d, 0x59,
+    0xc1, 0x3d, 0x57, 0x37, 0x47, 0x29, 0xfc, 0x4e, 0x29, 0x53, 0x62, 0xe2, 0xa5, 0x19, 0x12, 0x04,
+    0x53, 0x04, 0x28, 0xbc, 0x15, 0x22, 0xaf, 0xa2, 0x8f, 0xf5, 0xfe, 0x16, 0x55, 0xe3, 0x04, 0xca,
+    0x5b, 0xc8, 0xc2, 0x7a, 0xd0, 0xe0, 0xc6, 0xa3, 0x9d, 0xd4, 0xdf, 0x28, 0x95, 0x6c, 0x14, 0xb3,
+    0x8c, 0xc9, 0x36, 0x82, 0xce, 0xfe, 0x40, 0x2b, 0xbd, 0x5e, 0x82, 0xd2, 0x9c, 0x46, 0x4e, 0x44,
+    0xeb, 0x5d, 0x37, 0xb4, 0x8f, 0xc5, 0x68, 0xdf, 0xe0, 0xcc, 0x6e, 0x8e, 0x16, 0xba, 0xea, 0x05,
+    0xe5, 0x13, 0x55, 0x90, 0xf1, 0x92, 0x94, 0xe7, 0x3e, 0x83, 0x67, 0xb0, 0x21, 0x6d, 0xbb, 0x81,
+    0x50, 0x30, 0xb9, 0xde, 0x55, 0x91, 0x3f, 0x08, 0x03, 0x9c, 0x42, 0x35, 0x1c, 0x59, 0xe5, 0x51,
+    0x5d, 0xd5, 0xaf, 0x8e, 0x08, 0x9a, 0x15, 0xe6, 0x25, 0xe8, 0xf6, 0xde, 0xe6, 0x39, 0x38, 0x6c,
+    0x46, 0x49, 0x7d, 0x7a, 0x26, 0x32, 0x88, 0x77, 0x4d, 0xe5, 0x81, 0xa7, 0xde, 0x96, 0x29, 0xb4,
+    0x1b, 0x44, 0x24, 0x14, 0x1f, 0x97, 0x8f, 0xb8, 0x33, 0x12, 0x08, 0xef, 0xde, 0xc3, 0xc6, 0xe0,
+    0xde, 0x39, 0xbc, 0x57, 0x06, 0x3f, 0x3d, 0xcd, 0x6c, 0x47, 0x03, 0x73, 0xc0, 0x88, 0x91, 0xea,
+    0x29, 0xcb, 0xc7, 0xcc, 0x6d, 0x64, 0x83, 0xb8, 0x88, 0x90, 0x83, 0xac, 0xe8, 0x6a, 0xa7, 0xb5,
+    0x1b, 0x1c, 0x2c, 0xfe, 0x6e, 0x2a, 0xd1, 0x8d, 0x97, 0xce, 0x36, 0xfb, 0xc5, 0x6e, 0xa4, 0x2f,
+    0xae, 0x97, 0xe6, 0xa7, 0xac, 0x11, 0x48, 0x64, 0x47, 0x8c, 0x36, 0x6d, 0xf1, 0xeb, 0xb1, 0xe7,
+    0xb1, 0x1a, 0x90, 0x98, 0x50, 0x4f, 0xd5, 0x97, 0x5b, 0xdf, 0x1f, 0x49, 0xdc, 0x70, 0x00, 0x2b,
+    0x63, 0xc1, 0x73, 0x9a, 0x9d, 0x26, 0x3f, 0xba, 0xd4, 0x07, 0x3f, 0x6a, 0x9f, 0x6c, 0x2b, 0x8a,
+    0xf4, 0xb4, 0xc3, 0x32, 0xa1, 0x03, 0xa0, 0xcf, 0xfa, 0x5d, 0xee, 0xb2, 0xd0, 0x62, 0xca, 0x3c,
+    0x21, 0x5f, 0xd3, 0x60, 0x02, 0x6b, 0xe7, 0xc5, 0x16, 0x4f, 0x4a, 0x44, 0x24, 0xef, 0x74, 0x94,
+    0x88, 0x04, 0xd6, 0x6f, 0x46, 0x48, 0x77, 0x32, 0xc8, 0x20, 0x2c, 0x79, 0x54, 0x78, 0x64, 0x7b,
+    0x4e, 0xa7, 0x1d, 0x62, 0x7c, 0x08, 0x60, 0x24, 0xcc, 0xa3, 0x54, 0xa4, 0x1f, 0x08, 0x77, 0xb3,
+    0x8f, 0x19, 0xb3, 0x77, 0x4a, 0xd2, 0x09, 0x5c, 0x8d, 0xa5, 0x3b, 0x06, 0x9e, 0x21, 0xc7, 0x6a,
+    0xe2, 0xd2, 0x00, 0x7e, 0x16, 0x71, 0x9e, 0xd4, 0x00, 0x80, 0xd3, 0x34, 0xf7, 0xda, 0x52, 0xe9,
+    0xf5, 0xa5, 0x99, 0x04, 0x39, 0xca, 0xf0, 0x83, 0xa9, 0x5b, 0x83, 0x3f, 0x02, 0xad, 0x10, 0xa0,
+    0x8c, 0x1a, 0x6d, 0x0f, 0x26, 0x0c, 0x00, 0x72, 0x85, 0xbd, 0x4a, 0x2f, 0x47, 0x70, 0x3a, 0x5a,
+    0xef, 0x46, 0x52, 0x87, 0xd2, 0x53, 0xb1, 0x8a, 0xc2, 0x25, 0x14, 0x31, 0x62, 0x10, 0xff, 0x56,
+    0x68, 0x14, 0xb1, 0x0f, 0x87, 0xa2, 0x93, 0xd6, 0xf1, 0x99, 0xd3, 0xc3, 0x95, 0x99, 0x90, 0xd0,
+    0xc1, 0x26, 0x8b, 0x4f, 0x50, 0xd5, 0xf9, 0xfc, 0xef, 0xbb, 0xf2, 0x37, 0xbd, 0x0c, 0x28, 0xb8,
+    0x01, 0x82, 0xd6, 0x65, 0x97, 0x41, 0xf1, 0x4f, 0x10, 0xbf, 0xbb, 0x21, 0xbb, 0xa1, 0x2a, 0xb6,
+    0x20, 0xaa, 0x23, 0x96, 0xf5, 0x6c, 0x06, 0x86, 0xb4, 0xea, 0x90, 0x17, 0x99, 0x02, 0x24, 0x21,
+    0x6b, 0x2f, 0xe8, 0xad, 0x76, 0xc4, 0xa9, 0x14, 0x8e, 0xef, 0x9a, 0x86, 0xa3, 0x63, 0x5a, 0x6a,
+    0xa7, 0x7b, 0xc1, 0xdc, 0xfb, 0x6f, 0xba, 0x59, 0xa7, 0x7d, 0xfd, 0xa9, 0xb7, 0x53, 0x0d, 0xc0,
+    0xca, 0x86, 0x48, 0xc8, 0xd9, 0x73, 0x73, 0x8e, 0x01, 0xba, 0xb8, 0xf0, 0x8b, 0x49, 0x05, 0xe8,
+    0x4a, 0xa4, 0x64, 0x1b, 0xd6, 0x02, 0x41, 0x0c, 0xd9, 0x75, 0x20, 0x26, 0x5f, 0x2f, 0x23, 0x1f,
+    0x2b, 0x35, 0xe1, 0x5e, 0xb2, 0xfa, 0x04, 0xd2, 0xbd, 0x94, 0xd5, 0xa7, 0x7a, 0xba, 0xf1, 0xe0,
+    0xe1, 0x61, 0x01, 0x0a, 0x99, 0x00, 0x87, 0xf5, 0xb4, 0x6e, 0xa9, 0x88, 0xb2, 0xbc, 0x05, 0x12,
+    0xfd, 0xa0, 0xfa, 0x92, 0x3d, 0xad, 0xd6, 0xc4, 0x5c, 0x53, 0x01, 0xd0, 0x94, 0x83, 0x67, 0x32,
+    0x65, 0xb5, 0xab, 0x2e, 0x10, 0xf4, 0xba, 0x52, 0x0f, 0x6b, 0xba, 0xd5, 0x64, 0xa5, 0xc3, 0xd5,
+    0xe2, 0x7b, 0xdb, 0x08, 0x0f, 0x7d, 0x20, 0xe1, 0x32, 0x96, 0xa3, 0x18, 0x19, 0x54, 0xc3, 0x9c,
+    0x64, 0x9c, 0x94, 0x3e, 0xbe, 0x17, 0xdf, 0x5c, 0x1f, 0x7a, 0xae, 0x0a, 0x8f, 0xe1, 0x26, 0xc4,
+    0x77, 0x58, 0x5a, 0x5d, 0x4d, 0x64, 0x8a, 0x0d, 0x00, 0x8b, 0x6a, 0xf5, 0xe8, 0xcd, 0x31, 0xbe,
+    0x69, 0xa9, 0x29, 0x6d, 0x4f, 0x3f, 0xd2, 0x5e, 0xd8, 0x6f, 0x22, 0x1e, 0x4b, 0x93, 0xf6, 0x5f,
+    0x59, 0x29, 0x96, 0x75, 0x33, 0x62, 0x4b, 0x92, 0x35, 0x75, 0x0c, 0x30, 0x70, 0x75, 0x50, 0xb5,
+    0x85, 0x36, 0xd1, 0x09, 0xa7, 0x13, 0x1c, 0x5a, 0x5b, 0xbe, 0x4a, 0x57, 0x15, 0x56, 0x7c, 0x12,
+    0x53, 0x4a, 0xec, 0x76, 0x60, 0x76, 0x1e, 0xeb, 0xb9, 0xfa, 0xe2, 0x89, 0x1c, 0x77, 0x45, 0x89,
+    0xb8, 0x0e, 0x56, 0x6a, 0xd5, 0x57, 0xdd, 0xef, 0x73, 0x67, 0x19, 0x6b, 0x72, 0x27, 0xea, 0x98,
+    0x70, 0xef, 0x09, 0xdd, 0xfe, 0xc7, 0x9d, 0x6b, 0x93, 0x19, 0xa6, 0x87, 0x9b, 0x52, 0x05, 0xd7,
+    0x6b, 0xf7, 0xab, 0xa5, 0xac, 0xf3, 0x3a, 0xfb, 0x59, 0xd1, 0x7f, 0xc5, 0x4e, 0x68, 0x38, 0x3d,
+    0x6b, 0xe5, 0xa0, 0x8e, 0x9b, 0x66, 0xda, 0x53, 0xdc, 0xde, 0x00, 0x8b, 0xb2, 0x94, 0xb8, 0x58,
+    0x2b, 0xd1, 0x32, 0xcd, 0xcc, 0x49, 0x95, 0x9f, 0xdb, 0xc2, 0x1e, 0x52, 0x72, 0x18, 0x80, 0xc8,
+    0xad, 0x03, 0x52, 0xc7, 0x9f, 0x03, 0xa4, 0x3b, 0xbd, 0x84, 0xc4, 0xcd, 0xfd, 0xc6, 0xc5, 0x29,
+    0x00, 0x5e, 0x1e, 0x7c, 0xd9, 0xa3, 0x49, 0xa7, 0x16, 0x8a, 0x35, 0x56, 0x9b, 0xa5, 0xde, 0xa8,
+    0x18, 0x96, 0x8d, 0x5a, 0x91, 0x46, 0x6b, 0xd6, 0xe6, 0x4e, 0x20, 0xbf, 0x62, 0x41, 0x71, 0x98,
+    0xaf, 0xc4, 0xe8, 0x1c, 0x28, 0xdd, 0x77, 0xed, 0x40, 0x28, 0x23, 0x23, 0x98, 0xb5, 0x2f, 0xbd,
+    0xe8, 0x6b, 0xc8, 0x4f, 0x47, 0x5b, 0x90, 0x16, 0x71, 0x0c, 0xe2, 0xaa, 0xbc, 0x11, 0xa0, 0x6b,
+    0x4d, 0xba, 0xc9, 0x01, 0xec, 0x16, 0xcf, 0x36, 0x5c, 0xa3, 0xf2, 0xd5, 0x38, 0x13, 0x94, 0x8a,
+    0x69, 0x3a, 0x0f, 0x93, 0xe7, 0x9c, 0x46, 0xca, 0x5d, 0x5a, 0x6d, 0xca, 0x3d, 0x28, 0xca, 0x50,
+    0xad, 0x18, 0xbd, 0x13, 0xfc, 0xa5, 0x50, 0x59, 0xdd, 0x9b, 0x18, 0x5f, 0x79, 0xf9, 0xc4, 0x71,
+    0x96, 0xa4, 0xe8, 0x1b, 0x21, 0x04, 0xbc, 0x46, 0x0a, 0x05, 0x1e, 0x02, 0xf2, 0xe8, 0x44, 0x4f,
+];
+
+pub static SIGNATURE: [u8; 3309] = [
+    0xd4, 0x70, 0xe4, 0xfe, 0x28, 0x12, 0x8c, 0xa7, 0x04, 0x1a, 0x10, 0xe7, 0x52, 0x0a, 0x39, 0x90,
+    0x42, 0x62, 0xba, 0x0d, 0x82, 0x67, 0x50, 0x9f, 0xa0, 0x76, 0xb6, 0xa2, 0x3f, 0xa0, 0x2f, 0xad,
+    0x75, 0x61, 0xfc, 0xcc, 0xb6, 0x63, 0x03, 0x61, 0x11, 0x5f, 0x8b, 0xf1, 0x15, 0xcb, 0x52, 0x76,
+    0x7e, 0x56, 0x30, 0xa5, 0x9a, 0x85, 0x8c, 0x1b, 0xff, 0xa7, 0xc8, 0xb9, 0xe4, 0x58, 0xeb, 0x7b,
+    0xb4, 0x85, 0x19, 0xb3, 0x23, 0xd2, 0x3b, 0x03, 0x02, 0xaa, 0x6c, 0x8e, 0x5b, 0x19, 0x9f, 0x70,
+    0xef, 0xba, 0x1c, 0x7d, 0xd8, 0x9a, 0xc6, 0x8d, 0xb4, 0x33, 0x9c, 0x2a, 0x77, 0x2d, 0x0a, 0x06,
+    0x5d, 0x94, 0x6a, 0x4b, 0xf2, 0xa4, 0x7d, 0xc3, 0x9a, 0x13, 0x63, 0x96, 0x22, 0xec, 0x5d, 0x08,
+    0x4a, 0xd6, 0xca, 0x8e, 0xe0, 0x2c, 0x58, 0x91, 0x71, 0xff, 0x8b, 0x4c, 0xb2, 0x62, 0xab, 0xeb,
+    0xe1, 0x46, 0xeb, 0xe3, 0xec, 0x2a, 0xe5, 0x8c, 0x9b, 0xfc, 0x06, 0x8b, 0x3f, 0x92, 0xcc, 0x6c,
+    0xcb, 0x6f, 0xef, 0x21, 0x3d, 0x84, 0x14, 0x9d, 0x65, 0x12, 0x4c, 0x90, 0x57, 0x3d, 0x0f, 0xd7,
+    0x11, 0x10, 0xa8, 0x5b, 0x7c, 0x09, 0x58, 0xba, 0x38, 0x70, 0x0f, 0x93, 0x0d, 0x8c, 0xaa, 0x96,
+    0xc0, 0xf1, 0xed, 0x5e, 0x09, 0x56, 0x38, 0x2b, 0x4b, 0xb9, 0xe7, 0x7d, 0x06, 0x6b, 0x74, 0xb4,
+    0xd9, 0x73, 0x17, 0x90, 0x49, 0x3c, 0x69, 0x50, 0x02, 0x20, 0x07, 0xde, 0x3c, 0x41, 0x7d, 0x00,
+    0xb1, 0x38, 0x34, 0x11, 0x3e, 0x4f, 0x82, 0xea, 0x95, 0x4e, 0x48, 0x5f, 0xce, 0xb4, 0x3d, 0xcb,
+    0x5f, 0xbf, 0xeb, 0xc4, 0xb1, 0xda, 0x65, 0x25, 0x78, 0x50, 0x79, 0x67, 0xf2, 0x55, 0xa9, 0x16,
+    0xe7, 0xa7, 0x44, 0xef, 0xb5, 0xc4, 0x02, 0xf7, 0xdc, 0x78, 0xa3, 0xf0, 0x16, 0xde, 0x86, 0xd5,
+    0x3d, 0xa3, 0x0a, 0x9c, 0x25, 0xb3, 0x43, 0x28, 0x5a, 0xec, 0x2b, 0xb7, 0x63, 0xbe, 0xe4, 0x61,
+    0x62, 0xab, 0x41, 0xe2, 0xcf, 0xc5, 0xbf, 0x27, 0x76, 0x7f, 0x11, 0xef, 0x9f, 0xc5, 0x7d, 0x9e,
+    0x89, 0xae, 0x01, 0x68, 0x3b, 0x03, 0x28, 0x04, 0xc8, 0x1c, 0xa6, 0x7c, 0xc4, 0xa0, 0x41, 0x41,
+    0x4b, 0xf0, 0x39, 0xb7, 0x66, 0xa9, 0x8f, 0xde, 0x5c, 0x53, 0x99, 0xae, 0x48, 0x92, 0x59, 0x34,
+    0x00, 0xca, 0x96, 0x42, 0x0b, 0xd8, 0xe0, 0xfa, 0xcf, 0xd4, 0xbc, 0xaa, 0x39, 0x38, 0xa3, 0x61,
+    0xc3, 0x17, 0xb2, 0x21, 0xd4, 0x5e, 0xb7, 0xb7, 0xba, 0xbc, 0x03, 0x64, 0x2d, 0x23, 0x41, 0x94,
+    0x2c, 0x52, 0xf6, 0x4e, 0x05, 0x5f, 0x2b, 0xe1, 0x13, 0x9d, 0x72, 0xd8, 0xd8, 0x1a, 0x6f, 0x02,
+    0xa6, 0xa0, 0x04, 0x86, 0xab, 0x64, 0x7d, 0x33, 0x0e, 0x01, 0xf5, 0xd4, 0xe3, 0x1c, 0x12, 0xdc,
+    0x0d, 0x09, 0xa4, 0xa5, 0x97, 0x88, 0x74, 0x51, 0xc4, 0xa3, 0xc2, 0xeb, 0x8b, 0x5e, 0xd7, 0xa0,
+    0x51, 0xd0, 0xfc, 0x94, 0xaf, 0x59, 0xcf, 0xae, 0xbc, 0x72, 0x31, 0xce, 0x52, 0x22, 0x54, 0xc4,
+    0x0b, 0x06, 0xae, 0x17, 0x98, 0xad, 0x76, 0xa7, 0x6e, 0xb6, 0xf1, 0x55, 0xc4, 0x63, 0x50, 0x54,
+    0x7d, 0x95, 0x4e, 0x79, 0x5d, 0xf2, 0x7e, 0x59, 0xf9, 0x32, 0x1f, 0x00, 0xcd, 0x45, 0x86, 0x8c,
+    0x5e, 0x77, 0xd5, 0x30, 0xfa, 0x4c, 0xfb, 0x79, 0x35, 0xcf, 0xd1, 0xfb, 0x54, 0x92, 0x8f, 0xbe,
+    0xf6, 0xd0, 0x1d, 0x15, 0xcb, 0x28, 0x8d, 0x56, 0x5b, 0x6c, 0x00, 0x47, 0x34, 0xd0, 0xd9, 0x8d,
+    0xed, 0x36, 0x29, 0x91, 0x26, 0x56, 0xbd, 0x68, 0x12, 0xf4, 0xf5, 0x3c, 0x0c, 0x06, 0x23, 0x02,
+    0x32, 0x97, 0x85, 0x14, 0x2e, 0xd8, 0xaf, 0x82, 0x9c, 0xde, 0x62, 0x22, 0xa5, 0x7d, 0x18, 0xcf,
+    0x9c, 0x29, 0x4c, 0x17, 0x07, 0xb2, 0x44, 0xfc, 0x28, 0xe2, 0x36, 0x3b, 0x8c, 0x7c, 0x1d, 0x9a,
+    0xe6, 0x96, 0xce, 0xe7, 0xb5, 0xba, 0x3e, 0xe7, 0x8b, 0x36, 0xef, 0x23, 0x12, 0x6a, 0x8c, 0x1f,
+    0x2f, 0x20, 0x23, 0x61, 0xc8, 0x93, 0x7d, 0x34, 0x7e, 0xda, 0x37, 0x63, 0x88, 0x62, 0x44, 0x1c,
+    0x76, 0x87, 0xec, 0x8b, 0x79, 0x1f, 0x4b, 0xb2, 0xfb, 0x1f, 0x2a, 0xcd, 0xa9, 0x2d, 0xc5, 0x79,
+    0x03, 0x5d, 0x37, 0xe5, 0xd3, 0xf0, 0x47, 0x6b, 0xc3, 0x8e, 0x26, 0x0e, 0x29, 0xe1, 0xb8, 0x1a,
+    0x56, 0x54, 0x2c, 0x64, 0x50, 0xe2, 0x93, 0x71, 0xb2, 0x24, 0x0b, 0x35, 0x2c, 0xd2, 0x7c, 0x8a,
+    0xf5, 0x0b, 0x6d, 0x72, 0xbf, 0x5c, 0x87, 0x1d, 0x9e, 0x0e, 0xc7, 0x75, 0xec, 0x33, 0x63, 0xb0,
+    0xdc, 0x47, 0x68, 0x97, 0x4f, 0x5b, 0x3b, 0xc9, 0x85, 0x2b, 0xf6, 0x02, 0xd6, 0xbc, 0x47, 0x9e,
+    0x9a, 0x6e, 0x08, 0xda, 0xf8, 0x3a, 0xdb, 0xcd, 0xee, 0xd2, 0x03, 0xc5, 0xad, 0x1b, 0xa5, 0x30,
+    0xba, 0x3d, 0x8f, 0xcc, 0xbf, 0x96, 0xd3, 0xa7, 0x55, 0xbe, 0xf8, 0x32, 0x5d, 0x56, 0xb7, 0x5a,
+    0xe0, 0x95, 0x3c, 0xce, 0x59, 0xec, 0xe6, 0xd3, 0xd6, 0xa7, 0x36, 0x2f, 0x0d, 0x3b, 0xe1, 0x2a,
+    0xb7, 0xb3, 0x33, 0xa2, 0x34, 0x41, 0xf8, 0x34, 0xb2, 0xa6, 0x10, 0x48, 0x8d, 0x0f, 0x12, 0xe4,
+    0x17, 0x50, 0xf6, 0x20, 0x49, 0xbf, 0x6c, 0xf6, 0xae, 0x75, 0xaf, 0xe5, 0x0f, 0x14, 0x64, 0x52,
+    0x80, 0xfc, 0xd1, 0xa8, 0x14, 0xe2, 0x51, 0x6e, 0x4b, 0x9d, 0xfd, 0x80, 0x21, 0x65, 0xd8, 0x91,
+    0xb8, 0x67, 0xee, 0x0a, 0x6b, 0x69, 0xe8, 0x96, 0x31, 0xc6, 0x59, 0xea, 0x87, 0xbb, 0x78, 0x93,
+    0x19, 0x02, 0x40, 0xac, 0x86, 0xc4, 0x3b, 0x35, 0x57, 0xd2, 0x4b, 0xde, 0xdc, 0x35, 0xb0, 0xac,
+    0x2c, 0xce, 0x87, 0x00, 0x13, 0x7c, 0xfb, 0x43, 0x8b, 0x47, 0xda, 0xad, 0x11, 0x4a, 0x16, 0x2e,
+    0x16, 0xa9, 0xeb, 0x2d, 0xf4, 0x83, 0xaa, 0x5b, 0x78, 0x5d, 0x39, 0xce, 0x88, 0x41, 0x7b, 0x88,
+    0x0c, 0x68, 0x3e, 0x0d, 0xab, 0x1a, 0x34, 0x4a, 0xcf, 0xb4, 0x34, 0x8c, 0xea, 0x71, 0x06, 0x13,
+    0xe0, 0x6d, 0x75, 0x2e, 0x35, 0x96, 0x02, 0xc7, 0x5a, 0x4d, 0xd2, 0x07, 0x27, 0x91, 0x4a, 0x94,
+    0xa2, 0x6e, 0xbf, 0xbc, 0x8a, 0xee, 0x27, 0x85, 0x98, 0xd3, 0x76, 0x63, 0x20, 0xc3, 0x74, 0xe7,
+    0x62, 0x07, 0x57, 0x42, 0xe6, 0x89, 0xdd, 0x83, 0xdc, 0x4d, 0xbb, 0xb4, 0x79, 0x0e, 0x06, 0x26,
+    0xbc, 0x2e, 0xec, 0x46, 0x1d, 0x3e, 0x22, 0x69, 0x9a, 0xe5, 0x49, 0x82, 0xcc, 0x05, 0xbd, 0x4a,
+    0xa9, 0x3d, 0xe3, 0xb4, 0x97, 0xf3, 0xf2, 0x2b, 0xeb, 0x73, 0x3e, 0x66, 0xcf, 0x80, 0x2b, 0x28,
+    0x7a, 0x4d, 0x90, 0x95, 0x46, 0x59, 0xa0, 0xe3, 0x1b, 0xf8, 0x9a, 0x74, 0x40, 0x28, 0x38, 0x13,
+    0x03, 0xf2, 0xbc, 0x20, 0x09, 0xff, 0x37, 0xbd, 0x88, 0x71, 0x18, 0x0f, 0x9d, 0x83, 0xba, 0xbc,
+    0x56, 0xa3, 0x2b, 0x6c, 0x9f, 0x99, 0x52, 0x85, 0x5d, 0xa7, 0x52, 0xbf, 0x40, 0x72, 0xc4, 0x21,
+    0x16, 0xc7, 0xc5, 0xa9, 0xf5, 0xf0, 0x0e, 0x3b, 0xe1, 0xde, 0xa2, 0x20, 0xfc, 0xde, 0x0b, 0x5e,
+    0xb1, 0xe1, 0xb3, 0x60, 0x7b, 0x67, 0xf0, 0x91, 0x86, 0x98, 0xe1, 0xea, 0x51, 0xc1, 0x43, 0xf9,
+    0xa9, 0xd9, 0xde, 0xd8, 0x34, 0x09, 0xfe, 0x27, 0xe1, 0x73, 0xf5, 0x99, 0x0a, 0x20, 0x28, 0xa8,
+    0x92, 0x63, 0x15, 0xe6, 0x04, 0x84, 0x8d, 0xb5, 0x5e, 0xd0, 0x48, 0x42, 0xf0, 0x62, 0xd3, 0x91,
+    0xa6, 0xd5, 0x9a, 0x99, 0x2b, 0xec, 0x16, 0xf9, 0xdb, 0x45, 0x40, 0xda, 0xe7, 0x26, 0xc6, 0x13,
+    0x8f, 0x3e, 0xc2, 0x34, 0x43, 0x4d, 0x28, 0x26, 0x13, 0x2d, 0x4b, 0x36, 0xc8, 0x91, 0xd6, 0xce,
+    0x43, 0xeb, 0x64, 0x38, 0x60, 0x9b, 0x2c, 0xed, 0x4e, 0x42, 0xbe, 0xba, 0x4d, 0xee, 0xb2, 0x3f,
+    0x98, 0xa0, 0x4e, 0xbd, 0x7f, 0x07, 0x78, 0xb0, 0x4a, 0x90, 0x64, 0xaf, 0x1b, 0x11, 0xb2, 0x37,
+    0xee, 0x43, 0x8a, 0x7f, 0xe6, 0xbe, 0x59, 0xb0, 0xf0, 0xa8, 0x8d, 0x8b, 0x0f, 0x57, 0x37, 0x6c,
+    0x18, 0xea, 0xf8, 0x32, 0x71, 0xf2, 0xd6, 0x63, 0x4c, 0xb7, 0x83, 0x0b, 0x61, 0x4d, 0x14, 0x67,
+    0x52, 0x42, 0x62, 0x60, 0xe4, 0x55, 0x88, 0xba, 0x35, 0x48, 0x37, 0xfc, 0x0f, 0x07, 0x51, 0x98,
+    0x43, 0xe0, 0xae, 0x2a, 0x20, 0xeb, 0xe0, 0xe6, 0x86, 0xdc, 0xc8, 0x05, 0xe2, 0x9e, 0x41, 0x88,
+    0xdb, 0xb3, 0x45, 0x3d, 0x29, 0x56, 0x5d, 0x5a, 0xc5, 0x7b, 0x4d, 0x36, 0xa4, 0x06, 0xd7, 0x15,
+    0xcc, 0x39, 0x60, 0x7d, 0x2b, 0x41, 0xe2, 0x80, 0xb9, 0x88, 0x61, 0x08, 0x95, 0x1b, 0x56, 0xa4,
+    0x5c, 0xb5, 0x63, 0xe9, 0x15, 0xe0, 0x11, 0x12, 0x8e, 0x8c, 0xfb, 0x27, 0x25, 0xe7, 0xaf, 0x49,
+    0xa6, 0xae, 0x0e, 0x7b, 0x16, 0xbb, 0x2b, 0x27, 0x96, 0x74, 0x1d, 0x07, 0x96, 0x25, 0xee, 0xe4,
+    0x96, 0x0a, 0x73, 0xa3, 0x0f, 0xa8, 0x40, 0x06, 0x9a, 0x78, 0x5d, 0x3d, 0x99, 0x3f, 0x97, 0x73,
+    0x35, 0x16, 0xc7, 0xc1, 0x44, 0x3e, 0x07, 0x2f, 0x5f, 0x91, 0x74, 0x1a, 0xd4, 0x6f, 0xc4, 0x32,
+    0x68, 0x1d, 0x5b, 0xe1, 0x07, 0xb8, 0x1f, 0x54, 0x9c, 0xdb, 0xbf, 0xf4, 0x04, 0x08, 0x9a, 0x12,
+    0x0c, 0xb6, 0xd6, 0xe9, 0x54, 0x37, 0xbb, 0x1c, 0xbd, 0x2e, 0xb7, 0xec, 0x61, 0x90, 0x18, 0xcb,
+    0x6f, 0x19, 0xc8, 0x0a, 0x3b, 0x1a, 0x0f, 0x5e, 0x82, 0x7a, 0x4c, 0x71, 0x0f, 0x78, 0x7d, 0x4b,
+    0x33, 0xeb, 0x04, 0x3d, 0x1f, 0x43, 0x2f, 0xc3, 0xaa, 0x15, 0x3a, 0x9a, 0xf9, 0xe3, 0x91, 0x98,
+    0xd8, 0x53, 0x76, 0x4f, 0x90, 0x57, 0xe4, 0x7d, 0x87, 0x71, 0x44, 0xe4, 0x75, 0xf3, 0x4a, 0x17,
+    0x1e, 0xc5, 0x8e, 0x06, 0x44, 0xbf, 0x82, 0xdc, 0x38, 0x02, 0xa4, 0x41, 0x5d, 0x73, 0xf8, 0x27,
+    0x4b, 0x51, 0x1b, 0x6a, 0xa8, 0xee, 0x6d, 0x09, 0xb4, 0x8f, 0x5c, 0x33, 0x7d, 0x51, 0xf3, 0x9c,
+    0x04, 0xc3, 0xb6, 0xd9, 0x5c, 0x15, 0x8b, 0x01, 0x2c, 0x1d, 0xfa, 0xda, 0xcf, 0x86, 0xf4, 0xc3,
+    0xc2, 0x6e, 0xbe, 0xf9, 0x44, 0x9f, 0x30, 0x66, 0xd4, 0x8e, 0x44, 0x96, 0x53, 0xa2, 0x83, 0x23,
+    0xb5, 0x2c, 0x27, 0x0a, 0xc6, 0xf3, 0x2e, 0x9b, 0x89, 0xfd, 0x11, 0x43, 0x1e, 0x5d, 0x9c, 0x5f,
+    0x3a, 0x70, 0x46, 0x55, 0x46, 0x76, 0x8a, 0xa4, 0x7d, 0xe6, 0xf3, 0x58, 0x87, 0xcc, 0x94, 0xba,
+    0x02, 0x85, 0x1c, 0x66, 0x5d, 0x49, 0x4e, 0xa4, 0x91, 0xe4, 0x22, 0x43, 0xff, 0xf1, 0x80, 0xe9,
+    0xcd, 0x04, 0x53, 0xbd, 0x03, 0x82, 0xea, 0x0d, 0x40, 0x92, 0xa0, 0x11, 0xbd, 0x16, 0xd6, 0xc1,
+    0x6f, 0x52, 0x50, 0x64, 0xa0, 0xd7, 0x56, 0x11, 0xba, 0xc8, 0x5d, 0xb9, 0xc8, 0x12, 0x22, 0xca,
+    0x0d, 0x64, 0xb7, 0x4c, 0x5c, 0x84, 0xc8, 0xde, 0x55, 0x95, 0x64, 0xae, 0xf2, 0xa5, 0x5f, 0xe0,
+    0x28, 0x83, 0xe2, 0xf9, 0x37, 0x90, 0xfd, 0xb2, 0x71, 0xfe, 0x64, 0xb7, 0x87, 0x43, 0x98, 0xc9,
+    0x96, 0x56, 0x3b, 0x98, 0xd1, 0x41, 0xe7, 0x48, 0x33, 0x9a, 0xc1, 0x1f, 0xe6, 0x28, 0xd6, 0x59,
+    0xd2, 0xc9, 0xc1, 0xbe, 0x2f, 0xb8, 0xdb, 0x0b, 0xea, 0xa0, 0xfb, 0x4e, 0x77, 0x3c, 0x9e, 0xd1,
+    0x18, 0x42, 0xf8, 0xda, 0xda, 0xb4, 0x93, 0xbf, 0x95, 0x6b, 0x2e, 0x71, 0xd1, 0x2e, 0x4a, 0x1c,
+    0x67, 0x5a, 0x8b, 0x3b, 0x5b, 0xa7, 0xa9, 0x5c, 0x27, 0xd4, 0x3e, 0x73, 0x7b, 0xb7, 0x5e, 0x55,
+    0x52, 0x13, 0x98, 0xe1, 0x5b, 0xbc, 0x1f, 0x4c, 0x38, 0xfe, 0x25, 0x26, 0x26, 0xf3, 0xe6, 0xa1,
+    0xd2, 0xf5, 0x3a, 0xb5, 0x86, 0x7f, 0x3c, 0xd2, 0x17, 0xbc, 0x04, 0x73, 0xa7, 0x05, 0x83, 0xef,
+    0x08, 0x7e, 0x57, 0x20, 0xac, 0x09, 0xb9, 0x00, 0x01, 0x00, 0x43, 0x6d, 0x91, 0xc0, 0xd4, 0x6f,
+    0xd8, 0xdf, 0x45, 0xc9, 0x76, 0x4d, 0x10, 0x39, 0x49, 0xf5, 0x01, 0xae, 0x66, 0x0b, 0xbd, 0xdf,
+    0x89, 0x50, 0x0b, 0x9f, 0x89, 0xfc, 0x5e, 0x3b, 0x20, 0xe6, 0x4e, 0x9d, 0x8d, 0x0a, 0xad, 0x61,
+    0x38, 0x54, 0x72, 0x66, 0x43, 0x38, 0xb5, 0x83, 0xaf, 0xfe, 0x5d, 0x48, 0x7a, 0x1c, 0xec, 0x14,
+    0x46, 0x5e, 0x60, 0x91, 0x7c, 0x63, 0x68, 0xab, 0xfa, 0x36, 0x6f, 0x2e, 0xac, 0x7f, 0x45, 0xb2,
+    0xa0, 0x8a, 0x00, 0x50, 0xc2, 0x50, 0x3d, 0x51, 0x8b, 0x9d, 0xb8, 0x08, 0x94, 0x00, 0xc9, 0xf8,
+    0x60, 0x60, 0x23, 0x25, 0x0a, 0x19, 0x0d, 0x69, 0x1b, 0x50, 0xa9, 0x7d, 0xd5, 0xff, 0x42, 0x0a,
+    0x77, 0xc3, 0x4b, 0x72, 0x13, 0xf1, 0x4c, 0xc9, 0x2c, 0xa7, 0xee, 0xba, 0x0d, 0x5a, 0x7a, 0x62,
+    0xa7, 0xe3, 0x87, 0x92, 0xe5, 0x3b, 0x9f, 0x7a, 0xbf, 0x2a, 0x32, 0x3e, 0x7d, 0x66, 0x7a, 0x4b,
+    0x68, 0xae, 0xaa, 0x1b, 0xd8, 0xbf, 0x8c, 0xb6, 0x52, 0x9d, 0x42, 0x15, 0x18, 0xe9, 0x68, 0x4d,
+    0xf4, 0x2a, 0x16, 0x59, 0xc3, 0x34, 0xfb, 0xc5, 0x86, 0xa6, 0xe2, 0x62, 0x1b, 0x60, 0x51, 0x9a,
+    0x3b, 0x51, 0x39, 0xc0, 0x91, 0xac, 0xd2, 0x6c, 0x56, 0xce, 0xbb, 0xcc, 0x47, 0x49, 0x85, 0x2c,
+    0x21, 0x2e, 0x55, 0x90, 0x16, 0x53, 0xb0, 0x4f, 0xbf, 0xa3, 0x72, 0x0d, 0xcc, 0x5b, 0x96, 0x30,
+    0x6d, 0x71, 0x4e, 0xae, 0xf9, 0x53, 0x79, 0x8c, 0xc6, 0x9b, 0xdc, 0x91, 0x51, 0xd1, 0xba, 0x55,
+    0x25, 0xd8, 0x55, 0xd7, 0x40, 0x8a, 0xdd, 0x65, 0x32, 0xdf, 0x66, 0x47, 0x49, 0x7b, 0x56, 0x60,
+    0x71, 0x3d, 0x85, 0xaa, 0x0c, 0xee, 0x02, 0xd9, 0x9b, 0x7c, 0x2a, 0xee, 0x92, 0x72, 0xe7, 0x1b,
+    0xca, 0xdf, 0x84, 0x45, 0xdf, 0x1d, 0xad, 0x15, 0x68, 0x8b, 0xe7, 0x1e, 0xc6, 0x3b, 0x58, 0x91,
+    0x03, 0x66, 0xe4, 0x35, 0x4a, 0x37, 0xfe, 0xc2, 0x65, 0xe5, 0x8d, 0x83, 0x5e, 0x5b, 0x52, 0xb8,
+    0xc2, 0xb6, 0x6f, 0x9a, 0x6d, 0x2a, 0x28, 0x59, 0x61, 0xc0, 0x05, 0x00, 0xdc, 0xcd, 0xec, 0x47,
+    0xb1, 0x08, 0xf5, 0x14, 0xaf, 0x21, 0x7e, 0x17, 0x6c, 0x7b, 0x7a, 0x76, 0xa1, 0xab, 0x69, 0xb1,
+    0x68, 0x1d, 0xea, 0x07, 0xac, 0xb2, 0xe1, 0x21, 0xcd, 0x5f, 0x77, 0xc2, 0x60, 0xaf, 0xce, 0x37,
+    0xb9, 0xba, 0x10, 0xc5, 0xa0, 0x78, 0x99, 0x5e, 0x46, 0x0e, 0x58, 0xca, 0xae, 0xbd, 0x62, 0xf5,
+    0xf8, 0x41, 0x28, 0x33, 0xf8, 0xa5, 0x50, 0xb0, 0x83, 0x89, 0xa3, 0xea, 0xe9, 0x17, 0x72, 0x2e,
+    0x81, 0x5d, 0x7b, 0x68, 0x76, 0x58, 0x62, 0x5d, 0xe9, 0xd8, 0x32, 0xe6, 0x20, 0x3e, 0x42, 0xd6,
+    0x4c, 0xf7, 0x4f, 0x92, 0xb4, 0x9a, 0x8a, 0x28, 0x52, 0x5a, 0x21, 0xda, 0xdf, 0xf8, 0x9f, 0xf8,
+    0x75, 0x1d, 0x05, 0x73, 0x96, 0xb6, 0x15, 0x8f, 0x7b, 0x60, 0x49, 0xeb, 0x19, 0x47, 0xce, 0xe4,
+    0x68, 0xcf, 0x7b, 0x82, 0x21, 0x94, 0xf5, 0x94, 0xc0, 0x4a, 0x87, 0x48, 0x69, 0x32, 0xc7, 0x16,
+    0xd4, 0xff, 0xcb, 0x28, 0xf1, 0xed, 0xa0, 0x8e, 0x11, 0x8e, 0x73, 0x85, 0x83, 0xec, 0x48, 0xe0,
+    0xcc, 0x1c, 0x1c, 0x01, 0xab, 0xd2, 0xc2, 0x60, 0x24, 0x8e, 0x40, 0x08, 0x3c, 0x67, 0xef, 0x07,
+    0x6f, 0xa9, 0x8c, 0xdd, 0x08, 0x78, 0x0c, 0xe6, 0x4b, 0x58, 0x66, 0x6b, 0x71, 0x72, 0xe5, 0x88,
+    0x16, 0x18, 0x87, 0x19, 0xbf, 0x3c, 0xc1, 0x0c, 0xeb, 0x87, 0xea, 0x65, 0xe4, 0x78, 0xef, 0x44,
+    0x45, 0xdd, 0xa7, 0xd0, 0x4d, 0xb0, 0x04, 0xf8, 0x64, 0xec, 0x3e, 0x14, 0xcf, 0xdb, 0x0a, 0xcf,
+    0xf1, 0x4c, 0xf1, 0x08, 0xea, 0x03, 0xf9, 0xcd, 0xb0, 0xe8, 0x9b, 0xc9, 0x47, 0x04, 0x9f, 0xf1,
+    0x71, 0x17, 0x98, 0x6b, 0x5a, 0x35, 0x4f, 0xec, 0x3a, 0x5c, 0x6e, 0x73, 0x66, 0xa4, 0xa5, 0x06,
+    0xe5, 0xe2, 0xb6, 0x82, 0xc0, 0xd4, 0x16, 0xf8, 0xff, 0xe4, 0x30, 0x95, 0x47, 0x41, 0xfd, 0x48,
+    0x46, 0x3e, 0x5d, 0xe8, 0x4a, 0x93, 0xf2, 0x18, 0xb7, 0x2e, 0x22, 0xe1, 0x4a, 0x6a, 0x61, 0x46,
+    0x95, 0x1c, 0xd6, 0x77, 0xd6, 0xb4, 0x74, 0x2a, 0x82, 0xc3, 0xa2, 0xbb, 0x32, 0x0a, 0xa8, 0x36,
+    0xe9, 0xdf, 0x15, 0xaa, 0x37, 0xda, 0xfa, 0xce, 0x70, 0x90, 0xce, 0x17, 0x8f, 0x21, 0x5b, 0xd0,
+    0xa6, 0xf0, 0x5c, 0x92, 0x67, 0xc6, 0x52, 0xc3, 0x55, 0xcf, 0x6d, 0xb7, 0x97, 0x0b, 0xa0, 0x5c,
+    0xc2, 0x64, 0xba, 0x26, 0xee, 0xb1, 0x76, 0x38, 0x92, 0xe1, 0x33, 0x5d, 0x4b, 0x27, 0x63, 0xe6,
+    0x72, 0xbd, 0x69, 0x2b, 0x25, 0x26, 0x90, 0xbb, 0x61, 0xc5, 0x08, 0x8c, 0x89, 0x67, 0xf9, 0x09,
+    0xc0, 0xf4, 0x77, 0x89, 0x24, 0xd1, 0x7c, 0x5e, 0xd6, 0x6f, 0x7c, 0xb9, 0x67, 0x6f, 0x1b, 0x5c,
+    0x7f, 0x00, 0xa2, 0x83, 0xe0, 0xde, 0x51, 0xc1, 0xbf, 0x5b, 0x87, 0x4a, 0xa3, 0x50, 0xfa, 0x70,
+    0x1e, 0xf6, 0xa7, 0x73, 0xe4, 0x12, 0x1a, 0x76, 0x4d, 0x72, 0xa3, 0x66, 0xc2, 0x98, 0x54, 0xd8,
+    0x61, 0xd6, 0x53, 0x04, 0x07, 0x1a, 0xed, 0x0c, 0xf5, 0x57, 0x77, 0x74, 0x65, 0x29, 0x92, 0x6c,
+    0x76, 0xde, 0x1d, 0xd4, 0xf3, 0x0e, 0x60, 0xd2, 0xba, 0xf7, 0xd7, 0x3e, 0x25, 0x77, 0x73, 0xc7,
+    0x5f, 0xd7, 0xd1, 0xa4, 0x64, 0x0f, 0x00, 0xe0, 0x65, 0xcf, 0x29, 0x22, 0x52, 0x9b, 0x88, 0xc7,
+    0xa6, 0xe5, 0x23, 0x6d, 0x36, 0x34, 0xa0, 0xc6, 0xb4, 0xa5, 0x33, 0xbf, 0x28, 0xda, 0x01, 0x8e,
+    0xd4, 0xba, 0x45, 0xc0, 0xc3, 0xa8, 0x89, 0xe6, 0x09, 0x19, 0xef, 0x49, 0x09, 0x1a, 0x83, 0x17,
+    0x2f, 0x58, 0x01, 0x7c, 0xcf, 0xb1, 0x64, 0x8f, 0xee, 0xdd, 0xd6, 0x43, 0x33, 0xd5, 0xc2, 0xab,
+    0x47, 0x61, 0x03, 0xf0, 0x59, 0x7c, 0x9e, 0x72, 0x67, 0x34, 0xd8, 0x90, 0x65, 0x5a, 0x2e, 0xfe,
+    0x0c, 0x62, 0x64, 0x74, 0xcb, 0x0d, 0x05, 0x31, 0x2e, 0x5b, 0x41, 0x62, 0x46, 0x70, 0xb0, 0x13,
+    0xd0, 0x0f, 0x8e, 0x0e, 0x58, 0xfe, 0xea, 0x5c, 0xf3, 0x89, 0xd5, 0x3d, 0xf7, 0xec, 0x56, 0x58,
+    0xf1, 0xc8, 0x5c, 0xc3, 0x6f, 0x36, 0x9e, 0xad, 0xe4, 0x48, 0x4b, 0xdf, 0x53, 0xb5, 0x37, 0x53,
+    0x64, 0xee, 0xb3, 0xf2, 0x7d, 0x9e, 0x6a, 0x96, 0xaa, 0x58, 0x47, 0x31, 0xcb, 0xea, 0xeb, 0x7c,
+    0xa4, 0x04, 0x68, 0x3c, 0xee, 0xd9, 0xb0, 0x79, 0x34, 0xf8, 0x19, 0xb7, 0x94, 0x74, 0xbc, 0xf0,
+    0x66, 0xd9, 0xf9, 0xe0, 0xb5, 0x0f, 0x43, 0x42, 0xc1, 0xa9, 0x9c, 0x44, 0xc8, 0x40, 0x44, 0x85,
+    0x6d, 0x76, 0x25, 0xe8, 0x2a, 0x56, 0xdd, 0xf2, 0x4a, 0x7f, 0xf0, 0xb8, 0x20, 0x5a, 0xa7, 0xe6,
+    0x57, 0xc2, 0x33, 0x02, 0x2e, 0x4f, 0x2f, 0x50, 0xc5, 0xc0, 0x7e, 0xb2, 0xa4, 0x2d, 0xfa, 0xf0,
+    0x3b, 0xe0, 0xc6, 0x65, 0x35, 0x0a, 0x5f, 0xd8, 0x1c, 0xa9, 0x28, 0x3f, 0xed, 0xb2, 0xe4, 0xac,
+    0x67, 0x83, 0x40, 0xa0, 0xbd, 0x6b, 0xf6, 0x35, 0x8e, 0x3a, 0xa7, 0x77, 0x8c, 0xeb, 0x54, 0x13,
+    0x34, 0xe7, 0xd3, 0x2f, 0x69, 0x6c, 0xee, 0x87, 0x32, 0xd0, 0xf5, 0x6d, 0xa7, 0xf6, 0x85, 0x6f,
+    0x3e, 0x49, 0x3c, 0xe7, 0xfd, 0x11, 0xea, 0xdf, 0xc4, 0x95, 0x45, 0x86, 0xcf, 0x41, 0xdb, 0xec,
+    0x30, 0x09, 0x6e, 0xb2, 0x1e, 0xf6, 0x50, 0xb7, 0x92, 0x89, 0xf8, 0xf4, 0x1c, 0xcb, 0x1a, 0x5a,
+    0x65, 0x95, 0xa4, 0x71, 0xab, 0xb4, 0xbb, 0x48, 0xf2, 0x81, 0x57, 0x96, 0x53, 0x85, 0xcf, 0x08,
+    0x4a, 0xf9, 0xa9, 0x63, 0x14, 0xf6, 0xd7, 0x27, 0xe2, 0xa4, 0x99, 0x11, 0x10, 0xea, 0x5c, 0xf2,
+    0xa8, 0x58, 0x08, 0x96, 0xc6, 0xa8, 0xd2, 0xb7, 0x8d, 0xc7, 0xc5, 0x80, 0x51, 0x93, 0x30, 0x13,
+    0x69, 0x36, 0x9b, 0x8d, 0xec, 0x3e, 0xd3, 0x1e, 0xce, 0x75, 0x64, 0x27, 0x08, 0x9b, 0x60, 0x59,
+    0xae, 0x39, 0x38, 0xd9, 0xc2, 0x5e, 0xb3, 0x84, 0xb0, 0x78, 0xda, 0x38, 0x7b, 0xd6, 0xa0, 0x0c,
+    0xc6, 0x13, 0x8f, 0x43, 0x70, 0x8e, 0x27, 0xaa, 0xe7, 0x0c, 0xb1, 0xb2, 0x96, 0x4b, 0xcf, 0xb3,
+    0x25, 0xd3, 0xf5, 0xbd, 0xa8, 0x1a, 0x8f, 0x6d, 0x13, 0x71, 0x41, 0x76, 0x30, 0x44, 0xfa, 0x35,
+    0x3b, 0x0d, 0x1c, 0x68, 0x73, 0xfa, 0x2d, 0x6d, 0x3f, 0x83, 0x3f, 0x64, 0x43, 0x9a, 0x90, 0x03,
+    0x22, 0x5a, 0x8f, 0x09, 0xe0, 0xf6, 0x5b, 0xfa, 0x00, 0x36, 0x88, 0xba, 0xb8, 0x95, 0x89, 0x91,
+    0xe7, 0xc2, 0xb9, 0x03, 0xf2, 0xc7, 0xff, 0xda, 0x03, 0xee, 0x07, 0xb0, 0x3b, 0xc2, 0xcd, 0xb7,
+    0x53, 0xca, 0xf9, 0x0b, 0x83, 0x14, 0x56, 0xd5, 0x74, 0x38, 0xde, 0x81, 0x0c, 0xdd, 0xda, 0x61,
+    0x1c, 0x9a, 0xdc, 0x32, 0x6a, 0x0a, 0xa2, 0x33, 0xee, 0x1e, 0x34, 0x6f, 0x14, 0xe2, 0x4e, 0x87,
+    0x8d, 0x4d, 0x44, 0x40, 0xf5, 0x32, 0x1c, 0x46, 0x73, 0x67, 0x3b, 0x65, 0x28, 0x96, 0xa0, 0xac,
+    0x06, 0x15, 0xcf, 0x32, 0x43, 0xf1, 0xc7, 0xb9, 0xad, 0x34, 0x2a, 0xd4, 0x35, 0xf1, 0x89, 0x14,
+    0x52, 0x6a, 0x43, 0xf6, 0x83, 0x38, 0x37, 0x18, 0xe7, 0xe2, 0xd5, 0xab, 0x05, 0x6c, 0xc5, 0x5e,
+    0xaf, 0xf3, 0xee, 0x6e, 0x71, 0x7f, 0xf8, 0x94, 0x7e, 0x82, 0x67, 0xc5, 0x71, 0x12, 0x7a, 0x14,
+    0x60, 0x43, 0x72, 0xf3, 0x74, 0xab, 0x1b, 0xe6, 0xf4, 0x99, 0xd4, 0xf1, 0x81, 0xcb, 0xe4, 0x01,
+    0x76, 0x07, 0x51, 0xd5, 0x50, 0x53, 0x48, 0xa1, 0xb9, 0x7f, 0xc8, 0xba, 0x72, 0x2a, 0x8c, 0xe0,
+    0xcc, 0x3c, 0x5b, 0x60, 0xe4, 0xf4, 0x13, 0xe7, 0x74, 0x34, 0x9d, 0xf3, 0x6a, 0xfd, 0xc7, 0xdf,
+    0x53, 0xfe, 0xce, 0x4f, 0x8c, 0xb2, 0x63, 0xb7, 0x70, 0x37, 0x88, 0x35, 0xf7, 0xec, 0xd8, 0x2b,
+    0x1c, 0x87, 0x38, 0x7a, 0x8c, 0xb2, 0x92, 0x3f, 0x7d, 0xf6, 0xc8, 0xf6, 0xb6, 0x97, 0x4c, 0xb8,
+    0x74, 0x89, 0x5a, 0xe4, 0x4d, 0x93, 0x49, 0x27, 0x12, 0x5c, 0x6e, 0xf7, 0xa9, 0x84, 0x6f, 0x8d,
+    0x3a, 0xb6, 0xc5, 0x3e, 0x9d, 0xd6, 0xe2, 0x23, 0xbd, 0xcb, 0x53, 0x54, 0xce, 0xfa, 0x7a, 0x20,
+    0x12, 0xc9, 0xc5, 0x90, 0x87, 0x3d, 0xa8, 0xc5, 0xd4, 0x52, 0xef, 0xa7, 0xcf, 0x28, 0xe4, 0xdd,
+    0x2c, 0x60, 0x2d, 0x20, 0x62, 0x1f, 0x06, 0x81, 0x77, 0x57, 0x33, 0x9f, 0x40, 0x5f, 0xaa, 0xcb,
+    0x05, 0x1d, 0x0e, 0x88, 0x44, 0x4e, 0x80, 0x76, 0x8a, 0xb6, 0x50, 0x0a, 0xd4, 0x8f, 0x5f, 0xe2,
+    0x47, 0xb9, 0x9d, 0x02, 0xec, 0xcc, 0xeb, 0xaa, 0xa7, 0x75, 0x71, 0xec, 0xb9, 0x3c, 0xab, 0xac,
+    0x10, 0x8e, 0xe8, 0x04, 0xaf, 0x5d, 0x41, 0x13, 0xba, 0xb2, 0xb3, 0xdf, 0xb7, 0x90, 0x43, 0xa0,
+    0xa7, 0x2e, 0xfb, 0xe4, 0xbc, 0x8e, 0x7a, 0xe9, 0x67, 0xbe, 0x17, 0x8b, 0x13, 0x98, 0xf8, 0x2d,
+    0x84, 0x49, 0xf5, 0x03, 0x23, 0xff, 0x8a, 0xc9, 0x59, 0x80, 0x8d, 0x1e, 0x86, 0xe4, 0x91, 0xb8,
+    0x82, 0x65, 0xc5, 0x08, 0x7f, 0x0c, 0x41, 0xb8, 0x6c, 0x24, 0x40, 0xd0, 0x33, 0x22, 0x50, 0x1f,
+    0xe0, 0x6d, 0xb2, 0x10, 0x01, 0xb3, 0xc2, 0xf1, 0xf8, 0x61, 0x88, 0x89, 0x62, 0x5e, 0xb4, 0x12,
+    0x94, 0xd4, 0xce, 0xef, 0xfd, 0x9f, 0x6f, 0xa9, 0xe2, 0x83, 0xc9, 0x62, 0x25, 0x0e, 0xf3, 0x7c,
+    0x6a, 0xfb, 0x4b, 0xb7, 0x42, 0x2c, 0x01, 0xa4, 0x3a, 0x01, 0xfa, 0x79, 0xa7, 0x50, 0x73, 0xe5,
+    0xf5, 0xc7, 0xe1, 0x5d, 0x31, 0xbc, 0x42, 0xea, 0xc3, 0xe0, 0x20, 0x0d, 0x19, 0xae, 0xf7, 0x3c,
+    0x1e, 0x0a, 0x5f, 0x1c, 0x5a, 0x0a, 0xe2, 0x1d, 0xf8, 0x76, 0x26, 0x2e, 0xc5, 0xdb, 0x1b, 0xe4,
+    0xa0, 0xc2, 0x7d, 0xfa, 0x33, 0x44, 0xab, 0x2d, 0xe6, 0xa9, 0x27, 0x39, 0x67, 0xc2, 0x18, 0xd6,
+    0x26, 0x6b, 0xac, 0xa7, 0xb7, 0x06, 0x77, 0x0f, 0xc5, 0xd0, 0x26, 0xb1, 0x62, 0xc0, 0x00, 0x26,
+    0xc2, 0x12, 0xc1, 0xc5, 0xb7, 0x9e, 0x05, 0x2c, 0x8e, 0xc2, 0xe6, 0xf1, 0x9d, 0x5e, 0x79, 0x44,
+    0xf7, 0xfb, 0xbd, 0xd6, 0x1b, 0x60, 0x29, 0x6c, 0xc1, 0x5b, 0xc8, 0x03, 0x88, 0x1d, 0xf7, 0xb5,
+    0x06, 0x19, 0x1b, 0x3d, 0x8b, 0xb2, 0xb9, 0xf4, 0xfd, 0x06, 0x09, 0x0a, 0x2a, 0x51, 0x73, 0x75,
+    0x87, 0xdf, 0xee, 0x56, 0x73, 0x77, 0x95, 0xa2, 0xaa, 0xc3, 0xd4, 0xec, 0xfa, 0xfe, 0x2d, 0x4d,
+    0x63, 0xaa, 0xb0, 0xeb, 0x35, 0x5d, 0x76, 0x77, 0x9c, 0x17, 0x20, 0x2d, 0x5f, 0x71, 0x74, 0xe3,
+    0xee, 0xf8, 0xff, 0x00, 0x00, 0x00, 0x00, 0x09, 0x13, 0x1e, 0x24, 0x29, 0x33,
+];
@@ -0,0 +1,620 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! zstd vector, `zstd -19 --zstd=wlog=13` as `hash.py` runs it over the first 0x8000 bytes of the numbers
+//! `i * 7919 % 10007` for i in 0.., written in decimal and each followed by a space
+
+pub static FRAME: [u8; 9804] = [
+    0x28, 0xb5, 0x2f, 0xfd, 0x44, 0x18, 0x00, 0x7f, 0x5c, 0x6e, 0x00, 0x0a, 0x00, 0x1a, 0x37, 0x09,
+    0xb0, 0xeb, 0x24, 0x09, 0x25, 0x75, 0xc9, 0x98, 0x45, 0x6e, 0x03, 0x6d, 0x03, 0x6e, 0x03, 0x73,
+    0xd4, 0x6a, 0x88, 0xbb, 0x09, 0x10, 0x9f, 0x81, 0x5c, 0x45, 0x7a, 0x28, 0x6c, 0x92, 0x2e, 0x95,
+    0x38, 0x62, 0x64, 0x20, 0xd1, 0x76, 0x64, 0x5f, 0xd5, 0x45, 0x16, 0xcf, 0x9c, 0x5c, 0x81, 0x9a,
+    0xe5, 0x1d, 0x0c, 0x0e, 0xd0, 0x6e, 0xd6, 0x99, 0x4a, 0x6a, 0xa1, 0xb9, 0x47, 0x88, 0xb7, 0xd5,
+    0x19, 0x36, 0x1f, 0x5c, 0x5d, 0x77, 0xb7, 0x15, 0xc4, 0x44, 0x94, 0x65, 0x82, 0x0e, 0xa9, 0xdd,
+    0x90, 0xc9, 0x89, 0xef, 0x46, 0x2e, 0x23, 0x7a, 0xaa, 0x6c, 0x1a, 0x5b, 0xe4, 0xe2, 0x28, 0xcc,
+    0x10, 0xac, 0x0d, 0xe2, 0x3e, 0x0b, 0x5e, 0xd5, 0x3e, 0x24, 0x73, 0x67, 0x6a, 0x0a, 0xdd, 0x11,
+    0xc5, 0x81, 0xd6, 0xed, 0x6c, 0x26, 0xbb, 0xba, 0xe8, 0x19, 0xc6, 0x95, 0x90, 0xd9, 0xd2, 0x47,
+    0xb1, 0x01, 0x66, 0xb3, 0x89, 0xa9, 0x48, 0x16, 0x2a, 0x3a, 0xc6, 0xec, 0xf6, 0x66, 0x40, 0x3f,
+    0xe0, 0x5c, 0xc7, 0x7a, 0x2c, 0x6e, 0xa2, 0x72, 0x19, 0xe9, 0x21, 0x33, 0x43, 0xa9, 0x36, 0xd6,
+    0x7d, 0x57, 0xbc, 0xcc, 0xfa, 0xd4, 0xcd, 0x25, 0xb0, 0x45, 0x78, 0x34, 0x62, 0x88, 0xca, 0x06,
+    0x42, 0xd3, 0x89, 0x5d, 0x35, 0x0f, 0x51, 0xdc, 0x29, 0x99, 0x62, 0xf4, 0xc5, 0xd9, 0x40, 0xe0,
+    0x76, 0xd0, 0xc9, 0x38, 0x4b, 0x45, 0x1d, 0xd4, 0xbb, 0x22, 0x9c, 0x9d, 0x7e, 0xa4, 0xb9, 0xa0,
+    0xea, 0xb9, 0xba, 0xa9, 0x8c, 0x0b, 0x5d, 0x8f, 0x01, 0x1a, 0x84, 0x36, 0x23, 0x3e, 0x68, 0x5c,
+    0x57, 0x79, 0x4c, 0xe8, 0xd6, 0xd8, 0x32, 0x74, 0x48, 0xc5, 0x50, 0x93, 0x8d, 0x47, 0x13, 0xc0,
+    0x5d, 0x06, 0x7c, 0x8a, 0xbd, 0x14, 0x33, 0xa7, 0xf5, 0x55, 0x6e, 0x88, 0xe1, 0x06, 0xd4, 0xe9,
+    0x68, 0x16, 0xab, 0x3a, 0xca, 0xee, 0xce, 0x71, 0x0a, 0xc8, 0x17, 0xa4, 0x8b, 0x61, 0xdb, 0xc5,
+    0x64, 0x8d, 0xa5, 0x44, 0x0e, 0x1a, 0x1a, 0x86, 0x6c, 0xb6, 0x3e, 0x5a, 0x5c, 0x70, 0xf2, 0xdc,
+    0xd1, 0x35, 0xb8, 0x85, 0xc8, 0x63, 0xa2, 0x83, 0x74, 0xb6, 0x90, 0x9a, 0x38, 0x77, 0xdd, 0xf0,
+    0x31, 0xea, 0xad, 0x9a, 0x49, 0x56, 0x9f, 0xdc, 0x0d, 0x85, 0xd8, 0x48, 0x99, 0x20, 0x68, 0xb9,
+    0xb0, 0xa7, 0x7a, 0x49, 0x62, 0xce, 0xc8, 0x57, 0xa2, 0x2b, 0xca, 0x36, 0xb0, 0xe9, 0x2c, 0x16,
+    0x3b, 0x39, 0x0a, 0x6a, 0x20, 0xde, 0x94, 0xe0, 0x17, 0xed, 0x45, 0x99, 0x07, 0xa6, 0xee, 0xcd,
+    0x2d, 0x45, 0x3c, 0xa8, 0x3a, 0x8c, 0xcd, 0xd6, 0xab, 0x8f, 0x17, 0x30, 0x9e, 0xa3, 0x5c, 0x0b,
+    0x9a, 0x55, 0x3b, 0x46, 0x06, 0x99, 0xd8, 0x82, 0x64, 0x62, 0xd1, 0x02, 0x66, 0x8f, 0xd9, 0xad,
+    0x8b, 0x49, 0x30, 0x9f, 0xb0, 0xae, 0xc6, 0x6d, 0x2c, 0x27, 0x90, 0x2e, 0x27, 0x73, 0xd8, 0xd4,
+    0x50, 0x74, 0x73, 0x8a, 0x5f, 0xad, 0x57, 0xdc, 0x3c, 0x04, 0x9b, 0x6e, 0x31, 0xc6, 0x51, 0x21,
+    0x03, 0x95, 0x36, 0x11, 0xfb, 0x62, 0x2e, 0x52, 0x3c, 0x50, 0x72, 0xaf, 0x68, 0x9a, 0xd9, 0x41,
+    0x37, 0x0c, 0x74, 0x2b, 0x67, 0x8a, 0xa8, 0x85, 0xbd, 0xe7, 0xca, 0x6b, 0xd2, 0x59, 0x33, 0xdf,
+    0x50, 0x5d, 0x52, 0xb7, 0x85, 0x71, 0xe2, 0x75, 0x01, 0xa0, 0xe3, 0xc0, 0x6e, 0x71, 0x52, 0xc4,
+    0x27, 0x95, 0xab, 0x42, 0x4f, 0x8c, 0x4d, 0x40, 0xcb, 0x51, 0x1c, 0x56, 0x32, 0x94, 0xd1, 0x36,
+    0x67, 0x5f, 0x81, 0x57, 0xb0, 0x0f, 0x63, 0x2e, 0xb4, 0xe6, 0xf5, 0x8e, 0x12, 0x0e, 0x34, 0xdd,
+    0x84, 0x66, 0x6a, 0xd5, 0x12, 0x76, 0x0f, 0x1c, 0xef, 0x5d, 0xa7, 0x01, 0x7d, 0x44, 0x1b, 0x26,
+    0xb6, 0x36, 0xa6, 0x10, 0x59, 0x38, 0x74, 0x00, 0xd9, 0x35, 0x9a, 0x55, 0xf1, 0x8d, 0xc9, 0x25,
+    0x47, 0x4f, 0xe1, 0x26, 0x92, 0x0b, 0x44, 0x8f, 0x8b, 0x19, 0xac, 0xb5, 0x91, 0xdc, 0x27, 0xc3,
+    0xab, 0xd4, 0x27, 0x6a, 0x2e, 0xad, 0x26, 0xdc, 0x1d, 0x76, 0x1c, 0x0a, 0x64, 0x1b, 0xd2, 0x24,
+    0x61, 0x57, 0xf4, 0xa1, 0xc4, 0x85, 0x91, 0x79, 0x43, 0x5f, 0x91, 0x0d, 0x54, 0x9b, 0x58, 0x4c,
+    0x3d, 0x59, 0x02, 0xea, 0x20, 0xef, 0x1e, 0x39, 0x2d, 0xfa, 0x51, 0xe7, 0x1a, 0xa9, 0x47, 0xe6,
+    0xa6, 0x20, 0x2e, 0xac, 0x1e, 0x60, 0x33, 0x9c, 0xd5, 0x56, 0x77, 0xdf, 0x40, 0x5c, 0x42, 0x79,
+    0x1a, 0x74, 0xa3, 0xb6, 0x80, 0x1c, 0x27, 0x31, 0xd8, 0xc8, 0x46, 0x44, 0xd3, 0x94, 0x5d, 0xb5,
+    0x27, 0x2e, 0x2e, 0x61, 0x26, 0xb0, 0x3e, 0xe3, 0x0d, 0x15, 0xdc, 0xa6, 0x9d, 0x44, 0x66, 0xe9,
+    0xd4, 0x11, 0x74, 0x17, 0x8a, 0xf3, 0xaa, 0x5f, 0xd9, 0x5c, 0x74, 0xb5, 0xc9, 0x54, 0xc6, 0x12,
+    0x21, 0x07, 0x4b, 0x03, 0x88, 0x4d, 0x93, 0x8f, 0x26, 0xae, 0x21, 0x79, 0xa4, 0xe8, 0xd6, 0x6c,
+    0xe1, 0x1d, 0x00, 0x1d, 0x0e, 0x66, 0x2b, 0xd6, 0x44, 0x71, 0x97, 0x94, 0x4f, 0xa5, 0x37, 0x66,
+    0x26, 0xa9, 0xbe, 0xa3, 0x1b, 0xac, 0xb8, 0x91, 0x75, 0x9a, 0x9b, 0x45, 0xc0, 0x9e, 0xe0, 0x65,
+    0xc4, 0x84, 0xca, 0x67, 0xa5, 0xab, 0xc4, 0xb6, 0x99, 0x49, 0x28, 0x96, 0x96, 0x1c, 0x61, 0x34,
+    0xf0, 0x6c, 0xde, 0x7d, 0x05, 0xbd, 0x88, 0xf3, 0x4c, 0xd4, 0x95, 0xde, 0x12, 0xc2, 0x83, 0xd3,
+    0x01, 0x68, 0x36, 0xa3, 0x9a, 0xaa, 0xee, 0x1a, 0xe3, 0x23, 0xd7, 0x5b, 0xa0, 0x19, 0xb4, 0x03,
+    0x62, 0xb8, 0x88, 0xad, 0x2a, 0x13, 0x09, 0x2d, 0x33, 0xf6, 0x94, 0x6e, 0x54, 0x4c, 0x9a, 0x7c,
+    0x67, 0x74, 0xd9, 0xd9, 0x46, 0xc0, 0x69, 0xd8, 0x45, 0x62, 0x8e, 0xb6, 0x86, 0x90, 0x9b, 0x30,
+    0xfc, 0x6c, 0x7a, 0x15, 0xcd, 0x43, 0x55, 0x77, 0xec, 0x96, 0x1e, 0x8f, 0x00, 0x19, 0x48, 0xda,
+    0x8e, 0xf6, 0x55, 0x5c, 0xd4, 0x78, 0x46, 0xe4, 0xca, 0xd0, 0x2c, 0xd9, 0xc1, 0x1a, 0xc0, 0x62,
+    0x33, 0x93, 0xa9, 0x8e, 0x16, 0x82, 0x7b, 0x84, 0xbc, 0x8d, 0xce, 0xe8, 0x7c, 0x20, 0x75, 0x9d,
+    0xdc, 0x56, 0xc3, 0x89, 0xa8, 0xcb, 0xd4, 0x1c, 0x62, 0x35, 0xf4, 0x6e, 0x12, 0xe2, 0x3b, 0x90,
+    0xcb, 0x48, 0x4f, 0x85, 0x4d, 0xd3, 0x45, 0x24, 0x8e, 0x8e, 0x0c, 0x41, 0xb4, 0x41, 0xd9, 0x67,
+    0x75, 0x95, 0xc5, 0x43, 0x27, 0x77, 0xa0, 0xa6, 0xf0, 0x8e, 0x08, 0x0e, 0x6c, 0xb7, 0xeb, 0x4c,
+    0x26, 0xb5, 0xd4, 0xdc, 0x33, 0xc4, 0x2b, 0xd5, 0x59, 0x9b, 0x8f, 0x57, 0x43, 0x1f, 0x4d, 0xaf,
+    0xa1, 0x79, 0xa4, 0xea, 0xd6, 0x6e, 0xe1, 0xf1, 0x00, 0x90, 0xe1, 0x80, 0xb6, 0xa2, 0x7d, 0x13,
+    0x97, 0x34, 0x9e, 0x8a, 0xdc, 0x18, 0x9a, 0x24, 0x3b, 0x8e, 0x06, 0xab, 0xd8, 0xc8, 0x64, 0x9a,
+    0xa3, 0x45, 0xe0, 0x9e, 0x20, 0x2f, 0xa3, 0x13, 0x3a, 0x9f, 0xb5, 0xae, 0x92, 0xdb, 0x66, 0x38,
+    0x09, 0x75, 0x69, 0xcd, 0x11, 0x56, 0x03, 0xef, 0xe6, 0x1d, 0xbf, 0x02, 0xb9, 0x88, 0xf4, 0x4c,
+    0xd8, 0xd4, 0x2e, 0x21, 0x71, 0x70, 0x64, 0x00, 0xa2, 0xcd, 0xc8, 0x3e, 0xaa, 0x6b, 0x2c, 0x1e,
+    0x39, 0xb9, 0x85, 0x9a, 0xc1, 0x3b, 0x20, 0x38, 0x5c, 0x74, 0xab, 0xce, 0x44, 0x52, 0xcb, 0xcc,
+    0x3d, 0x25, 0xde, 0xa8, 0x4e, 0xda, 0x7c, 0x67, 0x75, 0xd9, 0xdd, 0x46, 0x10, 0xd3, 0x50, 0x16,
+    0x09, 0x3a, 0x5a, 0xbb, 0x94, 0x09, 0x13, 0x9f, 0x8d, 0x5c, 0x45, 0xf4, 0x50, 0xd9, 0x24, 0xb6,
+    0xf4, 0xe2, 0x08, 0x98, 0x81, 0xac, 0xed, 0x78, 0x5f, 0x05, 0x2f, 0x6a, 0x9f, 0x91, 0xb9, 0x32,
+    0x35, 0x4b, 0x77, 0xb0, 0x38, 0x80, 0x75, 0x33, 0x9b, 0xa9, 0xae, 0xae, 0x79, 0x84, 0x71, 0x1b,
+    0x32, 0xa3, 0xf4, 0x81, 0xd8, 0x70, 0xb2, 0xd5, 0xc4, 0x44, 0x24, 0xcb, 0x14, 0x1d, 0x62, 0x76,
+    0xe3, 0x26, 0xa1, 0xdf, 0xc1, 0x5c, 0xc6, 0x7a, 0x2a, 0x6e, 0x9a, 0x72, 0x11, 0xe9, 0xd1, 0x99,
+    0x21, 0xa8, 0x36, 0xa8, 0xfb, 0xac, 0x78, 0x95, 0xf5, 0xa1, 0x9b, 0x3b, 0x60, 0x4b, 0x79, 0x44,
+    0xc4, 0xc0, 0xca, 0x76, 0xa5, 0xc9, 0xc4, 0x2e, 0x9a, 0x67, 0x28, 0xae, 0x94, 0xcc, 0x1a, 0x7d,
+    0x3c, 0x1b, 0x00, 0xb8, 0x19, 0x74, 0x2a, 0xce, 0x42, 0x51, 0xc7, 0xf4, 0x6e, 0x85, 0x33, 0xa6,
+    0x1f, 0xd0, 0x5c, 0x47, 0xf5, 0x58, 0xdd, 0x44, 0xc6, 0x65, 0xae, 0x87, 0x00, 0x0d, 0xa5, 0x4d,
+    0xc6, 0x77, 0x11, 0x97, 0x55, 0x9e, 0x12, 0xba, 0x34, 0xb6, 0x08, 0x1d, 0xad, 0x18, 0xc2, 0x64,
+    0x83, 0xa3, 0xe9, 0xce, 0xae, 0x02, 0x3e, 0xc4, 0xde, 0x89, 0x99, 0xd2, 0xfa, 0x42, 0x6e, 0xe0,
+    0x70, 0xbb, 0xe9, 0x64, 0x34, 0x4b, 0x55, 0x1d, 0x64, 0x77, 0xe5, 0x38, 0x0b, 0xf2, 0x91, 0x74,
+    0x41, 0xd8, 0x66, 0x31, 0x55, 0x63, 0x21, 0x91, 0x63, 0x86, 0x06, 0x21, 0x9b, 0x51, 0x1f, 0x58,
+    0x5c, 0x67, 0xf2, 0xd8, 0xd1, 0x2d, 0xb8, 0x65, 0xc8, 0x43, 0xa2, 0x43, 0x3b, 0x1b, 0xa5, 0x26,
+    0x98, 0xbb, 0x6c, 0xf8, 0x14, 0xf5, 0x52, 0xcd, 0x1c, 0xab, 0xaf, 0x77, 0x43, 0x40, 0x6c, 0x40,
+    0x99, 0x8e, 0xb4, 0x58, 0xd8, 0x43, 0xbd, 0x23, 0x31, 0x65, 0xe4, 0x0b, 0xa2, 0x8b, 0x65, 0xdb,
+    0xd5, 0x64, 0x16, 0x4b, 0x9d, 0x1c, 0x04, 0x35, 0x0c, 0x6f, 0x36, 0xf8, 0xb1, 0xbd, 0x40, 0xe6,
+    0x39, 0xa9, 0x6b, 0x73, 0x0b, 0x11, 0x8f, 0xa9, 0x0e, 0x62, 0xb3, 0xc5, 0xd5, 0x77, 0x1d, 0xc4,
+    0x63, 0x94, 0x5b, 0x41, 0x93, 0x6a, 0x87, 0xc8, 0xd0, 0x89, 0x8d, 0x24, 0x13, 0x14, 0x2d, 0x57,
+    0xf6, 0x94, 0x5d, 0xba, 0x98, 0x03, 0xf3, 0x95, 0x75, 0x45, 0xdc, 0x06, 0xe5, 0x74, 0xed, 0x62,
+    0x32, 0x47, 0x4d, 0x0d, 0x44, 0x37, 0xa5, 0xf8, 0x85, 0xf5, 0xe2, 0xcd, 0x03, 0x60, 0x93, 0x2d,
+    0xc5, 0x38, 0x28, 0x64, 0x98, 0xd2, 0x56, 0xb1, 0x8f, 0x73, 0x01, 0xc5, 0x73, 0x24, 0xd7, 0x8a,
+    0x66, 0x99, 0x1d, 0x73, 0x83, 0x40, 0xb7, 0xe0, 0x4c, 0x8c, 0x5a, 0xa0, 0xf7, 0x58, 0x79, 0x4b,
+    0x3a, 0x69, 0xe6, 0x13, 0xaa, 0xab, 0x75, 0x1b, 0x8d, 0x13, 0x5c, 0x97, 0xbb, 0x39, 0x0c, 0xec,
+    0x12, 0xe7, 0x44, 0x7c, 0xad, 0x5c, 0x21, 0xf4, 0x70, 0x6c, 0xba, 0x59, 0x8c, 0xe2, 0xa8, 0x92,
+    0x81, 0x8c, 0x36, 0x39, 0xfb, 0x02, 0x78, 0x91, 0x7d, 0x20, 0xe6, 0x5e, 0xd4, 0xb4, 0xde, 0x41,
+    0xc2, 0x61, 0xa6, 0x5b, 0x69, 0xa6, 0xa8, 0x5a, 0x68, 0xf7, 0x9c, 0xf1, 0xda, 0x75, 0x16, 0xd0,
+    0x37, 0xb4, 0x41, 0x62, 0x8b, 0xc6, 0x44, 0x91, 0x05, 0x86, 0x8e, 0x1b, 0xbb, 0x45, 0x93, 0x2a,
+    0x3e, 0x31, 0xb9, 0x7a, 0xf4, 0x04, 0xdc, 0x04, 0xe4, 0x72, 0xec, 0x61, 0x31, 0x43, 0xb5, 0xb6,
+    0x91, 0xfb, 0x3a, 0xbc, 0x82, 0xfa, 0xb0, 0xe6, 0x82, 0xd5, 0x3c, 0xbb, 0xa3, 0x8e, 0x03, 0x81,
+    0x6c, 0x42, 0x9a, 0x1a, 0x76, 0xb1, 0x0f, 0x48, 0xdc, 0x13, 0x99, 0x36, 0xf4, 0x11, 0xd9, 0x30,
+    0xb5, 0xd5, 0x62, 0x8a, 0x93, 0x85, 0x50, 0x07, 0xf0, 0xae, 0x91, 0xb3, 0xa2, 0xdf, 0x74, 0x2e,
+    0x91, 0x7a, 0x3a, 0x37, 0x91, 0xb8, 0x40, 0xf5, 0xb8, 0x9a, 0xc1, 0xac, 0x36, 0xba, 0xfb, 0x04,
+    0xe2, 0x2a, 0xe5, 0x89, 0xa0, 0xcb, 0xda, 0x72, 0x3d, 0x4c, 0x62, 0xa8, 0x91, 0x6d, 0x88, 0x26,
+    0x29, 0xbb, 0xc2, 0x1e, 0x5e, 0x5c, 0x80, 0x99, 0x07, 0xf5, 0x15, 0x6f, 0xa0, 0xe0, 0x26, 0xed,
+    0x54, 0x99, 0x25, 0xa6, 0x0e, 0xd2, 0xdd, 0x23, 0x4e, 0xab, 0x7e, 0x64, 0x73, 0xcd, 0xd5, 0xd6,
+    0x29, 0x18, 0x0b, 0x43, 0x0e, 0x28, 0x0d, 0x57, 0x9b, 0x25, 0xdf, 0x4c, 0x5c, 0x42, 0xf2, 0xb4,
+    0xe8, 0x86, 0xd9, 0x02, 0x77, 0xdc, 0xc5, 0x60, 0x30, 0x1b, 0xb1, 0xa6, 0x89, 0xbb, 0x5a, 0x3e,
+    0x21, 0xbd, 0x9c, 0x99, 0x40, 0xf5, 0x19, 0xdd, 0x50, 0xc5, 0x6d, 0xac, 0x93, 0xdc, 0x2c, 0x05,
+    0x7b, 0xc8, 0x0b, 0x11, 0xf3, 0x42, 0xbe, 0x2a, 0x5d, 0x24, 0xb6, 0xc9, 0x4c, 0xa5, 0x58, 0xa2,
+    0xe4, 0xa0, 0xd1, 0x00, 0x67, 0xd3, 0xee, 0x23, 0xe8, 0x35, 0x9c, 0x47, 0xa2, 0x6e, 0x7b, 0x0b,
+    0x85, 0x07, 0x4c, 0x87, 0x9b, 0xd9, 0x8a, 0x6a, 0xa2, 0xba, 0x4b, 0x8c, 0x4f, 0xaf, 0x37, 0x80,
+    0x26, 0x69, 0xc7, 0x71, 0xb0, 0x88, 0x8d, 0x2a, 0xd3, 0x08, 0x2d, 0x32, 0xf6, 0x04, 0x5d, 0x56,
+    0x4c, 0x30, 0xf9, 0xcc, 0xe8, 0xaa, 0xb3, 0x6d, 0x80, 0x93, 0xb0, 0x4b, 0x63, 0x8e, 0x68, 0x0d,
+    0x94, 0x9b, 0x27, 0xfc, 0x6a, 0x7a, 0x11, 0xcd, 0x33, 0x55, 0x57, 0xec, 0x96, 0x38, 0x1e, 0x04,
+    0x19, 0x80, 0xb4, 0x19, 0xed, 0xa3, 0xb8, 0xa6, 0xf1, 0x88, 0xc8, 0xed, 0xd0, 0x0c, 0xb2, 0x03,
+    0x6a, 0xb8, 0x8a, 0xad, 0x4c, 0x26, 0x3a, 0x5a, 0x06, 0xee, 0x29, 0x79, 0x23, 0x3a, 0xd9, 0xf9,
+    0xae, 0x75, 0x99, 0xdc, 0x46, 0xc3, 0x69, 0xa8, 0x8b, 0x54, 0xb7, 0x5c, 0x79, 0x98, 0x74, 0xa8,
+    0x99, 0x6d, 0xa8, 0x26, 0xa9, 0xbb, 0xc2, 0xf8, 0xf0, 0x7a, 0x01, 0x68, 0x1e, 0xd8, 0x51, 0x1c,
+    0x28, 0x62, 0x93, 0xca, 0x54, 0xa1, 0x25, 0xc6, 0x1e, 0xa0, 0x7b, 0x14, 0xd3, 0x4a, 0x3e, 0x32,
+    0xba, 0xe6, 0x6c, 0x2b, 0x70, 0x0a, 0x76, 0x61, 0xcc, 0x01, 0xad, 0xe1, 0x7a, 0xb3, 0x84, 0xdf,
+    0x4c, 0x2f, 0xa1, 0x79, 0x5a, 0x75, 0xc3, 0x6e, 0x81, 0xe3, 0x71, 0xd7, 0xc1, 0x80, 0x36, 0xa2,
+    0x7d, 0x12, 0x57, 0x1b, 0x4f, 0x88, 0x5c, 0x0e, 0x4d, 0x20, 0x3b, 0x8c, 0x86, 0xaa, 0xd8, 0xc6,
+    0x64, 0x92, 0xa3, 0xa5, 0x70, 0x0f, 0xc9, 0x0b, 0xd1, 0x79, 0x31, 0x5f, 0xb5, 0x2e, 0x92, 0xdb,
+    0x64, 0x38, 0x95, 0xba, 0x44, 0xcd, 0x41, 0xab, 0x01, 0xee, 0xa6, 0x1d, 0x3f, 0x02, 0xb9, 0x86,
+    0xf4, 0x48, 0xd8, 0x14, 0x5d, 0x28, 0x71, 0xc0, 0xc8, 0x70, 0x43, 0x5b, 0x91, 0x7d, 0x53, 0x97,
+    0x58, 0x3c, 0x3d, 0xb9, 0x01, 0x35, 0xc9, 0x3b, 0x8e, 0x1c, 0x2c, 0xba, 0x51, 0x67, 0x1a, 0xa9,
+    0x45, 0xe6, 0x9e, 0x20, 0x5e, 0x56, 0x27, 0xd8, 0x7c, 0x66, 0x75, 0xd5, 0xdd, 0x36, 0x10, 0x93,
+    0x50, 0x96, 0x06, 0x1d, 0x51, 0xbb, 0x20, 0xf3, 0x24, 0xbe, 0x1a, 0xb9, 0x88, 0xe8, 0x99, 0xb2,
+    0xa9, 0xb6, 0xc4, 0xc5, 0x41, 0x98, 0x01, 0x58, 0x9b, 0xf1, 0x3e, 0x0a, 0x5e, 0xd3, 0x3e, 0x22,
+    0x73, 0x3b, 0x35, 0x83, 0xee, 0x80, 0xe2, 0x70, 0xd5, 0xad, 0x6c, 0x26, 0xba, 0xba, 0xe4, 0x29,
+    0xe3, 0x46, 0xc8, 0x64, 0xe9, 0xbb, 0xda, 0x60, 0xb2, 0xd1, 0xc4, 0x34, 0x24, 0x8b, 0x14, 0x1d,
+    0x35, 0xbb, 0xbc, 0x09, 0xd0, 0xcf, 0x60, 0xae, 0x62, 0x3d, 0x14, 0x37, 0x49, 0xb9, 0x54, 0x7a,
+    0xc4, 0xcc, 0x40, 0xaa, 0xed, 0xe8, 0xbe, 0x2a, 0x5e, 0x64, 0x7d, 0xe6, 0xe6, 0x0a, 0xd8, 0x12,
+    0x3c, 0x18, 0x31, 0x40, 0x65, 0xb3, 0xd2, 0x54, 0x62, 0xd7, 0xcc, 0x23, 0x14, 0xb7, 0x25, 0x33,
+    0x8c, 0x3e, 0x38, 0x1b, 0xee, 0xb6, 0x82, 0x4e, 0xc4, 0x59, 0x26, 0xea, 0x90, 0xde, 0x0d, 0xe1,
+    0xe4, 0xf4, 0xbb, 0x99, 0xcb, 0xa8, 0x9e, 0xaa, 0x9b, 0xc6, 0xb8, 0xc8, 0xf5, 0x28, 0xd0, 0x10,
+    0xb4, 0x09, 0xf1, 0x59, 0xc4, 0x55, 0x95, 0x87, 0x84, 0xee, 0x8c, 0x2d, 0xa5, 0x23, 0x2a, 0x06,
+    0x9a, 0x6c, 0x67, 0x34, 0xd9, 0xd9, 0x45, 0xc0, 0x67, 0xd8, 0x2b, 0x31, 0xb3, 0xad, 0x8f, 0x72,
+    0x03, 0x0c, 0x37, 0x9b, 0x4e, 0x45, 0xb3, 0x50, 0xd5, 0x31, 0x76, 0xb7, 0xc7, 0x19, 0x20, 0x1f,
+    0x90, 0xae, 0xa3, 0x6d, 0x15, 0x13, 0x35, 0x96, 0x11, 0x39, 0x64, 0x68, 0x28, 0xd9, 0x64, 0x7d,
+    0x57, 0x71, 0x99, 0xc9, 0x53, 0x47, 0x97, 0xe0, 0x16, 0x21, 0x8f, 0x46, 0x87, 0xe8, 0x6c, 0x20,
+    0x35, 0x9d, 0xdc, 0x55, 0xc3, 0x87, 0xa8, 0x77, 0x6a, 0xa6, 0x58, 0x7d, 0x71, 0x37, 0x10, 0x62,
+    0x3b, 0x90, 0xc9, 0x48, 0x4b, 0x85, 0x3d, 0xd3, 0x2b, 0x12, 0xb3, 0x23, 0x1f, 0x89, 0x2e, 0x28,
+    0xdb, 0xac, 0xa6, 0xb2, 0x58, 0xe8, 0xe4, 0x18, 0xa8, 0x41, 0x78, 0x33, 0x82, 0x1f, 0xb4, 0xd7,
+    0x75, 0x1e, 0x93, 0xba, 0x35, 0xb7, 0x0c, 0xf1, 0x90, 0xea, 0x50, 0x9b, 0x8d, 0x57, 0x13, 0x00,
+    0xf0, 0x32, 0x88, 0xa7, 0x28, 0x97, 0x82, 0xe6, 0xd4, 0x8e, 0xca, 0x10, 0x13, 0x1b, 0x90, 0x4c,
+    0x47, 0xb4, 0x58, 0xd9, 0x43, 0x76, 0xe7, 0x62, 0x0a, 0xcc, 0x17, 0xac, 0x8b, 0x71, 0xdb, 0x05,
+    0x27, 0x6b, 0x97, 0x92, 0x39, 0x68, 0x6a, 0x18, 0xba, 0xd9, 0xe2, 0x47, 0xeb, 0x05, 0x37, 0xcf,
+    0x5d, 0x4d, 0xb5, 0x10, 0xe3, 0x98, 0x90, 0x41, 0x4a, 0x5b, 0x88, 0x7d, 0x30, 0xd7, 0x4d, 0x3c,
+    0x46, 0x72, 0xab, 0x68, 0x92, 0xd9, 0x21, 0x37, 0x14, 0xba, 0x91, 0x33, 0x41, 0xd4, 0x72, 0x71,
+    0x4f, 0x95, 0x97, 0xa4, 0x73, 0x66, 0xbe, 0x52, 0x5d, 0x51, 0xb7, 0x81, 0x71, 0x3a, 0xeb, 0x62,
+    0x37, 0x47, 0x81, 0xdd, 0xe1, 0x94, 0x88, 0x2f, 0x2a, 0x17, 0x85, 0x1e, 0x18, 0x9b, 0x6c, 0x96,
+    0xa2, 0x38, 0xa8, 0x64, 0x18, 0xa3, 0xad, 0x67, 0x1f, 0x81, 0x17, 0xb0, 0xcf, 0x71, 0xae, 0x45,
+    0xcd, 0xea, 0x1d, 0x23, 0x1c, 0x64, 0xba, 0x05, 0xcd, 0xc4, 0xaa, 0x05, 0xec, 0x1e, 0x33, 0xde,
+    0xba, 0x4e, 0x02, 0xfa, 0x84, 0x36, 0x34, 0x36, 0x36, 0x26, 0x10, 0x59, 0x4e, 0xe8, 0xb0, 0xb1,
+    0x4b, 0x34, 0xa7, 0xe2, 0xab, 0xc9, 0x15, 0x47, 0x0f, 0xe1, 0xa6, 0x03, 0x2e, 0xc6, 0x1e, 0x15,
+    0x33, 0x50, 0x6b, 0x13, 0xb9, 0x2f, 0x86, 0x17, 0xa9, 0x0f, 0xd4, 0xdc, 0xab, 0x9a, 0x66, 0x77,
+    0xd0, 0x71, 0x18, 0x90, 0xad, 0xa4, 0x29, 0xc2, 0x2e, 0xe8, 0x73, 0x8d, 0x6b, 0x22, 0xb3, 0x86,
+    0xbe, 0x21, 0x1b, 0xa4, 0xb6, 0xb0, 0x98, 0x78, 0xb2, 0x00, 0xd4, 0x71, 0x70, 0xb7, 0xc8, 0x49,
+    0xd1, 0x4f, 0x3a, 0x57, 0xa5, 0x9e, 0x98, 0x9b, 0x80, 0xb8, 0x1c, 0xf5, 0xb0, 0x9a, 0xa1, 0xac,
+    0xb6, 0xb9, 0xfb, 0x0a, 0x71, 0x05, 0xe5, 0x61, 0xd0, 0x85, 0xda, 0x62, 0x3d, 0x4a, 0x62, 0xa0,
+    0x91, 0x4d, 0x88, 0xa6, 0x96, 0x5d, 0xb4, 0x07, 0x2e, 0xee, 0x9d, 0x4c, 0x83, 0xfa, 0x88, 0x37,
+    0x4c, 0x70, 0x6b, 0x3b, 0x85, 0xcc, 0xc2, 0xa9, 0x03, 0xe8, 0xae, 0x11, 0x67, 0x55, 0xbf, 0xb1,
+    0xb9, 0xe4, 0x6a, 0x8b, 0x89, 0x8c, 0x05, 0x42, 0x8e, 0x0b, 0x1a, 0xac, 0x36, 0x49, 0x3e, 0x99,
+    0xb8, 0x4a, 0xf2, 0x44, 0xd1, 0xa5, 0xd9, 0x72, 0x76, 0xd8, 0xc5, 0x50, 0x30, 0xdb, 0xb0, 0x26,
+    0x89, 0xbb, 0xa2, 0x7c, 0x28, 0xbd, 0x30, 0x33, 0x6f, 0xea, 0x2b, 0xba, 0x81, 0x8a, 0x9b, 0x58,
+    0xa7, 0xde, 0x2c, 0x01, 0xf6, 0x00, 0xef, 0x31, 0xa6, 0x85, 0x7c, 0x54, 0xba, 0x46, 0x6c, 0xeb,
+    0x4c, 0x41, 0xb1, 0xb0, 0xe4, 0x00, 0xa3, 0xe1, 0xcc, 0x66, 0xdd, 0x37, 0xd0, 0x4b, 0x38, 0x4f,
+    0xa3, 0x6e, 0xf4, 0x16, 0x10, 0x1e, 0x27, 0x1d, 0x6c, 0x66, 0x23, 0xaa, 0x69, 0xea, 0xae, 0x1a,
+    0x9f, 0xb8, 0x5e, 0x02, 0x4d, 0xa0, 0x1d, 0xc6, 0xa1, 0x22, 0xb6, 0xa9, 0x4c, 0x22, 0xb4, 0x74,
+    0xec, 0x21, 0x5d, 0xa8, 0x98, 0x57, 0xf2, 0x95, 0xd1, 0x45, 0x67, 0x9b, 0x00, 0xa7, 0xb2, 0x4b,
+    0xc4, 0x1c, 0x6c, 0x0d, 0x20, 0x37, 0x4d, 0x18, 0xb3, 0x95, 0x35, 0x45, 0xdc, 0x05, 0xe5, 0x73,
+    0xed, 0x35, 0x99, 0x59, 0x53, 0xdf, 0xd0, 0x0d, 0x52, 0xdc, 0xc2, 0x3a, 0xf1, 0x66, 0x01, 0xb0,
+    0xc7, 0x6e, 0x31, 0x26, 0x85, 0x7c, 0x52, 0xba, 0x2a, 0xb6, 0x71, 0x26, 0xa0, 0x58, 0x8e, 0xe4,
+    0xb0, 0xa2, 0xa1, 0xcc, 0xe6, 0xdc, 0x57, 0xe8, 0x15, 0x9c, 0x87, 0x51, 0x17, 0x7a, 0x8b, 0x95,
+    0x47, 0x49, 0x07, 0x9a, 0xd9, 0x84, 0x6a, 0x6a, 0xdd, 0x45, 0xe3, 0x03, 0xd7, 0x7b, 0x37, 0xd3,
+    0xc0, 0x0e, 0xe2, 0x30, 0x11, 0x5b, 0x2b, 0x53, 0x08, 0x2d, 0x1c, 0x7b, 0x6e, 0xae, 0x51, 0xcc,
+    0x2a, 0xf9, 0xc6, 0xe8, 0x92, 0xb3, 0x2d, 0x80, 0x13, 0xd9, 0x05, 0x62, 0x8e, 0x8b, 0x1a, 0xac,
+    0x37, 0x49, 0xf8, 0xc9, 0xf4, 0x2a, 0xcd, 0x13, 0x55, 0x97, 0x76, 0xcb, 0x19, 0x0f, 0xbb, 0x0e,
+    0x05, 0xb4, 0x0d, 0xed, 0x6b, 0x5c, 0xd1, 0x78, 0x28, 0x72, 0x61, 0x68, 0xde, 0xd8, 0x51, 0x34,
+    0x50, 0xc5, 0x26, 0x26, 0x53, 0x8f, 0x96, 0x80, 0x7b, 0x80, 0xbc, 0xc7, 0x4e, 0x8b, 0xf9, 0xa8,
+    0x75, 0x8d, 0xdc, 0xd6, 0xe1, 0x14, 0xd4, 0x85, 0x35, 0x07, 0x58, 0x0d, 0x67, 0x37, 0xeb, 0xf8,
+    0x0d, 0xc8, 0x25, 0xa4, 0xa7, 0x61, 0x13, 0xbb, 0x80, 0xc4, 0x71, 0x22, 0x83, 0x0d, 0x6d, 0x44,
+    0xf6, 0x49, 0x5d, 0xb5, 0x78, 0xe2, 0xe4, 0x12, 0x6a, 0x02, 0xef, 0x30, 0x72, 0xa8, 0xe8, 0x36,
+    0x9d, 0x49, 0xa4, 0x96, 0xce, 0x3d, 0x24, 0x5e, 0xa8, 0xce, 0xab, 0xf9, 0xca, 0xea, 0xa2, 0xbb,
+    0x4d, 0x20, 0xa6, 0x52, 0x96, 0x08, 0x3a, 0x58, 0xbb, 0xd7, 0x69, 0x12, 0x1f, 0x8d, 0x5c, 0x43,
+    0xf4, 0x48, 0xd9, 0x14, 0xb6, 0xf0, 0xe2, 0x00, 0x98, 0xe1, 0xa0, 0xb6, 0xe2, 0x7d, 0x13, 0xbc,
+    0xa4, 0x7d, 0x2a, 0x73, 0x63, 0x6a, 0x92, 0xee, 0x38, 0xe2, 0x60, 0xd5, 0x8d, 0x6c, 0xa6, 0xb9,
+    0xba, 0xfa, 0x04, 0xe3, 0x32, 0x64, 0x42, 0xe9, 0xb3, 0xda, 0x50, 0xb2, 0xcd, 0xc4, 0x24, 0x24,
+    0x4b, 0x8b, 0x8e, 0x30, 0xbb, 0x70, 0xf3, 0x2e, 0xbe, 0x82, 0xb9, 0x88, 0xf5, 0x4c, 0xdc, 0xd4,
+    0x72, 0x09, 0xe9, 0xc1, 0x99, 0x01, 0xa8, 0x36, 0xa3, 0xfb, 0xa8, 0x78, 0x8d, 0xf5, 0x91, 0x9b,
+    0x5b, 0xb0, 0x85, 0x3c, 0x20, 0x62, 0xb8, 0x90, 0xad, 0x4a, 0x13, 0x89, 0x5d, 0x32, 0x4f, 0x29,
+    0x6e, 0x94, 0x4c, 0x1a, 0x7d, 0x67, 0x36, 0xd8, 0x6d, 0x04, 0x9d, 0x86, 0xb3, 0x48, 0xd4, 0xd1,
+    0xde, 0xa5, 0x70, 0xc2, 0xf4, 0xb3, 0x99, 0xab, 0xa8, 0x1e, 0xaa, 0x9b, 0xc4, 0xb8, 0xf4, 0x7a,
+    0x04, 0xd0, 0x40, 0xda, 0x3c, 0x7e, 0x15, 0x71, 0x51, 0xe5, 0x19, 0xa1, 0x2b, 0x63, 0x4b, 0xd0,
+    0xc1, 0x8a, 0x01, 0x4c, 0x36, 0x33, 0x9a, 0xea, 0xec, 0x1a, 0xe0, 0x23, 0xec, 0x6d, 0xcc, 0x8c,
+    0xd6, 0x07, 0x72, 0xc3, 0x09, 0xb7, 0x9a, 0x4e, 0x44, 0xb3, 0x4c, 0xd5, 0x21, 0x76, 0x37, 0x8e,
+    0x93, 0x20, 0xdf, 0x01, 0x5d, 0x46, 0xdb, 0x28, 0xa6, 0x69, 0x2c, 0x22, 0x72, 0x74, 0x68, 0x08,
+    0xb2, 0x09, 0xf5, 0x59, 0xc5, 0x55, 0x26, 0x0f, 0x1d, 0xdd, 0x81, 0x5b, 0x4a, 0x1e, 0x11, 0x1d,
+    0xd8, 0xd9, 0xae, 0x35, 0x99, 0xdc, 0x45, 0xc3, 0x67, 0xa8, 0x57, 0x6a, 0x66, 0xad, 0x3e, 0xde,
+    0x0d, 0x00, 0xb1, 0x19, 0xc8, 0x54, 0xa4, 0x85, 0xc2, 0x1e, 0xe9, 0xad, 0xc4, 0x8c, 0x91, 0x0f,
+    0x88, 0xae, 0x23, 0xdb, 0xaa, 0x26, 0xb2, 0x58, 0xe6, 0xe4, 0x10, 0xa8, 0xa1, 0xbc, 0xc9, 0xe0,
+    0x77, 0xd1, 0xcb, 0x3a, 0x4f, 0x49, 0x5d, 0x9a, 0x5b, 0x84, 0x78, 0xb4, 0x3a, 0x84, 0xcd, 0x06,
+    0x57, 0xd3, 0xdd, 0x5d, 0x05, 0xf1, 0x10, 0xe5, 0x4e, 0xd0, 0x94, 0xda, 0x11, 0x32, 0x70, 0x62,
+    0xbb, 0x91, 0xc9, 0x88, 0x96, 0x2a, 0x7b, 0xc6, 0xae, 0x5c, 0xcc, 0xc2, 0x7c, 0x64, 0x5d, 0x10,
+    0xb7, 0x59, 0x70, 0xaa, 0x76, 0x21, 0x99, 0x63, 0xa6, 0x06, 0xa1, 0x9b, 0x51, 0xfc, 0xc0, 0x7a,
+    0x9d, 0xcd, 0x63, 0x57, 0x13, 0x2d, 0xc3, 0x38, 0x24, 0x64, 0x68, 0x69, 0xa3, 0xd8, 0x77, 0x72,
+    0xd9, 0xc4, 0x53, 0x24, 0x97, 0x8a, 0xe6, 0x98, 0x1d, 0xbd, 0x21, 0xa0, 0x1b, 0x70, 0xa6, 0x63,
+    0x2d, 0x16, 0xf7, 0x50, 0x79, 0x47, 0x3a, 0x65, 0xe6, 0x0b, 0xaa, 0x8b, 0x75, 0xdb, 0x15, 0x27,
+    0xb3, 0x2e, 0x75, 0x73, 0x10, 0xd8, 0x15, 0xce, 0x46, 0x7c, 0xac, 0x5c, 0x20, 0xf4, 0x9c, 0xd8,
+    0x54, 0xb3, 0x10, 0xc5, 0x31, 0x25, 0x83, 0x18, 0x6d, 0x71, 0xf6, 0x01, 0xf0, 0x3a, 0xe8, 0x63,
+    0x9c, 0x5b, 0x51, 0x93, 0x7a, 0x87, 0x08, 0x87, 0x4e, 0x37, 0xd2, 0x4c, 0x50, 0xb5, 0x5c, 0xdd,
+    0x53, 0xc6, 0x4b, 0xd7, 0x39, 0x40, 0x5f, 0x69, 0x43, 0xc4, 0x06, 0x8d, 0xe9, 0x2a, 0x8b, 0x09,
+    0x1d, 0x35, 0x76, 0x87, 0xa6, 0x54, 0x7c, 0x61, 0x72, 0xf1, 0xe8, 0x01, 0xb8, 0xc9, 0x80, 0x4b,
+    0xb1, 0x07, 0xc5, 0x0c, 0xd3, 0xda, 0x2a, 0xf7, 0x71, 0x78, 0x01, 0xf5, 0x39, 0x9a, 0x6b, 0x55,
+    0xb3, 0xec, 0x8e, 0x39, 0x0e, 0x02, 0xb2, 0x05, 0x69, 0x62, 0xd8, 0x75, 0xf1, 0x58, 0xe3, 0x96,
+    0xc8, 0xa4, 0xa1, 0x4f, 0xc8, 0x86, 0xd6, 0x46, 0x8b, 0x09, 0x4e, 0x96, 0x3b, 0x3a, 0x0c, 0xee,
+    0x12, 0x39, 0x27, 0xfa, 0xb5, 0x73, 0x85, 0xd4, 0xc3, 0xb9, 0xe9, 0x86, 0x8b, 0x51, 0x8f, 0xaa,
+    0x19, 0xc8, 0x6a, 0x93, 0xbb, 0x2f, 0x20, 0x2e, 0x52, 0x1e, 0x08, 0xba, 0x17, 0xb6, 0x54, 0x0f,
+    0x92, 0x18, 0x66, 0x64, 0x2b, 0xd1, 0x14, 0x65, 0x17, 0xd8, 0x73, 0x16, 0xd7, 0x4e, 0x66, 0x41,
+    0x7d, 0xc3, 0x1b, 0x24, 0xb8, 0x45, 0x3b, 0x51, 0x66, 0x81, 0xa9, 0xe3, 0xe6, 0x6e, 0x11, 0x27,
+    0x55, 0x3f, 0xb1, 0xb9, 0x7a, 0xb5, 0x71, 0x02, 0xc6, 0x72, 0x94, 0xc3, 0x82, 0x86, 0xaa, 0xcd,
+    0x91, 0xaf, 0x13, 0x57, 0x90, 0x3c, 0x2c, 0xba, 0x60, 0xb6, 0x98, 0x1d, 0x75, 0x31, 0x10, 0xcc,
+    0x26, 0xac, 0xa9, 0x71, 0x17, 0xcb, 0x07, 0xa4, 0xf7, 0x64, 0xa6, 0x4d, 0x7d, 0x44, 0x37, 0x4c,
+    0x71, 0xab, 0x75, 0x8a, 0x9b, 0x85, 0x60, 0xcf, 0x5d, 0x63, 0xcc, 0x0a, 0xf9, 0xa6, 0x74, 0x89,
+    0xd8, 0x16, 0x33, 0x91, 0x62, 0x81, 0x92, 0xe3, 0x8a, 0x06, 0x33, 0x9b, 0x74, 0x9f, 0x40, 0xaf,
+    0x72, 0x9e, 0x88, 0xba, 0x6c, 0x00, 0xb4, 0x6a, 0x00, 0x5b, 0xe7, 0x69, 0x34, 0x45, 0x03, 0x44,
+    0x03, 0x47, 0x03, 0x13, 0x17, 0x90, 0x3c, 0x47, 0x74, 0xad, 0x6c, 0x21, 0x3b, 0xe6, 0x62, 0x10,
+    0x98, 0x2d, 0x58, 0x13, 0xe3, 0xae, 0x0b, 0x3e, 0xd6, 0xde, 0x92, 0x99, 0x34, 0xf5, 0x09, 0xdd,
+    0xd0, 0xe2, 0x46, 0xeb, 0x04, 0x37, 0xcb, 0x5d, 0x3d, 0x75, 0x89, 0x31, 0x27, 0xe4, 0x6b, 0xe9,
+    0x0a, 0xb1, 0x0d, 0x66, 0xba, 0x89, 0xc5, 0x48, 0x8e, 0x2a, 0x1a, 0xc8, 0x6c, 0xca, 0x7d, 0x01,
+    0xbd, 0xc8, 0x79, 0x20, 0xea, 0x5e, 0xdc, 0x52, 0xe5, 0x41, 0xd2, 0x61, 0x66, 0xb6, 0x52, 0x4d,
+    0x51, 0x77, 0x81, 0xf1, 0x39, 0xeb, 0xb5, 0x9b, 0x59, 0x60, 0xc7, 0x70, 0x90, 0x88, 0x2d, 0x2a,
+    0x13, 0x85, 0x16, 0x18, 0x7b, 0x6c, 0x6e, 0x51, 0x4c, 0x2a, 0xf9, 0xc4, 0xe8, 0xea, 0xd9, 0x46,
+    0xe0, 0x04, 0xec, 0x72, 0x9c, 0xc3, 0xa2, 0x86, 0xea, 0xcd, 0x11, 0x7e, 0x9d, 0x5e, 0x41, 0xf3,
+    0xb0, 0xea, 0x82, 0xdd, 0x62, 0xc6, 0xa3, 0xae, 0x03, 0x01, 0x6d, 0x42, 0xfb, 0x22, 0x2e, 0x36,
+    0x1e, 0x10, 0xb9, 0x27, 0x34, 0x6d, 0xec, 0x20, 0x1a, 0xa6, 0x62, 0xab, 0xc9, 0x14, 0x47, 0x0b,
+    0xe1, 0x9e, 0x03, 0x5e, 0x63, 0x67, 0xc5, 0x7c, 0xd3, 0xba, 0x44, 0x6e, 0x8b, 0xe1, 0x44, 0xea,
+    0x02, 0x35, 0xc7, 0x55, 0x0d, 0x66, 0x37, 0xe9, 0xf8, 0x09, 0xc8, 0x55, 0xd2, 0x13, 0x61, 0x13,
+    0x74, 0xb9, 0xc6, 0x61, 0x22, 0x43, 0x0d, 0x6d, 0x43, 0xf6, 0xb5, 0xae, 0xb0, 0x78, 0x78, 0x72,
+    0x01, 0x6a, 0x1e, 0xdc, 0x51, 0xe4, 0x40, 0xd1, 0x4d, 0x3a, 0x53, 0xa5, 0x96, 0x98, 0x7b, 0x80,
+    0x78, 0x8f, 0x3a, 0xad, 0xe6, 0x23, 0xab, 0x6b, 0xee, 0xb6, 0x42, 0x4c, 0x41, 0x59, 0x18, 0x74,
+    0x40, 0xed, 0x5a, 0x67, 0x49, 0x7c, 0x33, 0x72, 0x09, 0xd1, 0xd3, 0xb2, 0x89, 0xb6, 0xc0, 0xc5,
+    0x71, 0x27, 0x83, 0x41, 0x6d, 0xc4, 0xfb, 0x24, 0x78, 0xb5, 0x7d, 0x42, 0xe6, 0x72, 0x6a, 0x02,
+    0xdd, 0x61, 0xc4, 0xa1, 0xaa, 0xdb, 0xd8, 0x4c, 0x72, 0x75, 0xc5, 0x43, 0xc6, 0x85, 0x90, 0x79,
+    0x41, 0x5f, 0xd5, 0x06, 0x92, 0x4d, 0x26, 0xa6, 0x92, 0x2c, 0x51, 0x74, 0xd0, 0xec, 0x9e, 0x4d,
+    0xbb, 0xf8, 0x08, 0xe6, 0x1a, 0xd6, 0x23, 0x71, 0x53, 0x94, 0x0b, 0xa5, 0x07, 0xcc, 0x0c, 0x37,
+    0xb5, 0x15, 0xdd, 0x37, 0xc5, 0x4b, 0xac, 0x4f, 0x6f, 0x6e, 0x80, 0x2d, 0xc0, 0xe3, 0x18, 0x83,
+    0x85, 0x6c, 0x54, 0x9a, 0x46, 0xec, 0xea, 0x3c, 0x41, 0x71, 0x59, 0x32, 0xc1, 0xe8, 0x33, 0xb3,
+    0xa1, 0x6e, 0x1b, 0xe8, 0x24, 0x9c, 0xa5, 0x51, 0x47, 0xf4, 0x2e, 0x08, 0xe7, 0x49, 0xbf, 0x9a,
+    0xb9, 0x88, 0xea, 0x99, 0xba, 0xa9, 0xc6, 0x25, 0xae, 0x07, 0x81, 0x06, 0xa0, 0x4d, 0xe3, 0x47,
+    0x11, 0xd7, 0x54, 0x1e, 0x11, 0xba, 0x1d, 0x5b, 0x48, 0x07, 0x54, 0x0c, 0x57, 0xb2, 0x95, 0xd1,
+    0x44, 0x67, 0x97, 0x00, 0x9f, 0xb2, 0x37, 0x62, 0x26, 0x5b, 0xdf, 0xf5, 0x06, 0x13, 0x6e, 0x34,
+    0x9d, 0x86, 0x66, 0x91, 0xaa, 0xa3, 0x76, 0x97, 0xc7, 0x09, 0x20, 0x9f, 0x01, 0x5d, 0x45, 0xdb,
+    0x26, 0x26, 0x69, 0x2c, 0x15, 0x39, 0x62, 0x68, 0x20, 0xd9, 0x3c, 0xfa, 0xaa, 0xe2, 0x22, 0x93,
+    0x67, 0x8e, 0xae, 0xc0, 0x2d, 0x41, 0x1e, 0x8c, 0x0e, 0xd0, 0xd9, 0xac, 0x35, 0x95, 0xdc, 0x35,
+    0xc3, 0x47, 0xa8, 0xb7, 0x35, 0x33, 0xac, 0x3e, 0xb8, 0x1b, 0xee, 0xb8, 0x15, 0xc8, 0x44, 0xa4,
+    0x65, 0xc2, 0x9e, 0xf6, 0x86, 0xc4, 0xe4, 0xc8, 0x77, 0x43, 0x97, 0x91, 0x6d, 0x54, 0xd3, 0x58,
+    0x2c, 0x72, 0x72, 0x14, 0x6a, 0x08, 0xde, 0x84, 0xe0, 0x67, 0xd1, 0xab, 0x3a, 0x0f, 0x49, 0xdd,
+    0x99, 0x5b, 0x4a, 0x3c, 0xa2, 0x3a, 0xd0, 0x66, 0x3b, 0xab, 0xc9, 0xee, 0x2e, 0x82, 0x78, 0x86,
+    0x72, 0x25, 0x68, 0xb6, 0x76, 0x50, 0x06, 0x98, 0xd8, 0x6c, 0x64, 0x2a, 0xa2, 0x85, 0xca, 0x1e,
+    0xb1, 0xdb, 0x8b, 0x19, 0x30, 0x1f, 0xb0, 0xae, 0xe3, 0x6d, 0x15, 0x9c, 0xa8, 0x5d, 0x46, 0xe6,
+    0x90, 0xa9, 0xa1, 0x74, 0x93, 0xc5, 0xef, 0xaa, 0x97, 0xd9, 0x3c, 0x75, 0x35, 0xcd, 0x22, 0x8c,
+    0xa3, 0x21, 0x43, 0x94, 0x36, 0x10, 0xfb, 0x4c, 0xae, 0x9a, 0x78, 0x88, 0xe4, 0x4e, 0xd1, 0x14,
+    0xb3, 0x23, 0x6e, 0x20, 0x74, 0x3b, 0x98, 0xc9, 0x58, 0x4b, 0xc5, 0x3d, 0x53, 0x5e, 0x91, 0xce,
+    0xce, 0x7c, 0xa4, 0xba, 0xa0, 0x6e, 0xb3, 0xe2, 0x54, 0xd6, 0x85, 0x6e, 0x8e, 0x01, 0xbb, 0xe5,
+    0x8c, 0x88, 0x0f, 0x2a, 0xd7, 0x95, 0x1e, 0x13, 0x9b, 0x68, 0x96, 0xa1, 0x38, 0xa4, 0x64, 0xa8,
+    0xd1, 0xc6, 0xb3, 0xef, 0xee, 0x32, 0xe8, 0x53, 0x9c, 0x4b, 0x51, 0x73, 0x7a, 0x47, 0x85, 0x43,
+    0x4c, 0x37, 0xa0, 0x99, 0x8e, 0x6a, 0xb1, 0xba, 0x87, 0x8c, 0x77, 0xae, 0x53, 0x80, 0xbe, 0xa0,
+    0x0d, 0x8c, 0xed, 0x22, 0x26, 0xab, 0x2c, 0x25, 0x74, 0xd0, 0xd8, 0x15, 0x9a, 0xad, 0xf8, 0x68,
+    0x72, 0xc1, 0xd1, 0x73, 0x67, 0x53, 0x01, 0x17, 0x62, 0x8f, 0x89, 0x19, 0xa4, 0xb5, 0x85, 0xdc,
+    0x07, 0xc3, 0xeb, 0xa6, 0x8f, 0xd1, 0xdc, 0xaa, 0x9a, 0x64, 0x77, 0xc8, 0x71, 0x28, 0xc8, 0x46,
+    0xd2, 0x04, 0x61, 0x97, 0xc5, 0x53, 0x8d, 0x4b, 0x22, 0x73, 0x86, 0xbe, 0x92, 0x0d, 0x51, 0x1b,
+    0x58, 0x4c, 0x67, 0xb2, 0xd8, 0xd1, 0x51, 0x70, 0x77, 0xc8, 0x29, 0xd1, 0x2f, 0x3a, 0x17, 0xa5,
+    0x1e, 0x98, 0x9b, 0x6c, 0xb8, 0x14, 0xf5, 0xa0, 0x9a, 0x61, 0xac, 0xb6, 0xde, 0x7d, 0x84, 0xb8,
+    0x80, 0xf2, 0x1c, 0xe9, 0x5a, 0xd8, 0x42, 0x3d, 0x46, 0x62, 0x90, 0x91, 0x2d, 0x88, 0x26, 0x96,
+    0x5d, 0x57, 0x8f, 0x59, 0xdc, 0x3a, 0x99, 0x04, 0xf5, 0x09, 0x6f, 0x68, 0x70, 0x63, 0x3b, 0x81,
+    0xcc, 0x72, 0x52, 0x87, 0xcd, 0x5d, 0x22, 0xce, 0xa9, 0x7e, 0xb5, 0xb9, 0xe2, 0x6a, 0x9b, 0x0e,
+    0x62, 0x31, 0xca, 0x51, 0x41, 0x03, 0xd5, 0xa6, 0xc8, 0x17, 0x13, 0x17, 0x49, 0x1e, 0x28, 0xba,
+    0x57, 0xb6, 0x94, 0x1d, 0x74, 0x31, 0x0c, 0x04, 0x53, 0x41, 0x2c, 0x44, 0x39, 0x26, 0x68, 0x90,
+    0xda, 0x0c, 0xf9, 0x60, 0xe2, 0xba, 0x91, 0xc7, 0x88, 0x6e, 0x95, 0x2d, 0x63, 0x87, 0x5c, 0x0c,
+    0x85, 0xd9, 0xc8, 0x9a, 0x20, 0xee, 0xb2, 0xe0, 0x53, 0xed, 0x25, 0x99, 0x39, 0x53, 0x5f, 0xe9,
+    0x86, 0x28, 0x6e, 0x60, 0x9d, 0xce, 0x66, 0xb1, 0xab, 0x87, 0xee, 0x30, 0xa6, 0x84, 0x7c, 0x51,
+    0xba, 0x28, 0xb6, 0x9d, 0x4c, 0x36, 0xb1, 0x14, 0xc9, 0x41, 0x45, 0xc3, 0x98, 0xcd, 0xde, 0x47,
+    0xe8, 0x05, 0x9c, 0xe7, 0x58, 0xd7, 0xe2, 0x16, 0x2a, 0x8f, 0x91, 0x0e, 0x32, 0xb3, 0x05, 0xd5,
+    0xc4, 0xba, 0xeb, 0x8a, 0x8f, 0x59, 0x6f, 0xdd, 0x4c, 0x02, 0x3b, 0x84, 0x43, 0x23, 0x36, 0x56,
+    0x26, 0x10, 0x5a, 0x4e, 0xec, 0xa9, 0xb9, 0x44, 0x31, 0xa7, 0xe4, 0xab, 0xd1, 0x15, 0x67, 0x1b,
+    0x00, 0xa7, 0x83, 0x2e, 0xc6, 0x39, 0x2a, 0x6a, 0xa0, 0xde, 0x14, 0xe1, 0x17, 0xd3, 0x8b, 0x34,
+    0x0f, 0x54, 0xdd, 0xab, 0x5b, 0xca, 0x78, 0xd0, 0x75, 0x18, 0xa0, 0xad, 0xb4, 0x8f, 0x71, 0x41,
+    0xe3, 0xb9, 0xca, 0x35, 0xa1, 0x59, 0x63, 0xc7, 0xd0, 0x20, 0x15, 0x5b, 0x98, 0x4c, 0x3c, 0x5a,
+    0x00, 0xee, 0x31, 0xe0, 0x2d, 0x76, 0x52, 0xcc, 0x27, 0xad, 0xab, 0x72, 0x1b, 0x87, 0x13, 0x50,
+    0x97, 0xa3, 0x39, 0xac, 0x6a, 0x28, 0xbb, 0x39, 0xc7, 0xaf, 0x20, 0x57, 0x90, 0x1e, 0x86, 0x4d,
+    0x17, 0x8b, 0x35, 0x8e, 0x12, 0x19, 0x68, 0x68, 0x13, 0xb2, 0x2f, 0xea, 0xa2, 0xc5, 0x03, 0x27,
+    0xf7, 0x8e, 0xa6, 0xc1, 0x1d, 0x44, 0x0e, 0x13, 0xdd, 0xda, 0x99, 0x42, 0x6a, 0xe1, 0xdc, 0x73,
+    0xc3, 0x6b, 0xd4, 0x59, 0x35, 0xdf, 0x58, 0x5d, 0x72, 0xb7, 0x05, 0xc4, 0x44, 0xca, 0x02, 0x41,
+    0xc7, 0x85, 0xdd, 0xea, 0x24, 0x89, 0x4f, 0x46, 0xae, 0x12, 0x3d, 0x51, 0x36, 0x81, 0x2d, 0x67,
+    0x71, 0xd8, 0xc9, 0x50, 0x50, 0xdb, 0xf0, 0xbe, 0x06, 0xaf, 0x68, 0x1f, 0xca, 0x5c, 0x98, 0x9a,
+    0x37, 0x77, 0x14, 0x71, 0xa0, 0xea, 0x26, 0x36, 0x53, 0xaf, 0x2e, 0x3e, 0xc0, 0xb8, 0x47, 0x99,
+    0x16, 0xf4, 0x51, 0x6d, 0x18, 0xd9, 0x3a, 0x31, 0x05, 0xc9, 0xc2, 0xa2, 0x03, 0xcc, 0xae, 0xd9,
+    0xac, 0x8b, 0x6f, 0x60, 0x2e, 0x61, 0x3d, 0x8d, 0x9b, 0x58, 0x2e, 0x20, 0x3d, 0x4e, 0x66, 0xb0,
+    0xa9, 0x8d, 0xe8, 0x3e, 0x29, 0x5e, 0xb5, 0x3e, 0x71, 0x73, 0x09, 0xb6, 0xdc, 0x61, 0x8c, 0xa1,
+    0x42, 0xb6, 0x29, 0x4d, 0x22, 0x76, 0xc5, 0x3c, 0xa4, 0xb8, 0x50, 0x32, 0xaf, 0xe8, 0x2b, 0xb3,
+    0x81, 0x6e, 0x13, 0xe8, 0x54, 0xce, 0x12, 0x51, 0x07, 0x7b, 0xf7, 0xca, 0x69, 0xd2, 0x8f, 0x66,
+    0xae, 0xa1, 0x7a, 0xa4, 0x6e, 0x0a, 0xe3, 0xc2, 0xeb, 0x01, 0x40, 0xc3, 0x81, 0xcd, 0xe2, 0x37,
+    0x11, 0x97, 0x54, 0x9e, 0x0a, 0xdd, 0x18, 0x5b, 0x80, 0x8e, 0xa3, 0x18, 0xac, 0x64, 0x23, 0xa3,
+    0x69, 0xce, 0xae, 0x02, 0x9f, 0x60, 0x2f, 0x63, 0x26, 0xb4, 0x3e, 0xeb, 0x0d, 0x25, 0xdc, 0x66,
+    0x3a, 0x09, 0xcd, 0xd2, 0xaa, 0x23, 0xec, 0x2e, 0x1c, 0xe7, 0x5d, 0xbf, 0x02, 0xba, 0x88, 0xb6,
+    0x49, 0x4c, 0x6d, 0x2c, 0x21, 0x72, 0x70, 0x68, 0x00, 0xb2, 0x69, 0xf4, 0x51, 0xc5, 0x35, 0x26,
+    0x8f, 0x1c, 0xdd, 0xc2, 0x2d, 0x24, 0x0f, 0x88, 0x0e, 0x17, 0xb3, 0x55, 0x6b, 0x22, 0xb9, 0x4b,
+    0x86, 0x4f, 0xa9, 0x37, 0x6a, 0x26, 0xad, 0xbe, 0xb3, 0x1b, 0xec, 0xb8, 0x11, 0xc8, 0x34, 0xa4,
+    0x45, 0xc2, 0x9e, 0xe8, 0xa5, 0xc4, 0x84, 0x91, 0xcf, 0x86, 0xae, 0x22, 0xdb, 0xa6, 0x26, 0xb1,
+    0x58, 0x7a, 0x72, 0x04, 0xd4, 0x40, 0xde, 0x3c, 0xf2, 0xab, 0xe8, 0x45, 0x9d, 0x67, 0xa4, 0xae,
+    0xcc, 0x2d, 0x41, 0x3c, 0x58, 0x1d, 0xc0, 0x66, 0x33, 0xab, 0xa9, 0xee, 0xae, 0x81, 0x78, 0x84,
+    0x72, 0x1b, 0x34, 0xa3, 0x76, 0x80, 0x0c, 0x27, 0xb1, 0xd5, 0xc8, 0x44, 0x44, 0xcb, 0x94, 0x3d,
+    0xb5, 0x1b, 0x17, 0x93, 0x30, 0xdf, 0x41, 0x5d, 0xc6, 0xdb, 0x28, 0x38, 0x4d, 0xbb, 0x88, 0xcc,
+    0xd1, 0xa9, 0x21, 0xe8, 0x26, 0x14, 0x3f, 0xab, 0x5e, 0x65, 0xf3, 0xd0, 0xd5, 0x24, 0x4b, 0x19,
+    0x47, 0x84, 0x0c, 0x2c, 0x6d, 0x57, 0xfb, 0x4a, 0x2e, 0x9a, 0x78, 0x86, 0xe4, 0x4a, 0xd1, 0xac,
+    0xd9, 0xc1, 0x1b, 0x00, 0xba, 0x19, 0xcc, 0x54, 0xac, 0x85, 0xe2, 0x1e, 0x29, 0x6f, 0xa5, 0x33,
+    0x66, 0x3e, 0xa0, 0xba, 0x8e, 0x6e, 0xab, 0xe2, 0x44, 0xd6, 0x65, 0x6e, 0x0e, 0x01, 0xbb, 0xc1,
+    0xc9, 0x88, 0xef, 0x42, 0x2e, 0x2b, 0x3d, 0x25, 0x36, 0xcd, 0x2c, 0x42, 0x71, 0xb4, 0x64, 0x08,
+    0xa3, 0x0d, 0xce, 0x3e, 0xbb, 0xab, 0xa0, 0x0f, 0x71, 0xee, 0x44, 0x4d, 0xe9, 0x1d, 0x21, 0x1c,
+    0x38, 0xdd, 0x6e, 0x66, 0x32, 0xaa, 0xa5, 0xea, 0x9e, 0x31, 0x5e, 0xb9, 0xce, 0x02, 0x7d, 0xa4,
+    0x0d, 0x10, 0x9b, 0x45, 0x4c, 0x55, 0x59, 0x48, 0xe8, 0x98, 0xb1, 0x5b, 0x9a, 0x51, 0xf1, 0x81,
+    0xc9, 0x75, 0x46, 0x8f, 0x9d, 0x4d, 0x04, 0x5c, 0x86, 0x3d, 0x24, 0x66, 0x68, 0x6b, 0xa3, 0xdc,
+    0x77, 0xc2, 0xcb, 0xa6, 0x4f, 0xd1, 0x5c, 0xaa, 0x9a, 0x63, 0x77, 0xf4, 0x38, 0x04, 0xc8, 0x06,
+    0xa4, 0xe9, 0x68, 0x57, 0xc5, 0x43, 0x8d, 0x3b, 0x22, 0x53, 0x86, 0xbe, 0x20, 0x1b, 0x58, 0xdb,
+    0x55, 0x4c, 0x66, 0xb2, 0xd4, 0xd1, 0x41, 0x70, 0x57, 0xc8, 0xd9, 0xe8, 0xc7, 0xce, 0x05, 0x52,
+    0xcf, 0xc9, 0x4d, 0x35, 0x5c, 0x88, 0x7a, 0x4c, 0xcd, 0x20, 0x56, 0x5b, 0xdc, 0x7d, 0x00, 0x71,
+    0x1d, 0xc8, 0x63, 0xa4, 0x5b, 0x61, 0xcb, 0xf4, 0x10, 0x89, 0xa1, 0x23, 0x1b, 0x89, 0x26, 0x28,
+    0xbb, 0xac, 0x9e, 0xb2, 0xb8, 0x74, 0x32, 0x07, 0xea, 0x2b, 0x6f, 0x88, 0xe0, 0x06, 0xed, 0x74,
+    0x9d, 0xc5, 0xa4, 0x8e, 0x9a, 0xbb, 0x43, 0x9c, 0x52, 0xfd, 0xc2, 0xe6, 0xe2, 0xd5, 0x03, 0x00,
+    0x9c, 0x0c, 0x62, 0x29, 0xca, 0x41, 0x41, 0xc3, 0xd4, 0x66, 0xe5, 0x63, 0xbd, 0xaf, 0x84, 0x17,
+    0x4d, 0x9f, 0xa1, 0xb9, 0x52, 0x35, 0x6b, 0x77, 0xf0, 0x38, 0x00, 0xc8, 0x66, 0x40, 0x53, 0xd1,
+    0xae, 0x89, 0x47, 0x1a, 0xb7, 0x22, 0x33, 0x86, 0x3e, 0x20, 0x1b, 0x8e, 0xb6, 0xaa, 0x98, 0xc8,
+    0x64, 0x99, 0xa3, 0x43, 0xe0, 0x6e, 0x90, 0x93, 0xd1, 0xef, 0x62, 0x2e, 0x6b, 0x3d, 0x25, 0x37,
+    0xcd, 0x70, 0x11, 0xea, 0xd1, 0x9a, 0x21, 0xac, 0x36, 0xb8, 0xfb, 0xec, 0x78, 0x15, 0xc8, 0x43,
+    0xa4, 0x3b, 0x61, 0x4b, 0x7b, 0x84, 0xc4, 0xc0, 0x91, 0xed, 0x86, 0x26, 0x23, 0xbb, 0xa8, 0x9e,
+    0xb1, 0xb8, 0x72, 0x32, 0x0b, 0xf5, 0x91, 0x37, 0x40, 0x70, 0xb3, 0xe8, 0x54, 0x9d, 0x85, 0xa4,
+    0x8e, 0x99, 0xbb, 0x25, 0xce, 0xa8, 0x7e, 0x60, 0x73, 0x9d, 0xd5, 0x63, 0x77, 0x13, 0x41, 0x2c,
+    0x43, 0x39, 0x24, 0x68, 0x68, 0x6d, 0x52, 0xbe, 0x93, 0xb8, 0x6c, 0xe4, 0x29, 0xa2, 0x4b, 0x65,
+    0x8b, 0xd8, 0xd1, 0x8b, 0x21, 0x60, 0x36, 0x60, 0x4d, 0xc7, 0xbb, 0x2a, 0xf8, 0x50, 0x7b, 0x47,
+    0x66, 0xca, 0xd4, 0x17, 0x74, 0x03, 0x8b, 0xdb, 0x55, 0x27, 0xb3, 0x59, 0xea, 0xea, 0x99, 0x2b,
+    0x8c, 0xd9, 0x90, 0x8f, 0xa5, 0x0b, 0xc4, 0x36, 0x93, 0xa9, 0x26, 0x16, 0x22, 0x39, 0xa6, 0x68,
+    0x10, 0xb3, 0x19, 0xf7, 0x01, 0xf4, 0x3a, 0x98, 0xc7, 0x58, 0xb7, 0xe2, 0x96, 0x29, 0x0f, 0x91,
+    0x0e, 0x9d, 0xd9, 0x48, 0x35, 0x41, 0xdd, 0x65, 0xc5, 0xa7, 0xac, 0x97, 0x6e, 0xe6, 0x80, 0x1d,
+    0xe5, 0x10, 0x11, 0x1b, 0x54, 0xa6, 0x2b, 0x2d, 0x26, 0xf6, 0xd0, 0xdc, 0xa1, 0x98, 0x52, 0xf2,
+    0x85, 0xd1, 0xc5, 0xb3, 0xed, 0xae, 0x53, 0xc7, 0x8f, 0xd3, 0x0b, 0x68, 0x9e, 0xa3, 0xba, 0x56,
+    0xb7, 0x90, 0xf1, 0x98, 0xeb, 0x20, 0x40, 0x5b, 0xd0, 0x3e, 0x88, 0xeb, 0x22, 0x1e, 0xab, 0xdc,
+    0x12, 0x9a, 0x34, 0x76, 0x08, 0x85, 0x90, 0x3d, 0x05, 0xbc, 0xc4, 0xce, 0x89, 0xf9, 0xda, 0xba,
+    0x42, 0x6e, 0x83, 0xe1, 0x74, 0xd3, 0xc5, 0x68, 0x8e, 0xaa, 0x1a, 0xc8, 0x6e, 0xca, 0xf1, 0x0b,
+    0x90, 0x8b, 0xa4, 0x07, 0xc2, 0x26, 0x8b, 0xa5, 0x1a, 0x07, 0x89, 0x0c, 0x33, 0xb4, 0x95, 0xec,
+    0x63, 0x5d, 0x60, 0xf1, 0x9c, 0xc9, 0xb5, 0xa3, 0x59, 0x70, 0xc7, 0x90, 0x83, 0x44, 0xb7, 0xe8,
+    0x4c, 0x94, 0x5a, 0x60, 0xee, 0xb1, 0xe1, 0x2d, 0xea, 0xa4, 0x9a, 0x4f, 0xac, 0xae, 0xde, 0x6d,
+    0x84, 0x98, 0x80, 0xb2, 0x1c, 0xe9, 0xb0, 0xb0, 0x4b, 0x9d, 0x23, 0xf1, 0x75, 0xe4, 0x0a, 0xa2,
+    0x87, 0x65, 0xd3, 0xd5, 0x62, 0x16, 0x47, 0x9d, 0x0c, 0x04, 0xb5, 0x09, 0xef, 0x8b, 0xe0, 0xc5,
+    0xf6, 0x01, 0x99, 0x7b, 0x52, 0xd3, 0xe6, 0x0e, 0x22, 0x0e, 0x53, 0xdd, 0x6a, 0x33, 0xc5, 0xd5,
+    0xf5, 0x1c, 0xc4, 0x35, 0xca, 0xac, 0xa0, 0x6f, 0x6a, 0x83, 0x48, 0x08, 0xd9, 0x2d, 0x9b, 0x74,
+    0xf1, 0x09, 0xcc, 0x55, 0xd6, 0x13, 0x71, 0x13, 0x94, 0x9d, 0x3a, 0x5e, 0x61, 0x7d, 0x78, 0x73,
+    0x01, 0x6c, 0xb1, 0xa3, 0x18, 0x03, 0x85, 0x6c, 0x52, 0x9a, 0x2a, 0x76, 0x71, 0x1e, 0xa0, 0xb8,
+    0x47, 0x32, 0xad, 0xe8, 0x23, 0xb3, 0x61, 0x6e, 0x2b, 0x74, 0x0a, 0xce, 0xc2, 0xa8, 0x03, 0x7a,
+    0xd7, 0xca, 0x59, 0xd2, 0x6f, 0x66, 0x2e, 0xa1, 0x7a, 0x5a, 0x37, 0xd1, 0xb8, 0xc0, 0xf5, 0xb8,
+    0x9b, 0xc1, 0xc0, 0x26, 0xf1, 0x93, 0x88, 0xab, 0x95, 0x27, 0x84, 0x2e, 0xc7, 0x96, 0x9b, 0xc3,
+    0x28, 0x86, 0x2a, 0xd9, 0xc6, 0x68, 0x92, 0xb3, 0x2b, 0x80, 0x0f, 0xd9, 0x0b, 0x31, 0xf3, 0xa2,
+    0xbe, 0xea, 0x0d, 0x24, 0xec, 0xd4, 0x71, 0xda, 0xf5, 0x23, 0xa0, 0x6b, 0x68, 0x5b, 0x23, 0x84,
+    0x6c, 0x16, 0x7d, 0x53, 0x71, 0x89, 0xc9, 0xd3, 0xa3, 0x1b, 0x70, 0x0b, 0xb0, 0x53, 0xc7, 0x27,
+    0xa8, 0x97, 0x35, 0x13, 0xac, 0x3e, 0xb3, 0x1b, 0xea, 0xb8, 0x0d, 0xc8, 0x24, 0xa4, 0xa5, 0x61,
+    0x0f, 0x7b, 0x41, 0x62, 0x9e, 0xc8, 0x57, 0x43, 0x17, 0x91, 0x6d, 0x52, 0x21, 0x75, 0xfc, 0x28,
+    0x7a, 0x4d, 0xe7, 0x11, 0xa9, 0xdb, 0xb9, 0x85, 0xc4, 0x03, 0xaa, 0xc3, 0xd5, 0x6c, 0x65, 0x35,
+    0xd1, 0xdd, 0x25, 0x10, 0x4f, 0x29, 0x37, 0x82, 0x26, 0x6b, 0xc7, 0x75, 0x30, 0x89, 0x8d, 0x46,
+    0xa6, 0x21, 0x5a, 0xa4, 0xec, 0x09, 0x8b, 0xa9, 0xe3, 0x24, 0xed, 0x52, 0x99, 0x23, 0xa6, 0x06,
+    0xd2, 0xcd, 0x23, 0x7e, 0x55, 0xbd, 0xc8, 0xe6, 0x99, 0xab, 0xa9, 0x21, 0x64, 0x1f, 0xc9, 0x35,
+    0x13, 0x8f, 0x90, 0xdc, 0x16, 0xcd, 0x30, 0x3b, 0xe0, 0x86, 0xbb, 0xd8, 0x0a, 0x66, 0x22, 0xd6,
+    0x32, 0x71, 0x4f, 0xcb, 0x1b, 0xd2, 0xc9, 0x99, 0xef, 0xa6, 0x2e, 0xa3, 0xdb, 0xa8, 0xd8, 0xb1,
+    0x4b, 0x4e, 0x88, 0xf8, 0x2c, 0xe4, 0xaa, 0xd2, 0x43, 0x62, 0x93, 0xcc, 0x52, 0x8a, 0x23, 0x4a,
+    0x06, 0x1a, 0x6d, 0x67, 0xf6, 0xd5, 0x5d, 0x04, 0x7d, 0x86, 0x73, 0x25, 0x6a, 0xb6, 0x77, 0x50,
+    0x38, 0xc0, 0x74, 0xb3, 0x99, 0xa9, 0xa8, 0x16, 0xaa, 0x7b, 0xc4, 0x78, 0x7b, 0x9d, 0x01, 0xf4,
+    0x01, 0x6d, 0x38, 0x6e, 0x15, 0x31, 0x51, 0x65, 0x19, 0xa1, 0x43, 0xc6, 0x6e, 0xd0, 0x64, 0xc5,
+    0x77, 0x25, 0x97, 0x19, 0x3d, 0x75, 0x36, 0x0d, 0x70, 0x11, 0xf6, 0x68, 0xcc, 0x10, 0xad, 0x0d,
+    0xe4, 0x3e, 0x13, 0x76, 0xea, 0x38, 0x10, 0x64, 0x3b, 0xa0, 0xc9, 0x68, 0x17, 0x45, 0x08, 0xd9,
+    0x00, 0xb5, 0x59, 0xc5, 0x54, 0x26, 0x0b, 0x1d, 0x1d, 0x03, 0x77, 0x4b, 0xce, 0x88, 0x7e, 0xd0,
+    0xb9, 0xae, 0xf5, 0x98, 0xdc, 0x44, 0xc3, 0x65, 0xa8, 0x87, 0xd4, 0x0c, 0xb5, 0xda, 0x78, 0xf7,
+    0xdd, 0xf1, 0x32, 0x90, 0xa7, 0x48, 0x97, 0xc2, 0x16, 0xe9, 0x51, 0x89, 0x21, 0x46, 0x36, 0x20,
+    0x9a, 0x8e, 0xec, 0xaa, 0x7a, 0xc8, 0xe2, 0xce, 0xc9, 0x14, 0xa8, 0x2f, 0x78, 0x03, 0x83, 0xdb,
+    0x45, 0x27, 0xeb, 0x2c, 0x25, 0x75, 0xd0, 0xdc, 0x15, 0xe2, 0x6c, 0xf5, 0xa3, 0xcd, 0x05, 0x57,
+    0xcf, 0xdd, 0x05, 0x37, 0x8d, 0xf1, 0x51, 0xc8, 0x35, 0xa5, 0x47, 0xc4, 0xa6, 0x98, 0x10, 0xb2,
+    0x6f, 0xee, 0x12, 0xe8, 0x53, 0xce, 0x8d, 0xa8, 0xc9, 0xde, 0x71, 0x65, 0xa7, 0x8e, 0x97, 0xd7,
+    0x09, 0x40, 0x9f, 0x81, 0x0d, 0xc5, 0x10, 0xb2, 0x0b, 0x34, 0x8f, 0xe2, 0xab, 0x92, 0x8b, 0x8c,
+    0x9e, 0x39, 0x9b, 0x0a, 0x5c, 0x82, 0x3d, 0x18, 0x33, 0x40, 0x6b, 0xb3, 0xde, 0x47, 0xc2, 0x6b,
+    0xa6, 0x8f, 0xd0, 0xdc, 0x56, 0xcd, 0xb0, 0x3b, 0xe0, 0x38, 0xdc, 0x75, 0x2b, 0xa0, 0x89, 0x68,
+    0x97, 0xc4, 0xd3, 0xc6, 0x0d, 0x91, 0xc9, 0xa1, 0xef, 0xc6, 0x06, 0xa3, 0x8d, 0x2a, 0xa6, 0x31,
+    0x59, 0xe4, 0xe8, 0x28, 0xdc, 0x25, 0x39, 0x21, 0xfa, 0x59, 0xcc, 0x55, 0xad, 0x87, 0xe4, 0x26,
+    0x19, 0x2e, 0xa5, 0x1e, 0x51, 0x33, 0xd0, 0x6a, 0x3b, 0xbb, 0xaf, 0x8e, 0x17, 0x81, 0x3c, 0x43,
+    0xba, 0x12, 0xb6, 0x44, 0x0f, 0x4a, 0x0c, 0x30, 0xb2, 0xd9, 0xd0, 0x54, 0x64, 0xd7, 0xd4, 0x23,
+    0x16, 0xb7, 0x27, 0x33, 0xa0, 0x3e, 0xe0, 0x0d, 0x47, 0x6e, 0x15, 0x9d, 0xa8, 0xb3, 0x8c, 0xd4,
+    0x21, 0x73, 0x37, 0x88, 0x93, 0xd5, 0xef, 0x6a, 0x2e, 0xb3, 0x7a, 0xea, 0x6e, 0x1a, 0x88, 0x45,
+    0x28, 0x47, 0x83, 0x86, 0xa8, 0x4d, 0x90, 0xcf, 0x24, 0xae, 0x1a, 0x79, 0x88, 0xe8, 0x4e, 0xd9,
+    0x52, 0x3b, 0xe2, 0x62, 0x20, 0xcc, 0x76, 0x50, 0x93, 0xf1, 0x2e, 0x0a, 0x3e, 0xd3, 0x5e, 0x91,
+    0x99, 0x9d, 0xfa, 0x48, 0x37, 0x40, 0x71, 0xb3, 0xea, 0x54, 0x36, 0x0b, 0x5d, 0x3d, 0x72, 0xcb,
+    0x98, 0x11, 0xf2, 0x41, 0xe9, 0xba, 0xda, 0x56, 0x32, 0xd1, 0xc4, 0x32, 0x24, 0x87, 0x14, 0x0d,
+    0x35, 0x9b, 0x8c, 0xa9, 0xe3, 0x51, 0xe9, 0x10, 0x33, 0x1b, 0x50, 0x4d, 0x47, 0x77, 0x55, 0xf1,
+    0x21, 0xeb, 0x9d, 0x9b, 0x29, 0x60, 0x47, 0x70, 0x60, 0xc4, 0x76, 0x21, 0x93, 0x95, 0x96, 0x12,
+    0x7b, 0x66, 0x42, 0xc8, 0x36, 0xbb, 0xa9, 0xa0, 0x0b, 0x71, 0x8e, 0x89, 0x1a, 0xa4, 0x37, 0x43,
+    0xd8, 0xa9, 0xe3, 0x21, 0xd7, 0xa1, 0x40, 0x1b, 0x69, 0xdf, 0x31, 0x84, 0xec, 0x28, 0x0d, 0x51,
+    0xb1, 0x81, 0xc9, 0x74, 0x46, 0x8b, 0x9d, 0x3d, 0x04, 0xbc, 0xc3, 0x4e, 0x89, 0xf9, 0xa2, 0x75,
+    0x51, 0x6e, 0x3b, 0xe1, 0x64, 0xd3, 0xa5, 0x68, 0x0e, 0xaa, 0x1a, 0xc6, 0x6e, 0xf6, 0xf8, 0x11,
+    0xe4, 0x02, 0xd2, 0x73, 0xb4, 0xa9, 0x62, 0xa1, 0xc6, 0x31, 0x22, 0x83, 0x0c, 0x6d, 0x41, 0xf6,
+    0x41, 0x5d, 0x57, 0xf1, 0x98, 0xc9, 0xad, 0xa3, 0x49, 0x70, 0x87, 0x90, 0x43, 0xa3, 0x1b, 0x3b,
+    0x13, 0x48, 0x2d, 0x27, 0xf7, 0xd4, 0xf0, 0x12, 0x75, 0x4e, 0xcd, 0x57, 0xab, 0x2b, 0xee, 0x36,
+    0x80, 0x98, 0x0e, 0x64, 0x31, 0xd2, 0x51, 0x61, 0x77, 0x3a, 0x45, 0xe2, 0x8b, 0x91, 0x8b, 0x44,
+    0x0f, 0x94, 0x4d, 0x56, 0x4b, 0x59, 0x1c, 0x74, 0x32, 0x0c, 0xd4, 0x56, 0xde, 0xc7, 0xe0, 0x05,
+    0xed, 0x73, 0x9d, 0x6b, 0x52, 0xb3, 0xe6, 0x8e, 0x21, 0x0e, 0x52, 0xdd, 0xc2, 0x66, 0xe2, 0xd5,
+    0x02, 0x00, 0x7c, 0x0c, 0xe2, 0x16, 0x65, 0x52, 0xd0, 0x27, 0xb5, 0xa1, 0xb2, 0x71, 0x62, 0x02,
+    0x92, 0xe5, 0x88, 0x0e, 0x2b, 0xbb, 0x64, 0x73, 0x2e, 0xbe, 0xc2, 0x5c, 0xc1, 0x7a, 0x18, 0x37,
+    0x5d, 0x70, 0xb1, 0xf6, 0x28, 0x99, 0x81, 0xa6, 0x36, 0xa1, 0xfb, 0xa2, 0x78, 0xd1, 0xfa, 0xc0,
+    0xcd, 0xbd, 0xab, 0xa5, 0x0e, 0x62, 0x0c, 0x13, 0xb2, 0xb5, 0x34, 0x85, 0xd8, 0x05, 0xf3, 0xdc,
+    0xc4, 0x35, 0x92, 0x59, 0x45, 0xdf, 0x98, 0x0d, 0xd2, 0xa9, 0xe3, 0x24, 0xe9, 0x27, 0x33, 0x57,
+    0xa9, 0x9e, 0xa8, 0x9b, 0xc0, 0xb8, 0x9c, 0xf5, 0xb0, 0x9b, 0xa1, 0xc0, 0xe6, 0xf0, 0x6b, 0xc4,
+    0x15, 0x95, 0x87, 0x42, 0x17, 0xc6, 0x16, 0x9b, 0x10, 0xb2, 0x8b, 0xc0, 0x07, 0xd8, 0x7b, 0x9c,
+    0x69, 0x51, 0x1f, 0xf5, 0x86, 0x11, 0x6e, 0x9d, 0x4e, 0x41, 0xb3, 0xb0, 0xea, 0x00, 0xbb, 0x6b,
+    0xc6, 0x59, 0xd7, 0x6f, 0x80, 0x2e, 0xa1, 0x6d, 0x11, 0x13, 0x1b, 0x0b, 0x88, 0x1c, 0x27, 0x34,
+    0xd8, 0xd8, 0x24, 0xfa, 0xa4, 0xe2, 0xaa, 0xc9, 0x13, 0x47, 0x97, 0x70, 0xcb, 0x01, 0x0f, 0x63,
+    0x87, 0x8a, 0xd9, 0xa6, 0x35, 0x89, 0xdc, 0x15, 0xc3, 0x87, 0xd4, 0x0b, 0x35, 0xf3, 0xaa, 0xbe,
+    0xb2, 0x1b, 0xe8, 0xb8, 0x09, 0xc8, 0x54, 0xd2, 0x12, 0x61, 0x0f, 0xf4, 0x5e, 0x63, 0x9a, 0xc8,
+    0x47, 0x43, 0xd7, 0x90, 0x6d, 0xad, 0x29, 0x2c, 0x16, 0x9e, 0x1c, 0x00, 0x35, 0x1c, 0xdc, 0x2c,
+    0xf2, 0x9b, 0xe8, 0x25, 0x9d, 0xa7, 0x52, 0x37, 0xe6, 0x16, 0x20, 0x1e, 0x47, 0x1d, 0xac, 0x66,
+    0x23, 0xab, 0x69, 0xee, 0xae, 0x42, 0x3c, 0x41, 0xb9, 0x0c, 0x9a, 0x50, 0x3b, 0xac, 0x43, 0x49,
+    0x6c, 0x33, 0x32, 0x09, 0xd1, 0xd2, 0xb2, 0x87, 0x76, 0xe1, 0x62, 0xde, 0xc9, 0x57, 0x50, 0x17,
+    0xf1, 0x36, 0x09, 0x4e, 0x6d, 0x97, 0x90, 0x39, 0x38, 0x35, 0x00, 0xdd, 0x34, 0xe2, 0x47, 0xd5,
+    0x6b, 0x6c, 0x1e, 0xb9, 0x9a, 0x62, 0x21, 0xe3, 0x80, 0x90, 0xe1, 0x82, 0xb6, 0xaa, 0x7d, 0x23,
+    0x97, 0x4c, 0x3c, 0x25, 0xb9, 0x51, 0x34, 0x69, 0x76, 0x9c, 0x0d, 0x76, 0xb1, 0x11, 0xcc, 0x34,
+    0xac, 0x45, 0xe2, 0x9e, 0x28, 0x2f, 0xa5, 0x13, 0x66, 0x3e, 0x9b, 0xba, 0x8a, 0x6e, 0x9b, 0xe2,
+    0x24, 0xd6, 0xa5, 0x37, 0x47, 0x80, 0x5d, 0xe0, 0x3c, 0xc6, 0x57, 0x21, 0x17, 0x95, 0x9e, 0x11,
+    0x9b, 0x3a, 0x4b, 0x50, 0x1c, 0x2c, 0x19, 0xc0, 0x68, 0x33, 0xb3, 0x8f, 0xee, 0x1a, 0xe8, 0x23,
+    0x9c, 0xdb, 0xa8, 0x19, 0xbd, 0x03, 0x84, 0xc3, 0x49, 0xb7, 0x9a, 0x99, 0x88, 0x6a, 0x99, 0xba,
+    0xa7, 0xc6, 0x1b, 0xd7, 0x49, 0xa0, 0xef, 0xc0, 0x06, 0xe3, 0x46, 0x11, 0xd3, 0x54, 0x16, 0x11,
+    0x3a, 0x3a, 0x76, 0x49, 0x13, 0x2a, 0x3e, 0x2b, 0xb9, 0xca, 0xe8, 0xa1, 0xb3, 0x49, 0x80, 0x4b,
+    0xd9, 0x23, 0x62, 0x06, 0xb6, 0xb6, 0x0b, 0x62, 0xa8, 0x11, 0x10, 0xc7, 0xff, 0x50, 0x44, 0x41,
+    0xa1, 0xb4, 0x70, 0xe0, 0xf7, 0x33, 0x10, 0xf0, 0x07, 0x51, 0xd7, 0xad, 0x0d, 0x63, 0x41, 0x71,
+    0x0a, 0xdd, 0x2e, 0x63, 0xa1, 0x06, 0x87, 0xb6, 0xd0, 0x69, 0xb6, 0x60, 0xd2, 0x2a, 0x68, 0xc3,
+    0x15, 0xa2, 0xa7, 0x0a, 0xf3, 0x43, 0x23, 0xae, 0x60, 0xba, 0xff, 0xed, 0xe5, 0xa9, 0x0a, 0x94,
+    0x32, 0x00, 0x5a, 0xa7, 0xb4, 0x12, 0x09, 0xb0, 0x79, 0x8c, 0x6c, 0x62, 0xbe, 0x60, 0xf8, 0x09,
+    0x26, 0x01, 0x2c, 0x01, 0x26, 0x01, 0x45, 0xf4, 0x6e, 0x24, 0xe8, 0x7e, 0x81, 0x48, 0x07, 0xaf,
+    0x0a, 0x2e, 0x31, 0xd0, 0x10, 0xc2, 0x4a, 0x0c, 0x42, 0x43, 0x30, 0x1e, 0x44, 0x39, 0x40, 0x2b,
+    0xc1, 0x63, 0x03, 0xe5, 0x04, 0xad, 0xc3, 0x88, 0x06, 0xe4, 0x45, 0xa8, 0xc6, 0xe1, 0x94, 0x02,
+    0x9a, 0x11, 0xb0, 0x6c, 0xc0, 0x60, 0x61, 0x8a, 0x87, 0xa0, 0x16, 0x88, 0x2d, 0x50, 0xfc, 0xe0,
+    0xa3, 0x42, 0xa7, 0x0e, 0x06, 0x56, 0xd0, 0x3b, 0x0e, 0x7d, 0x73, 0x13, 0x93, 0xf5, 0x35, 0x12,
+    0x04, 0xf2, 0x72, 0x12, 0x65, 0x32, 0x37, 0x12, 0xf4, 0x72, 0x12, 0x55, 0x85, 0xba, 0xb9, 0x91,
+    0x2a, 0x08, 0xb9, 0x1c, 0x89, 0x9a, 0x97, 0x1b, 0x29, 0x22, 0x77, 0x33, 0xf4, 0xcb, 0x4d, 0x88,
+    0xdc, 0xdd, 0x48, 0x90, 0xcb, 0xc9, 0x8a, 0xac, 0x3f, 0x3c, 0xb9, 0x9c, 0x04, 0xdd, 0xdc, 0x48,
+    0x8d, 0x53, 0x9f, 0xa6, 0x2f, 0x37, 0x51, 0xbe, 0xbb, 0x21, 0xb9, 0xdc, 0x04, 0xdd, 0xdd, 0x48,
+    0x95, 0x5c, 0x4e, 0x82, 0x6e, 0x6e, 0xa2, 0xe8, 0x2e, 0x27, 0xc5, 0xd5, 0xa7, 0x09, 0xba, 0xcb,
+    0x91, 0x3a, 0xbd, 0x9c, 0x04, 0xdd, 0xe5, 0x44, 0x05, 0xb9, 0x5c, 0xed, 0xbb, 0x9b, 0xa8, 0x91,
+    0xcb, 0x91, 0x2a, 0x01, 0xd3, 0xcd, 0x71, 0x6a, 0x7f, 0xf8, 0x92, 0xd9, 0xca, 0xd7, 0xa7, 0x3a,
+    0xb0, 0xaa, 0xa0, 0x77, 0x37, 0x51, 0xa5, 0x77, 0x24, 0xe8, 0xee, 0x24, 0x8a, 0xc8, 0x30, 0xe5,
+    0x48, 0x11, 0x4a, 0x37, 0x27, 0x51, 0x42, 0xee, 0x46, 0xea, 0x74, 0x73, 0x24, 0x8a, 0x77, 0x37,
+    0x51, 0x41, 0x2f, 0x47, 0xa2, 0x7e, 0x73, 0x12, 0x35, 0x7a, 0x37, 0x12, 0x74, 0x73, 0x12, 0xe5,
+    0xba, 0x1b, 0xa9, 0x81, 0xd1, 0xcb, 0x91, 0xa8, 0xb2, 0xb9, 0x91, 0x2a, 0xbd, 0x1c, 0x89, 0x9a,
+    0x9b, 0x9b, 0x20, 0x72, 0x37, 0x12, 0xd5, 0x97, 0x13, 0x60, 0x94, 0x90, 0xb9, 0x91, 0xa0, 0x1a,
+    0xa0, 0x46, 0xc1, 0x94, 0x03, 0x37, 0x05, 0x95, 0x09, 0x2c, 0x38, 0x90, 0x62, 0xa1, 0xde, 0xc3,
+    0xed, 0x02, 0xe2, 0x05, 0x3c, 0x1a, 0x70, 0xaa, 0x30, 0x12, 0x04, 0x0b, 0x61, 0xa0, 0x0a, 0x82,
+    0xba, 0xc0, 0x3d, 0x03, 0xed, 0x08, 0x23, 0x0d, 0xb2, 0x12, 0x8c, 0x18, 0x22, 0x3e, 0x40, 0x74,
+    0x82, 0x4f, 0x06, 0xba, 0x08, 0xca, 0x10, 0xd8, 0x64, 0x20, 0x33, 0x85, 0x8a, 0x1d, 0x5e, 0x2b,
+    0xa0, 0x53, 0xc0, 0xee, 0x80, 0x75, 0x61, 0xf8, 0x43, 0x8c, 0x0a, 0x44, 0x4a, 0xa0, 0xaa, 0x06,
+    0xa7, 0x30, 0xf4, 0x42, 0xe0, 0x0e, 0x90, 0x1b, 0x98, 0x91, 0x10, 0x53, 0x03, 0x85, 0x09, 0x8a,
+    0x0c, 0xff, 0x07, 0x6a, 0x22, 0xac, 0x65, 0x10, 0x46, 0xc1, 0x8c, 0x08, 0x84, 0xcc, 0x80, 0x6a,
+    0x0a, 0xa6, 0x1d, 0x78, 0x16, 0xb4, 0x15, 0x18, 0x3d, 0x90, 0xbd, 0x50, 0x42, 0x87, 0x47, 0x15,
+    0x10, 0x95, 0x80, 0x1f, 0x04, 0x5a, 0x0c, 0xd3, 0x21, 0xd8, 0x06, 0x32, 0x1c, 0xa8, 0x28, 0xe1,
+    0x55, 0x43, 0xc9, 0x04, 0xf6, 0x81, 0xa5, 0x81, 0x61, 0x11, 0x62, 0x71, 0x20, 0x91, 0x82, 0x2a,
+    0x11, 0x38, 0xcd, 0xa0, 0x5f, 0x61, 0xe6, 0x41, 0xda, 0x82, 0x99, 0x05, 0x62, 0x3e, 0xa0, 0xa0,
+    0x82, 0x8b, 0x0e, 0xa4, 0x2a, 0xa8, 0x63, 0x60, 0x15, 0x82, 0x70, 0x0c, 0x35, 0x23, 0x08, 0x21,
+    0x50, 0x71, 0xc0, 0x54, 0x02, 0x6f, 0xc3, 0xf8, 0x04, 0x23, 0x81, 0xac, 0x06, 0x4a, 0xa2, 0xf0,
+    0x90, 0x43, 0x4b, 0x0a, 0xfc, 0x11, 0xa0, 0x37, 0x98, 0xb2, 0x10, 0xec, 0x81, 0xc6, 0x05, 0x15,
+    0x17, 0x78, 0x7d, 0x50, 0xa2, 0xc2, 0x5e, 0x07, 0x16, 0x86, 0x32, 0x10, 0x1b, 0x02, 0xc9, 0x0c,
+    0x0e, 0x12, 0x9c, 0x08, 0x7d, 0x07, 0x66, 0x13, 0xa4, 0x37, 0xd4, 0x88, 0x20, 0xa6, 0x80, 0x22,
+    0x06, 0xae, 0x28, 0x90, 0xe4, 0x30, 0x9f, 0x82, 0x75, 0x02, 0x42, 0x0e, 0x6a, 0x5a, 0xb8, 0xf8,
+    0xd0, 0x78, 0x81, 0xe9, 0x02, 0x3c, 0x0d, 0xc6, 0x55, 0x88, 0x06, 0x81, 0x4c, 0xa0, 0x64, 0x06,
+    0x1e, 0x23, 0xb4, 0x66, 0x18, 0x91, 0x80, 0x2e, 0x4c, 0x3d, 0x10, 0x3c, 0x81, 0x86, 0x0c, 0x96,
+    0x22, 0x38, 0xdd, 0x9c, 0x44, 0x99, 0xdc, 0x4d, 0xd4, 0xd0, 0xcd, 0x91, 0xa8, 0x22, 0xe0, 0xdd,
+    0x4d, 0x14, 0xbd, 0x1c, 0x09, 0xba, 0x39, 0x89, 0xe6, 0x26, 0x2a, 0xe8, 0xdd, 0x48, 0xd4, 0x6f,
+    0x4e, 0xa2, 0x46, 0xee, 0x46, 0x82, 0x5e, 0x4e, 0xa2, 0x4c, 0xe6, 0x46, 0x6a, 0x60, 0xe8, 0xe5,
+    0x48, 0x54, 0x19, 0xea, 0xe6, 0x46, 0xaa, 0xe4, 0x72, 0x24, 0x6a, 0x5e, 0x6e, 0xa2, 0x88, 0xdc,
+    0x8d, 0x04, 0xbd, 0x9c, 0xc0, 0xa2, 0xe4, 0x26, 0xeb, 0x13, 0x4d, 0x5f, 0xc2, 0xb1, 0xf5, 0xa9,
+    0x82, 0x60, 0x0a, 0x82, 0x5c, 0x8e, 0x04, 0xbd, 0xdc, 0x48, 0xed, 0x2e, 0x47, 0x82, 0x5c, 0x6e,
+    0xa2, 0x7c, 0x77, 0x23, 0x35, 0xe4, 0x72, 0x13, 0x74, 0x73, 0x23, 0xad, 0xcf, 0x49, 0xd0, 0xcd,
+    0x4d, 0x14, 0xdd, 0xe5, 0x48, 0x91, 0x5e, 0x6e, 0x82, 0xee, 0x72, 0xa4, 0x4e, 0x2f, 0x27, 0x41,
+    0x77, 0x37, 0x51, 0x41, 0x2e, 0x27, 0x65, 0xba, 0x09, 0xb3, 0x89, 0xda, 0x5d, 0x8e, 0x54, 0x0d,
+    0x98, 0x6e, 0x4e, 0xa2, 0x7c, 0x97, 0x13, 0x35, 0xf4, 0x72, 0x12, 0x74, 0x77, 0x13, 0xed, 0xcb,
+    0x91, 0x12, 0x30, 0xdd, 0x9d, 0x44, 0x11, 0xb9, 0x1c, 0x29, 0xd2, 0xcd, 0x49, 0x94, 0x10, 0xf4,
+    0xb3, 0x21, 0xeb, 0x9f, 0x2a, 0x4a, 0x64, 0xb6, 0xf2, 0xac, 0xca, 0xa9, 0x62, 0xa0, 0x15, 0x84,
+    0x91, 0x18, 0xe4, 0x43, 0x30, 0x1d, 0xa7, 0xfe, 0x87, 0x2f, 0xd9, 0x56, 0xbe, 0x3e, 0x55, 0xd0,
+    0xcd, 0x91, 0x28, 0x93, 0xbb, 0x91, 0x1a, 0x7a, 0x39, 0x12, 0x55, 0x87, 0x1d, 0xc4, 0xdc, 0x44,
+    0x95, 0xde, 0x8d, 0x44, 0xcd, 0xcd, 0x4d, 0x14, 0x91, 0xbb, 0x91, 0xa0, 0x97, 0x93, 0x28, 0x21,
+    0x73, 0x23, 0x41, 0x2f, 0x47, 0xa2, 0x78, 0x73, 0x23, 0x15, 0xe4, 0x72, 0x24, 0xea, 0x2f, 0x37,
+    0xb1, 0x71, 0x37, 0x12, 0xf4, 0x72, 0x13, 0xe5, 0xbb, 0x1b, 0x09, 0x72, 0x39, 0x89, 0x2a, 0x02,
+    0xdd, 0xdc, 0x48, 0x95, 0x5c, 0x0e, 0x4c, 0x82, 0x5e, 0x6e, 0xa4, 0x5c, 0x8e, 0x04, 0xbd, 0xdc,
+    0x44, 0xc9, 0xdd, 0x8d, 0xd4, 0xc9, 0x8d, 0x01, 0x29, 0x85, 0xd9, 0x1c, 0x62, 0x56, 0x20, 0x41,
+    0x81, 0x22, 0x0e, 0xfe, 0x16, 0x0e, 0xeb, 0x0b, 0x38, 0x12, 0x98, 0xa9, 0x41, 0x48, 0x94, 0x82,
+    0x4c, 0x82, 0xee, 0x6e, 0xa2, 0xe2, 0x2e, 0x57, 0xfb, 0xe6, 0x26, 0x6a, 0x77, 0x39, 0x52, 0x35,
+    0x30, 0xbd, 0x9c, 0x04, 0xdd, 0xe5, 0x44, 0x0d, 0xbd, 0x9c, 0x04, 0xdd, 0xdd, 0x44, 0x95, 0x5c,
+    0x8e, 0x94, 0xd0, 0xdd, 0x49, 0x14, 0x91, 0xcb, 0x89, 0x22, 0xdd, 0x9c, 0x44, 0xc9, 0xdd, 0x4d,
+    0xd4, 0xe9, 0x1d, 0x09, 0xba, 0x3b, 0x89, 0x0a, 0x7a, 0x39, 0x12, 0x74, 0x73, 0x12, 0x35, 0x72,
+    0x7d, 0xaa, 0xba, 0x39, 0x12, 0x65, 0x72, 0x37, 0x51, 0x43, 0x6f, 0x7d, 0xaa, 0x32, 0x37, 0x51,
+    0xa5, 0x77, 0x23, 0x41, 0xb3, 0x21, 0xeb, 0x13, 0x55, 0x94, 0xc8, 0x6c, 0xe5, 0x5b, 0x59, 0x5f,
+    0x43, 0x13, 0x88, 0xc7, 0xa9, 0x4f, 0x87, 0x21, 0x1a, 0x88, 0x17, 0x81, 0x1a, 0x07, 0x53, 0x0a,
+    0x3e, 0x23, 0x50, 0xd9, 0x60, 0xc1, 0x82, 0x14, 0x0f, 0xf5, 0x16, 0x84, 0x2d, 0x40, 0xfc, 0xc0,
+    0xa3, 0x02, 0xa7, 0x0e, 0x41, 0xee, 0x46, 0xa2, 0xfe, 0x72, 0x13, 0x35, 0x72, 0x37, 0x12, 0x04,
+    0xf1, 0x72, 0x12, 0xe5, 0x9b, 0x1b, 0x09, 0x72, 0x39, 0x89, 0xaa, 0x02, 0xbd, 0xdc, 0x48, 0x15,
+    0x82, 0x5c, 0x8e, 0x04, 0xbd, 0xdc, 0x48, 0xd1, 0xdd, 0x8d, 0x04, 0xb9, 0xdc, 0x44, 0xc9, 0xdd,
+    0x8d, 0xd4, 0xc9, 0xe5, 0x24, 0xe8, 0xe6, 0x46, 0x2a, 0xee, 0x72, 0x12, 0x74, 0x73, 0x13, 0x35,
+    0xa0, 0xbb, 0x1c, 0xa9, 0x22, 0x9c, 0x5e, 0x6e, 0x82, 0xee, 0x0e, 0x66, 0xa4, 0x86, 0x5c, 0x4e,
+    0x82, 0xee, 0x6e, 0xa2, 0x4a, 0x2e, 0x27, 0x25, 0x74, 0x73, 0x13, 0x45, 0x77, 0x39, 0x52, 0xa4,
+    0x9b, 0x93, 0xa0, 0xbb, 0x9c, 0xa8, 0xd3, 0xcb, 0x49, 0xd0, 0xdd, 0x4d, 0x54, 0x90, 0xcb, 0x91,
+    0x32, 0x98, 0xee, 0x4e, 0xa2, 0x46, 0x2e, 0x47, 0xaa, 0x04, 0x4a, 0x37, 0x27, 0x51, 0xbe, 0xbb,
+    0x89, 0x1a, 0xba, 0x39, 0xd2, 0xa8, 0x2a, 0xe0, 0xdd, 0x49, 0x54, 0xe9, 0xe5, 0x48, 0xd0, 0xcd,
+    0x49, 0x14, 0x91, 0x41, 0xba, 0x91, 0x62, 0xfd, 0x91, 0x28, 0x21, 0x77, 0x23, 0x75, 0x7a, 0x39,
+    0x12, 0xc5, 0x07, 0x85, 0xa3, 0xa8, 0x53, 0x60, 0xad, 0xc9, 0xcc, 0x48, 0x52, 0x1e, 0xa5, 0x4a,
+    0x63, 0x23, 0x7f, 0x51, 0x92, 0xe4, 0x01, 0xe3, 0xf2, 0x0f, 0xbf, 0x41, 0xfa, 0xb2, 0xce, 0xe5,
+    0x85, 0xcf, 0x1f, 0x6f, 0xaa, 0xd9, 0x30, 0xa5, 0x03, 0xc2, 0xda, 0x0c, 0x09, 0x65, 0x59, 0x92,
+    0xf4, 0xb5, 0x92, 0xa5, 0x19, 0xdc, 0x42, 0x84, 0x5e, 0x90, 0x93, 0xb1, 0xaa, 0xd8, 0x3f, 0xb4,
+    0x84, 0xcf, 0x26, 0x36, 0xff, 0x97, 0x33, 0x5c, 0x28, 0x5f, 0xef, 0xcb, 0x81, 0x49, 0x6f, 0xac,
+    0xd0, 0xec, 0x25, 0xee, 0x80, 0x32, 0x36, 0x1f, 0xdb, 0x64, 0xd0, 0x58, 0xd0, 0x08, 0x74, 0x98,
+    0x06, 0xd7, 0x53, 0xec, 0x08, 0xae, 0x01, 0xe2, 0xe2, 0xc0, 0xeb, 0xca, 0x19, 0xff, 0xc2, 0x42,
+    0x6b, 0x07, 0x80, 0x76, 0xad, 0xfe, 0x9a, 0x3e, 0xff, 0xea, 0x2e, 0x7c, 0x1c, 0x3c, 0x34, 0xb9,
+    0xc6, 0x5f, 0xd4, 0xc2, 0xbd, 0x75, 0x28, 0xf7, 0x09, 0x7d, 0x9e, 0xd3, 0x00, 0xad, 0x75, 0x6f,
+    0xb5, 0x64, 0x2d, 0x53, 0x6d, 0xa7, 0x8d, 0x6c, 0xe4, 0x23, 0xd8, 0xb7, 0x75, 0x2d, 0x55, 0x1d,
+    0x35, 0xb8, 0x05, 0xfb, 0x5e, 0xc3, 0x8b, 0xab, 0xaf, 0x62, 0xb1, 0x97, 0x15, 0x78, 0x52, 0x52,
+    0xd4, 0xf6, 0xea, 0x2f, 0xe6, 0x1f, 0x45, 0x19, 0xcd, 0x9a, 0xd2, 0x66, 0x54, 0x9d, 0x0a, 0x9d,
+    0x4b, 0x7c, 0xa5, 0xa0, 0x55, 0x0a, 0x32, 0x4e, 0x78, 0x0a, 0x3d, 0x06, 0xde, 0xb1, 0xe3, 0xd3,
+    0x16, 0xe5, 0xfd, 0x76, 0x38, 0x6b, 0xb0, 0x20, 0xf4, 0x1a, 0x3b, 0x6c, 0xec, 0x96, 0x45, 0xac,
+    0x5e, 0xc2, 0x21, 0x17, 0x1e, 0xe6, 0xbb, 0x19, 0xec, 0x65, 0x4d, 0x52, 0xd3, 0xf0, 0xa5, 0xeb,
+    0xc0, 0xb6, 0x2f, 0x0c, 0xca, 0x0a, 0xcd, 0x71, 0x7b, 0x50, 0x62, 0xe1, 0x0b, 0x6c, 0x91, 0x97,
+    0x02, 0x00, 0x45, 0x45, 0xef, 0x34, 0x6c, 0x73, 0x07, 0xfb, 0x7a, 0xf1, 0xa9, 0x31, 0xc3, 0xc2,
+    0x90, 0x83, 0x73, 0xf6, 0xa9, 0xef, 0xe0, 0x8d, 0x89, 0x74, 0x7f, 0xe6, 0x6a, 0xd6, 0x96, 0x94,
+    0x42, 0xce, 0x79, 0xb9, 0xa2, 0x19, 0x33, 0x6a, 0xc9, 0x08, 0xbf, 0xd8, 0x43, 0x50, 0x40, 0x4c,
+    0x04, 0x0b, 0xb8, 0xc3, 0x31, 0x71, 0x45, 0x80, 0x56, 0x75, 0xcc, 0x86, 0x0a, 0x15, 0x41, 0xc2,
+    0x6c, 0xa7, 0x3f, 0x71, 0xd5, 0xfd, 0x51, 0x53, 0x8c, 0x46, 0xae, 0x8d, 0x3a, 0x09, 0x8a, 0x0f,
+    0x08, 0x0f, 0x65, 0xe1, 0xd3, 0x63, 0xe5, 0x51, 0x84, 0x03, 0xa4, 0x1f, 0xf1, 0xac, 0x04, 0x71,
+    0x71, 0x9b, 0x76, 0x63, 0x3d, 0x20, 0x0f, 0x66, 0x0b, 0x2b, 0xfb, 0xc2, 0xb1, 0x16, 0xef, 0xd5,
+    0x8c, 0xf6, 0xc6, 0x1e, 0xa9, 0x13, 0x10, 0xf6, 0x42, 0x79, 0x8b, 0x08, 0x0f, 0x9d, 0xb7, 0x27,
+    0xdb, 0x6c, 0x14, 0x23, 0x7f, 0xc0, 0xcc, 0x4e, 0x90, 0xd5, 0x1c, 0x82, 0x62, 0x47, 0x26, 0x11,
+    0x00, 0x8d, 0x79, 0x64, 0x11, 0x88, 0xee, 0xc9, 0xfa, 0xdc, 0xae, 0x6e, 0xf8, 0x45, 0x2a, 0x80,
+    0x0b, 0x5a, 0xbc, 0x08, 0x0d, 0x26, 0x00, 0xcb, 0x74, 0xe0, 0x0c, 0xca, 0x00, 0xce, 0x00, 0xcd,
+    0x00, 0x7d, 0x09, 0xc7, 0xd6, 0xa7, 0x0a, 0x02, 0x71, 0x39, 0x12, 0xf4, 0x72, 0x13, 0xb5, 0xbb,
+    0x3e, 0x55, 0xb9, 0xdc, 0x04, 0xdd, 0xdd, 0x48, 0x0d, 0xb9, 0x9c, 0x04, 0xdd, 0xdc, 0x44, 0xeb,
+    0x72, 0x52, 0x42, 0x37, 0x37, 0x51, 0x74, 0x97, 0x23, 0x45, 0x7a, 0x39, 0x09, 0xba, 0xcb, 0x89,
+    0x3a, 0xbd, 0x9c, 0xd4, 0xee, 0x6e, 0xa2, 0x82, 0x5c, 0x8e, 0x94, 0xe9, 0x26, 0xc8, 0x24, 0x6a,
+    0x77, 0x39, 0x51, 0x35, 0x50, 0xba, 0x39, 0x89, 0xf2, 0xdd, 0x4d, 0xd4, 0xd0, 0xcb, 0x91, 0xa0,
+    0xbb, 0x93, 0x68, 0x5f, 0x8e, 0x94, 0x40, 0xe9, 0xe6, 0x24, 0x8a, 0xc8, 0xdd, 0x48, 0x91, 0x6e,
+    0x8e, 0x44, 0xc9, 0xdd, 0xd5, 0xff, 0xcb, 0x91, 0x28, 0xde, 0xdc, 0x44, 0x05, 0xbd, 0xe3, 0xd4,
+    0xff, 0xf0, 0x25, 0xdb, 0xca, 0xd7, 0xa7, 0x0a, 0x7a, 0x39, 0x12, 0x65, 0x32, 0x37, 0x52, 0x43,
+    0x2f, 0x47, 0xa2, 0xea, 0x60, 0x6e, 0xa2, 0x4a, 0xee, 0x46, 0xa2, 0xe6, 0x65, 0x7d, 0x22, 0x73,
+    0x23, 0x41, 0x2f, 0x27, 0x51, 0x72, 0x73, 0x23, 0x41, 0x2e, 0x47, 0xa2, 0xf8, 0x72, 0x23, 0x15,
+    0xe4, 0x72, 0x24, 0xe8, 0xe5, 0x26, 0x6a, 0x77, 0x37, 0x12, 0xe4, 0x72, 0x13, 0xe5, 0xbb, 0x1b,
+    0xa9, 0x21, 0x97, 0x93, 0xa0, 0x9b, 0x1b, 0xa9, 0xde, 0xe5, 0x04, 0xbd, 0xdc, 0x44, 0x11, 0xd0,
+    0x5d, 0x8e, 0x14, 0xe9, 0xe5, 0x26, 0xe8, 0xee, 0xea, 0xdf, 0x5d, 0x9c, 0xa0, 0xea, 0x3b, 0xc2,
+    0x04, 0xf9, 0x9d, 0x54, 0x90, 0x39, 0x93, 0x7a, 0xcd, 0x09, 0x21, 0xa8, 0x77, 0x9c, 0x20, 0xbf,
+    0x93, 0x0a, 0x32, 0x67, 0x52, 0x27, 0x73, 0x42, 0xea, 0x1d, 0x27, 0xc8, 0xef, 0xa4, 0x82, 0xcc,
+    0x4e, 0xea, 0x64, 0x4e, 0x48, 0xbd, 0xe3, 0x04, 0xf9, 0x9d, 0x4c, 0x90, 0xd9, 0x49, 0x9d, 0xcc,
+    0x09, 0xa9, 0x77, 0x9c, 0xa0, 0x7a, 0x27, 0x13, 0x64, 0x76, 0x52, 0x72, 0x32, 0x67, 0x03, 0xa9,
+    0x78, 0xcd, 0x09, 0xaa, 0x22, 0x78, 0x27, 0x13, 0x64, 0x76, 0x52, 0x13, 0x64, 0xce, 0xa4, 0xe4,
+    0x35, 0x27, 0xa8, 0xe2, 0x9d, 0x4c, 0x90, 0xdf, 0x49, 0x2d, 0xc8, 0x9c, 0x49, 0xcd, 0x6b, 0x4e,
+    0x50, 0xc9, 0x3b, 0x4e, 0x90, 0xdf, 0x49, 0x31, 0xc8, 0x9c, 0x49, 0xed, 0x35, 0x27, 0xa4, 0xe6,
+    0x1d, 0x27, 0xc8, 0x8f, 0x91, 0xf5, 0x25, 0x83, 0x1e, 0xa6, 0xaa, 0x60, 0x37, 0x27, 0x51, 0xa5,
+    0x77, 0x23, 0x41, 0x37, 0x27, 0x51, 0x44, 0x86, 0xe8, 0x46, 0x8a, 0x2d, 0x47, 0xa2, 0x84, 0xcc,
+    0x8d, 0xd4, 0xe9, 0xdd, 0x48, 0x14, 0x6f, 0x6e, 0xa2, 0x82, 0xdc, 0x8d, 0x44, 0xfd, 0xe5, 0x24,
+    0x6a, 0x64, 0x6e, 0x24, 0xe8, 0xe5, 0x24, 0xca, 0x37, 0x37, 0x52, 0x03, 0x42, 0x2e, 0x47, 0xa2,
+    0xca, 0x40, 0x2f, 0x37, 0x52, 0x25, 0x97, 0x23, 0x41, 0x2f, 0x37, 0x51, 0x74, 0x77, 0x23, 0x41,
+    0x2e, 0x27, 0x30, 0x51, 0x72, 0x93, 0xf5, 0x4f, 0x13, 0x13, 0x54, 0x7b, 0x27, 0x13, 0x64, 0x76,
+    0x52, 0x0e, 0x32, 0x67, 0x52, 0x7d, 0xcd, 0x09, 0x2a, 0xbe, 0x13, 0x98, 0x20, 0xbf, 0x93, 0x0a,
+    0x32, 0x67, 0x52, 0xaf, 0x39, 0x21, 0x87, 0x7a, 0xc7, 0x09, 0xf2, 0x3b, 0xa9, 0x20, 0x73, 0x26,
+    0x75, 0x32, 0x27, 0xa4, 0xde, 0x71, 0x82, 0xfc, 0x4e, 0x2a, 0xc8, 0xec, 0xa4, 0xe8, 0x64, 0x4e,
+    0x48, 0xfd, 0x1d, 0x27, 0xc8, 0xef, 0x64, 0x82, 0xcc, 0x0e, 0x5d, 0xe6, 0x84, 0x14, 0xbd, 0xe3,
+    0x04, 0xd5, 0x07, 0xef, 0x64, 0x82, 0xcc, 0x4e, 0x2a, 0x4e, 0xe6, 0x6c, 0x86, 0xf0, 0x9a, 0x13,
+    0x54, 0x44, 0xf0, 0x4e, 0x26, 0xc8, 0xec, 0xa4, 0x24, 0xc8, 0x9c, 0x49, 0xc5, 0x6b, 0x4e, 0x50,
+    0xbd, 0x93, 0x09, 0xf2, 0x3b, 0xa9, 0x09, 0x32, 0x67, 0x52, 0xf2, 0x9a, 0x13, 0x54, 0xf1, 0x8e,
+    0x13, 0xe4, 0x77, 0x52, 0x0b, 0x32, 0x67, 0x52, 0xf3, 0x9a, 0x13, 0x52, 0xf2, 0x8e, 0x13, 0xe4,
+    0x77, 0x52, 0x0c, 0x32, 0x67, 0x52, 0x3b, 0x99, 0x13, 0x52, 0xf3, 0x8e, 0x13, 0xe4, 0x77, 0x32,
+    0x85, 0x20, 0xb3, 0x93, 0xe2, 0xc9, 0x9c, 0x90, 0xda, 0x3b, 0x4e, 0x50, 0x8d, 0xc0, 0x3b, 0x99,
+    0x20, 0xb3, 0x93, 0xea, 0xc9, 0x9c, 0x90, 0xe2, 0x6b, 0x4e, 0x50, 0xcd, 0xf0, 0x4e, 0x26, 0xc8,
+    0xec, 0xa4, 0x7c, 0x32, 0x67, 0x52, 0x7d, 0xcd, 0x09, 0x2a, 0xbe, 0x93, 0x09, 0x32, 0x3b, 0xa9,
+    0x07, 0x99, 0x33, 0x29, 0xbf, 0x0e, 0x24, 0xc0, 0x09, 0x2a, 0x31, 0xbc, 0x93, 0x09, 0x32, 0x3b,
+    0x29, 0x9e, 0xcc, 0x99, 0xd4, 0x5e, 0x73, 0x82, 0x6a, 0xde, 0xc9, 0x04, 0x99, 0x9d, 0x54, 0x83,
+    0xcc, 0x99, 0x14, 0x5f, 0x73, 0x82, 0x6a, 0xef, 0x64, 0x82, 0xfc, 0x4e, 0xca, 0x41, 0xe6, 0x4c,
+    0xaa, 0xaf, 0x39, 0x21, 0x86, 0xe2, 0x3b, 0x4e, 0x90, 0xdf, 0x49, 0x3d, 0xc8, 0x9c, 0x49, 0xf9,
+    0x64, 0x4e, 0x48, 0xf5, 0x1d, 0x27, 0xc8, 0xef, 0xa4, 0x82, 0xcc, 0x4e, 0xea, 0x64, 0x4e, 0x48,
+    0xbd, 0xe3, 0x04, 0xf9, 0x9d, 0x4c, 0x90, 0xd9, 0x49, 0x9d, 0xcc, 0x09, 0xa9, 0x77, 0x9c, 0xa0,
+    0x7a, 0x27, 0x13, 0x64, 0x76, 0x52, 0x27, 0x73, 0x26, 0x20, 0xf5, 0x9a, 0x13, 0x54, 0xef, 0x64,
+    0x82, 0xcc, 0x4e, 0x2a, 0x0a, 0xc8, 0x9c, 0x49, 0xbd, 0xe6, 0x04, 0xd5, 0x3b, 0x99, 0x20, 0xbf,
+    0x93, 0x0a, 0x32, 0x67, 0x52, 0xaf, 0x39, 0x41, 0xf5, 0x8e, 0x13, 0xe4, 0x77, 0x52, 0x13, 0x64,
+    0xce, 0xa4, 0xe4, 0x35, 0x27, 0xa4, 0xe2, 0x1d, 0x27, 0xc8, 0xef, 0xa4, 0x16, 0x64, 0xce, 0xa4,
+    0xe6, 0x64, 0x4e, 0x48, 0xc9, 0x3b, 0x4e, 0x90, 0xdf, 0xc9, 0x10, 0x82, 0xcc, 0x4e, 0x6a, 0x27,
+    0x73, 0x42, 0x6a, 0xde, 0x71, 0x82, 0x4a, 0x04, 0xde, 0xc9, 0x04, 0x99, 0x9d, 0x14, 0x4f, 0xe6,
+    0x84, 0xd4, 0x5e, 0x73, 0x82, 0x6a, 0x0c, 0xef, 0x64, 0x82, 0xcc, 0x4e, 0xaa, 0x27, 0x73, 0x26,
+    0xc5, 0xd7, 0x0c, 0x86, 0x6d, 0xa8, 0x33, 0x60, 0x8e, 0xb5, 0x59, 0x03, 0xf3, 0xde, 0x32, 0x92,
+    0x42, 0x07, 0x83, 0xf2, 0x0f, 0xc1, 0x9f, 0x03, 0x7a, 0x5c, 0x8f, 0x71, 0x7d, 0xc8, 0x2f, 0x9f,
+    0x0c, 0x5c, 0xe2, 0xf3, 0xce, 0x27, 0x6a, 0x53, 0x7f, 0xa1, 0x0f, 0x41, 0x3e, 0x63, 0x62, 0x41,
+    0xdd, 0xb8, 0x15, 0xcc, 0x1c, 0x6b, 0x81, 0x62, 0x2b, 0xb2, 0x00, 0xa3, 0x96, 0x4c, 0x14, 0xb2,
+    0x5a, 0x6c, 0xf0, 0x9a, 0xc1, 0x64, 0x5a, 0x56, 0x98, 0x0d, 0x0c, 0x2f, 0x08, 0xb6, 0xb9, 0x2f,
+    0x42, 0xbe, 0xa0, 0x12, 0x27, 0xac, 0x1a, 0x95, 0xf4, 0x4f, 0xf8, 0xa8, 0xc9, 0x13, 0xb0, 0xdc,
+    0x0a, 0xff, 0x84, 0x1b, 0x5e, 0xb7, 0xdd, 0xbd, 0x1b, 0x70, 0x03, 0xda, 0x1e, 0xb2, 0xcf, 0xe5,
+    0xf4, 0x21, 0x62, 0x37, 0xbd, 0x72, 0x68, 0xc9, 0x04, 0x5b, 0x83, 0xe0, 0x65, 0x94, 0xc2, 0xe0,
+    0xe2, 0x45, 0x80, 0xe9, 0x4b, 0x17, 0x83, 0x2a, 0x09, 0xb9, 0x7d, 0x17, 0xe3, 0xd3, 0x50, 0x07,
+    0x72, 0x30, 0xbd, 0xf0, 0xdf, 0x25, 0xc6, 0xda, 0xe4, 0x27, 0x2a, 0x9e, 0xb2, 0x46, 0xa4, 0xb1,
+    0xde, 0x95, 0xdd, 0x5d, 0x63, 0xac, 0x04, 0x6f, 0xa7, 0x82, 0xd3, 0x3a, 0x59, 0xae, 0xcb, 0x76,
+    0x2b, 0xdf, 0x52, 0xee, 0x33, 0x5d, 0x78, 0x59, 0xec, 0x9c, 0x1d, 0x69, 0x7b, 0xc8, 0x3e, 0x47,
+    0x75, 0x4b, 0x90, 0x9c, 0xb1, 0x65, 0x8d, 0xcc, 0x05, 0x2d, 0xbb, 0x54, 0x1c, 0x01, 0xd8, 0x41,
+    0x28, 0xbf, 0x4d, 0xd3, 0x60, 0xfa, 0xf9, 0x85, 0xf5, 0x41, 0x34, 0x8c, 0x34, 0xa0, 0x37, 0x92,
+    0xba, 0xec, 0xe0, 0x36, 0x57, 0x26, 0x37, 0xd6, 0x00, 0x3b, 0x88, 0xbc, 0x4e, 0x7a, 0xf9, 0x1b,
+    0xb1, 0x93, 0xce, 0xd2, 0x9c, 0x85, 0x11, 0x3e, 0x55, 0xb3, 0xd7, 0x08, 0xa0, 0x73, 0xbd, 0xf2,
+    0x07, 0xc5, 0x01, 0x2a, 0x8d, 0xdf, 0x86, 0xbf, 0x6c, 0x73, 0x5f, 0x73, 0xce, 0xfd, 0xb5, 0x1a,
+    0xfa, 0x5a, 0x29, 0x8d, 0xdf, 0x0c, 0xcd, 0x8f, 0xe1, 0x4e, 0xb1, 0x34, 0x1c, 0x71, 0xe9, 0x2b,
+    0x0c, 0x04, 0x93, 0xf8, 0xb7, 0x4b, 0x62, 0xc7, 0xa8, 0xf5, 0xd7, 0x50, 0xc7, 0x1d, 0xd5, 0xb6,
+    0x10, 0x78, 0x62, 0x83, 0x87, 0x03, 0x70, 0x7d, 0xb5, 0x74, 0x1a, 0xad, 0x4a, 0xf3, 0x8a, 0x4f,
+    0xc8, 0x29, 0x4d, 0x73, 0xc0, 0xb3, 0x16, 0xd8, 0xe4, 0xce, 0xfe, 0xba, 0x19, 0xf8, 0x38, 0x74,
+    0xa3, 0xd7, 0xeb, 0xf0, 0x87, 0xe2, 0xe4, 0x3b, 0x8e, 0xbd, 0x31, 0xa3, 0xa2, 0x1f, 0xe2, 0x7b,
+    0xd1, 0x11, 0xf1, 0x72, 0xd4, 0x46, 0xac, 0x85, 0x40, 0x30, 0x39, 0xf3, 0x83, 0x97, 0x35, 0xf0,
+    0xec, 0xc4, 0x48, 0x93, 0xe1, 0x82, 0xa4, 0xac, 0x07, 0xf2, 0xca, 0xf1, 0xf0, 0xe3, 0xf0, 0x73,
+    0xd4, 0x74, 0x63, 0x8d, 0x5a, 0x1d, 0xd3, 0x2c, 0xc6, 0x23, 0x21, 0xcc,
+];
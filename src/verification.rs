@@ -1,14 +1,258 @@
 // Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
 
+pub mod backend;
+pub mod certificate;
+pub mod compression;
+pub mod dice;
+mod encryption;
+pub mod header;
+mod image;
+pub mod keyring;
+#[cfg(feature = "lms")]
+pub mod lms;
+pub mod manifest;
+pub mod measurement;
+#[cfg(feature = "mldsa")]
+pub mod mldsa;
+pub mod pkcs1;
+pub mod revocation;
+mod rollback;
+#[cfg(target_arch = "riscv64")]
+pub mod scalar;
+pub mod self_test;
+#[cfg(target_arch = "riscv64")]
+pub mod vector;
+
+use backend::{Sha256, Sha384, Sha512, Sm3};
+use certificate::{Certificate, MAX_CHAIN_LEN};
+use compression::Compression;
 use console::{print, println};
-#[cfg(feature = "qemu_vector")]
-use core::arch::asm;
-use core::slice;
-use pelite::pe64::{self, Pe};
-#[cfg(not(feature = "qemu_vector"))]
-use sha2::{Digest, Sha256};
-
-#[cfg(not(feature = "qemu_vector"))]
+use core::{fmt, slice};
+use encryption::Encryption;
+use header::{
+    HashAlgorithm,
+    Header,
+    SignatureAlgorithm,
+    MAX_DIGEST_LEN,
+    TLV_CERTIFICATE,
+    TLV_COMPRESSION,
+    TLV_ENCRYPTION,
+};
+use image::ImageHeader;
+use keyring::{
+    TrustedKey,
+    KEY_USAGE_CERTIFICATE,
+    KEY_USAGE_IMAGE,
+    KEY_USAGE_REVOCATION,
+};
+use manifest::{Component, ComponentKind, Manifest};
+use revocation::{RevocationList, FINGERPRINT_LEN};
+use sha2::{digest::Output, Digest};
+use sha3::Sha3_256;
+
+#[cfg(not(any(
+    feature = "ed25519",
+    feature = "p256",
+    feature = "rsa",
+    feature = "lms",
+    feature = "mldsa"
+)))]
+compile_error!("at least one signature algorithm feature must be enabled");
+
+/// Reasons a signed image can be rejected
+#[derive(Debug)]
+pub enum VerificationError {
+    /// Header is too short to hold its own fields
+    Truncated,
+    /// Header does not start with `header::MAGIC`
+    BadMagic,
+    /// Header format version is not understood by this loader
+    UnsupportedVersion(u16),
+    /// Header length does not place the payload at the kernel address
+    HeaderLength(usize),
+    /// Signature algorithm identifier is not supported
+    UnsupportedSignatureAlgorithm(u8),
+    /// Digest algorithm identifier is not supported
+    UnsupportedHashAlgorithm(u8),
+    /// Kernel encryption cipher identifier is not supported
+    UnsupportedCipher(u8),
+    /// Kernel compression format identifier is not supported
+    UnsupportedCompression(u8),
+    /// Header was signed by a key this loader does not hold
+    UnknownKey([u8; 8]),
+    /// Header was signed by a key not trusted to sign boot images
+    KeyUsage([u8; 8]),
+    /// Signature algorithm differs from the one the key was issued for
+    KeyAlgorithm([u8; 8]),
+    /// RSA key is too short to be trusted, holding its size in bits
+    #[cfg_attr(not(feature = "rsa"), allow(dead_code))]
+    WeakKey([u8; 8], usize),
+    /// Header was signed by a key that has since been revoked
+    RevokedKey([u8; 8]),
+    /// Key certificate shipped with the image is malformed
+    InvalidCertificate(&'static str),
+    /// Link from the issuer to the subject key of a certificate chain failed
+    Certificate([u8; 8], [u8; 8], &'static str),
+    /// Revocation update was loaded but is malformed
+    InvalidRevocationList(&'static str),
+    /// Payload, or the memory the kernel claims, runs into the DTB
+    PayloadOverlapsDtb(usize),
+    /// Payload is not a bootable RISC-V Linux `Image`
+    InvalidKernelImage(&'static str),
+    /// Signed TLV record that may only appear once was repeated
+    DuplicateTlv(u16),
+    /// Image security version is below the stored anti-rollback floor
+    Rollback(u32, u32),
+    /// Revocation update sequence, 0 when none was loaded, is below the
+    /// highest one this board has accepted
+    RevocationRollback(u32, u32),
+    /// Persistent storage holding the anti-rollback floor failed
+    Storage(&'static str),
+    /// Manifest kernel entry disagrees with the header payload fields
+    ManifestPayloadMismatch,
+    /// Manifest holds a component kind this loader does not know
+    UnknownComponent(u32),
+    /// Manifest describes the same component twice
+    DuplicateComponent(ComponentKind),
+    /// Manifest does not describe a component required to boot
+    MissingComponent(ComponentKind),
+    /// Component was not loaded where the BSP expects it
+    ComponentAddress(ComponentKind),
+    /// Component runs into the following region
+    ComponentOverlap(ComponentKind),
+    /// Component digest does not match the signed digest
+    ComponentHashMismatch(ComponentKind),
+    /// Signature failed to verify with the named algorithm
+    Signature(SignatureAlgorithm),
+    /// Encrypted kernel could not be decrypted
+    Decryption(&'static str),
+    /// Compressed kernel could not be decompressed
+    Decompression(&'static str),
+    /// Measured boot event log could not be handed to the kernel
+    EventLog(&'static str),
+    /// DICE handover could not be written or handed to the kernel
+    Dice(&'static str),
+}
+
+impl fmt::Display for VerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerificationError::Truncated => write!(f, "image header truncated"),
+            VerificationError::BadMagic => {
+                write!(f, "image header magic not found")
+            }
+            VerificationError::UnsupportedVersion(x) => {
+                write!(f, "unsupported image header version {}", x)
+            }
+            VerificationError::HeaderLength(x) => write!(
+                f,
+                "image header length 0x{:X} does not reach kernel at 0x{:X}",
+                x,
+                bsp::memory::map::kernel::KERNEL
+            ),
+            VerificationError::UnsupportedSignatureAlgorithm(x) => {
+                write!(f, "unsupported signature algorithm {}", x)
+            }
+            VerificationError::UnsupportedHashAlgorithm(x) => {
+                write!(f, "unsupported hash algorithm {}", x)
+            }
+            VerificationError::UnsupportedCipher(x) => {
+                write!(f, "unsupported kernel cipher {}", x)
+            }
+            VerificationError::UnsupportedCompression(x) => {
+                write!(f, "unsupported kernel compression {}", x)
+            }
+            VerificationError::UnknownKey(x) => {
+                write!(f, "unknown signing key {:02X?}", x)
+            }
+            VerificationError::KeyUsage(x) => {
+                write!(f, "key {:02X?} may not sign boot images", x)
+            }
+            VerificationError::KeyAlgorithm(x) => {
+                write!(f, "key {:02X?} does not sign with this algorithm", x)
+            }
+            VerificationError::WeakKey(x, y) => write!(
+                f,
+                "key {:02X?} of {} bits is below the minimum of {}",
+                x,
+                y,
+                pkcs1::MIN_BITS
+            ),
+            VerificationError::RevokedKey(x) => {
+                write!(f, "signing key {:02X?} has been revoked", x)
+            }
+            VerificationError::InvalidCertificate(x) => {
+                write!(f, "invalid certificate: {}", x)
+            }
+            VerificationError::Certificate(x, y, z) => write!(
+                f,
+                "certificate chain link {:02X?} -> {:02X?} failed: {}",
+                x, y, z
+            ),
+            VerificationError::InvalidRevocationList(x) => {
+                write!(f, "invalid revocation update: {}", x)
+            }
+            VerificationError::PayloadOverlapsDtb(x) => write!(
+                f,
+                "kernel of 0x{:X} bytes runs into the DTB at 0x{:X}",
+                x,
+                bsp::memory::map::kernel::DTB
+            ),
+            VerificationError::InvalidKernelImage(x) => {
+                write!(f, "invalid kernel image: {}", x)
+            }
+            VerificationError::DuplicateTlv(x) => {
+                write!(f, "signed record 0x{:04X} repeated", x)
+            }
+            VerificationError::Rollback(x, y) => {
+                write!(f, "security version {} is below the minimum {}", x, y)
+            }
+            VerificationError::RevocationRollback(x, y) => write!(
+                f,
+                "revocation update sequence {} is below the minimum {}",
+                x, y
+            ),
+            VerificationError::Storage(x) => {
+                write!(f, "anti-rollback storage: {}", x)
+            }
+            VerificationError::ManifestPayloadMismatch => {
+                write!(f, "manifest kernel does not match signed payload")
+            }
+            VerificationError::UnknownComponent(x) => {
+                write!(f, "unknown manifest component {}", x)
+            }
+            VerificationError::DuplicateComponent(x) => {
+                write!(f, "{:?} listed more than once in manifest", x)
+            }
+            VerificationError::MissingComponent(x) => {
+                write!(f, "{:?} missing from manifest", x)
+            }
+            VerificationError::ComponentAddress(x) => {
+                write!(f, "{:?} load address does not match the BSP", x)
+            }
+            VerificationError::ComponentOverlap(x) => {
+                write!(f, "{:?} runs into the following region", x)
+            }
+            VerificationError::ComponentHashMismatch(x) => {
+                write!(f, "{:?} hash does not match signed hash", x)
+            }
+            VerificationError::Signature(x) => {
+                write!(f, "{:?} signature verification failed", x)
+            }
+            VerificationError::Decryption(x) => {
+                write!(f, "kernel decryption: {}", x)
+            }
+            VerificationError::Decompression(x) => {
+                write!(f, "kernel decompression: {}", x)
+            }
+            VerificationError::EventLog(x) => {
+                write!(f, "measured boot event log: {}", x)
+            }
+            VerificationError::Dice(x) => write!(f, "DICE handover: {}", x),
+        }
+    }
+}
+
 fn min(a: usize, b: usize) -> usize {
     if a < b {
         return a;
@@ -16,361 +260,82 @@ fn min(a: usize, b: usize) -> usize {
     b
 }
 
-#[cfg(not(feature = "qemu_vector"))]
-fn hash_kernel() -> [u8; 32] {
-    let mut hasher = Sha256::new();
+/// Hashes `size` bytes at `address` with `D` in 4K chunks
+fn stream_region<D: Digest>(address: usize, size: usize) -> Output<D> {
+    let mut hasher = D::new();
     let mut offset = 0;
-    let kernel_size: usize = get_kernel_size();
-    let mut buff_size = min(4096, kernel_size);
+    let mut buff_size = min(4096, size);
     loop {
         // We have to form a data structure from the raw pointer as this pointer
         // is defined by the MMIO we are forced to trust it; however, the slice
         // is immutable so we can only read it.
         let data = unsafe {
-            slice::from_raw_parts(
-                (bsp::memory::map::kernel::KERNEL + offset) as *mut u8,
-                buff_size,
-            )
+            slice::from_raw_parts((address + offset) as *mut u8, buff_size)
         };
         hasher.update(data);
         offset += buff_size;
-        buff_size = min(4096, kernel_size - offset);
+        buff_size = min(4096, size - offset);
         if buff_size == 0 {
             break;
         }
     }
-    hasher.finalize().into()
+    hasher.finalize()
 }
 
-#[cfg(feature = "qemu_vector")]
-const SHA256_ROUND_CONSTANTS: [u32; 64] = [
-    0x428A2F98, 0x71374491, 0xB5C0FBCF, 0xE9B5DBA5, // 0-3
-    0x3956C25B, 0x59F111F1, 0x923F82A4, 0xAB1C5ED5, // 4-7
-    0xD807AA98, 0x12835B01, 0x243185BE, 0x550C7DC3, // 8-11
-    0x72BE5D74, 0x80DEB1FE, 0x9BDC06A7, 0xC19BF174, // 12-15
-    0xE49B69C1, 0xEFBE4786, 0x0FC19DC6, 0x240CA1CC, // 16-19
-    0x2DE92C6F, 0x4A7484AA, 0x5CB0A9DC, 0x76F988DA, // 20-23
-    0x983E5152, 0xA831C66D, 0xB00327C8, 0xBF597FC7, // 24-27
-    0xC6E00BF3, 0xD5A79147, 0x06CA6351, 0x14292967, // 28-31
-    0x27B70A85, 0x2E1B2138, 0x4D2C6DFC, 0x53380D13, // 32-35
-    0x650A7354, 0x766A0ABB, 0x81C2C92E, 0x92722C85, // 36-39
-    0xA2BFE8A1, 0xA81A664B, 0xC24B8B70, 0xC76C51A3, // 40-43
-    0xD192E819, 0xD6990624, 0xF40E3585, 0x106AA070, // 44-47
-    0x19A4C116, 0x1E376C08, 0x2748774C, 0x34B0BCB5, // 48-51
-    0x391C0CB3, 0x4ED8AA4A, 0x5B9CCA4F, 0x682E6FF3, // 52-55
-    0x748F82EE, 0x78A5636F, 0x84C87814, 0x8CC70208, // 56-59
-    0x90BEFFFA, 0xA4506CEB, 0xBEF9A3F7, 0xC67178F2, // 60-63
-];
-
-#[cfg(feature = "qemu_vector")]
-#[inline(never)]
-fn asm_hash(a0: *mut usize, a1: *mut usize, a2: *mut usize) {
-    // Only unsafe as assembly code the assembly is an implementation of SHA256
-    // as per RISC-V Crypto
-
-    // Reference: RISC-V Cryptographic Extensions Vector Code Sample
-    // The code sample is part of the riscv-crypto project on GitHub
-    // commit 6589bcd6edb5abd91e758a67b28ae05b347c0470.
-    // See: https://github.com/riscv/riscv-crypto/blob/
-    //  6589bcd6edb5abd91e758a67b28ae05b347c0470/doc/vector/code-samples/zvknh.s
-
-    // ? Rust's RISC-V targets do not support vector operations nor vector
-    // ? cryptography operations as such the instructions are pre-assembled
-    // ? into their binary equivalents and hardcoded the comments represent
-    // ? the assembled instruction this solely bypasses the assembler and does
-    // ? not further negate security guarantees.
-
-    unsafe {
-        asm!(
-            /* ------------------------- Sanity ------------------------- */
-            "addi a3, a3, 1",
-
-            /* ------------------------- Setup -------------------------- */
-            // Set vector configuration
-            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
-
-            // Load 512 bits of the message block into v10-v13 endian swaping
-            ".word 0x0205e507", // vle32.v v10,(a1)
-            ".word 0x4aa4a557", // vrev8.v v10 v10
-            "add a1, a1, 16",
-            ".word 0x0205e587", // vle32.v v11,(a1)
-            ".word 0x4ab4a5d7", // vrev8.v v11 v11
-            "add a1, a1, 16",
-            ".word 0x0205e607", // vle32.v v12,(a1)
-            ".word 0x4ac4a657", // vrev8.v v12 v12
-            "add a1, a1, 16",
-            ".word 0x0205e687", // vle32.v v13,(a1)
-            ".word 0x4ad4a6d7", // vrev8.v v13 v13
-
-            /* ---------------------- Round loop ------------------------ */
-            // Load H[0..8]
-            // v26 = v16 = {a[t],b[t],e[t],f[t]}
-            // v27 = v17 = {c[t],d[t],g[t],h[t]}
-            ".word 0x02056807", // vle32.v v16,(a0)
-            "addi a0, a0, 16",
-            ".word 0x02056887", // vle32.v v17,(a0)
-
-            // Capture initial H to allow computing H'
-            ".word 0x5e080d57", // vmv.v.v v26,v16
-            ".word 0x5e088dd7", // vmv.v.v v27,v17
-
-            // Set v0 for vmerge that replaces first word
-            // v0.mask[i] = (i == 0 ? 1 : 0)
-            ".word 0x5208a057", // vid.v v0
-            ".word 0x62003057", // vmseq.vi v0,v0,0
-
-            /* ----------------------- Quad rounds ---------------------- */
-            // Round 0
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f50757", // vadd.vv v14,v15,v10
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
-            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
-
-            // Round 1
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f58757", // vadd.vv v14,v15,v11
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
-            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
-
-            // Round 2
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f60757", // vadd.vv v14,v15,v12
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
-            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
-
-            // Round 3
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f68757", // vadd.vv v14,v15,v13
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
-            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
-
-            // Round 4
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f50757", // vadd.vv v14,v15,v10
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
-            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
-
-            // Round 5
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f58757", // vadd.vv v14,v15,v11
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
-            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
-
-            // Round 6
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f60757", // vadd.vv v14,v15,v12
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
-            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
-
-            // Round 7
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f68757", // vadd.vv v14,v15,v13
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
-            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
-
-            // Round 8
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f50757", // vadd.vv v14,v15,v10
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
-            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
-
-            // Round 9
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f58757", // vadd.vv v14,v15,v11
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
-            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
-
-            // Round 10
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f60757", // vadd.vv v14,v15,v12
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
-            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
-
-            // Round 11
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f68757", // vadd.vv v14,v15,v13
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
-            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
-
-            // Round 12
-            // We no longer generate new message schedules
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f50757", // vadd.vv v14,v15,v10
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-
-            // Round 13
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f58757", // vadd.vv v14,v15,v11
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-
-            // Round 14
-            ".word 0x02066787", // vle32.v v15,(a2)
-            "addi a2, a2, 16",
-            ".word 0x02f60757", // vadd.vv v14,v15,v12
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-
-            // Round 15
-            // a2 increment not needed
-            ".word 0x02066787", // vle32.v v15,(a2)
-            ".word 0x02f68757", // vadd.vv v14,v15,v13
-            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
-            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
-
-            /* ----------------------- Update hash ---------------------- */
-            ".word 0x03a80857", // vadd.vv v16, v26, v16
-            ".word 0x03b888d7", // vadd.vv v17, v27, v17
-
-            /* ------------------------ Save hash ----------------------- */
-            ".word 0x020568a7", // vse32.v v17,(a0)
-            "addi a0, a0, -16",
-            ".word 0x02056827", // vse32.v v16,(a0)
-            in("a0") a0,
-            in("a1") a1,
-            in("a2") a2,
-        );
-    }
+fn sha256_region(address: usize, size: usize) -> [u8; 32] {
+    stream_region::<Sha256>(address, size).into()
 }
 
-#[cfg(feature = "qemu_vector")]
-fn hash_kernel() -> [u8; 32] {
-    let kernel_size = get_kernel_size();
+pub fn sha384_region(address: usize, size: usize) -> [u8; 48] {
+    stream_region::<Sha384>(address, size).into()
+}
 
-    println!(
-        "Kernel range: 0x{:X?} -> 0x{:X?}",
-        bsp::memory::map::kernel::KERNEL,
-        bsp::memory::map::kernel::KERNEL + kernel_size
-    );
+pub fn sha512_region(address: usize, size: usize) -> [u8; 64] {
+    stream_region::<Sha512>(address, size).into()
+}
 
-    // Initialise with SHA256 initial Hash
-    let mut result: [u32; 8] = [
-        0x9B05688C, // F
-        0x510E527F, // E
-        0xBB67AE85, // B
-        0x6A09E667, // A
-        0x5BE0CD19, // H
-        0x1F83D9AB, // G
-        0xA54FF53A, // D
-        0x3C6EF372, // C
-    ];
-
-    // Used for a sanity check
-    let mut loops = 0;
-
-    // ? Note this code cannot be used but is here for completeness
-    //      The U-boot which sits before QEMU to prevent the need to implement
-    //      a driver for tftp booting does not support vector operations and
-    //      as it hands operation to use in supervisor mode it is not possible
-    //      for us to enable this bit as such for development the require_rvv
-    //      check in QEMU is hardcode to return true.
-    // println!("Attempting enabling mstatus.vs");
-    // unsafe {
-    //     asm!(
-    //         "csrr t0, mstatus",
-    //         "ori t0, t0, 0x200",
-    //         "csrrw x0, mstatus, t0",
-    //         out("t0") _,
-    //     )
-    // }
-
-    let mut size_left = kernel_size;
-    while size_left >= 64 {
-        asm_hash(
-            result.as_mut_ptr() as *mut usize,
-            (bsp::memory::map::kernel::KERNEL + (loops * 64)) as *mut usize,
-            SHA256_ROUND_CONSTANTS.as_ptr() as *mut usize,
-        );
-        loops += 1;
-        size_left -= 64;
-    }
+pub fn sm3_region(address: usize, size: usize) -> [u8; 32] {
+    stream_region::<Sm3>(address, size).into()
+}
 
-    // Padding step
-    let unaligned_size = kernel_size % 64;
-    let mut final_bytes: [u8; 64] = [0; 64];
-    for i in 0..unaligned_size {
-        // We have to get the final bytes of the kernel so raw pointer read
-        // is unavoidable but follows the safety logic that this pointer is
-        // defined by the MMIO we are forced to trust it; however, the slice
-        // is immutable so we can only read it.
-        final_bytes[i] = unsafe {
-            core::ptr::read(
-                (bsp::memory::map::kernel::KERNEL
-                    + (64 * (kernel_size / 64))
-                    + i) as *mut u8,
-            )
-        };
-    }
-    final_bytes[unaligned_size] = 0x80;
-    let final_size: u64 = (kernel_size as u64) * 8;
-    let mut index = 56;
-    for byte in final_size.to_be_bytes() {
-        final_bytes[index] = byte;
-        index += 1;
-    }
+// --------------------------------------------------------------------------
+// Unified code
+// --------------------------------------------------------------------------
 
-    asm_hash(
-        result.as_mut_ptr() as *mut usize,
-        final_bytes.as_ptr() as *mut usize,
-        SHA256_ROUND_CONSTANTS.as_ptr() as *mut usize,
-    );
+/// Digest produced by any `HashAlgorithm`
+struct Hash {
+    bytes: [u8; MAX_DIGEST_LEN],
+    len: usize,
+}
 
-    result = result.map(|x: u32| x.swap_bytes());
-    result = [
-        result[3], result[2], result[7], result[6], result[1], result[0],
-        result[5], result[4],
-    ];
+impl Hash {
+    fn new(digest: &[u8]) -> Self {
+        let mut bytes = [0; MAX_DIGEST_LEN];
+        bytes[..digest.len()].copy_from_slice(digest);
+        Hash {
+            bytes,
+            len: digest.len(),
+        }
+    }
 
-    let mut byte_result: [u8; 32] = [0; 32];
-    for i in 0..result.len() {
-        byte_result[4 * i..][..4]
-            .copy_from_slice(&result[i].to_le_bytes());
+    fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
     }
-    byte_result
 }
 
-// --------------------------------------------------------------------------
-// Unified code
-// --------------------------------------------------------------------------
+/// Hashes `size` bytes at `address` with `hash_alg`, the SHA-2 and SM3
+/// digests use the `backend` selected at boot
+fn hash_region(hash_alg: HashAlgorithm, address: usize, size: usize) -> Hash {
+    match hash_alg {
+        HashAlgorithm::Sha256 => Hash::new(&sha256_region(address, size)),
+        HashAlgorithm::Sha384 => Hash::new(&sha384_region(address, size)),
+        HashAlgorithm::Sha512 => Hash::new(&sha512_region(address, size)),
+        HashAlgorithm::Sha3_256 => {
+            Hash::new(&stream_region::<Sha3_256>(address, size))
+        }
+        HashAlgorithm::Sm3 => Hash::new(&sm3_region(address, size)),
+    }
+}
 
 fn pretty_print_slice(bytes: &[u8]) {
     let mut counter = 0;
@@ -389,53 +354,518 @@ fn pretty_print_slice(bytes: &[u8]) {
     println!();
 }
 
-fn get_kernel_size() -> usize {
-    println!("Determining kernel size...");
+/// Parses and validates the RISC-V Linux `Image` header of the first `len`
+/// bytes at the kernel address
+fn load_image_header(len: usize) -> Result<ImageHeader, VerificationError> {
     // We have to form a data structure from the raw pointer as this pointer
     // is defined by the MMIO we are forced to trust it; however, the slice
     // is immutable so we can only read it.
     let data = unsafe {
         slice::from_raw_parts(
             (bsp::memory::map::kernel::KERNEL) as *mut u8,
-            bsp::memory::map::kernel::DTB - bsp::memory::map::kernel::KERNEL,
+            len,
         )
     };
-    let pe = pe64::PeFile::from_bytes(data).unwrap();
-    let kernel_size: usize = pe.optional_header().AddressOfEntryPoint as usize;
-    println!("Kernel size: 0x{:X?}", kernel_size);
-    kernel_size
+    let kernel = ImageHeader::parse(data)?;
+    kernel.validate(
+        bsp::memory::map::kernel::KERNEL,
+        bsp::memory::map::kernel::RAM,
+    )?;
+    if kernel.image_size
+        > bsp::memory::map::kernel::DTB - bsp::memory::map::kernel::KERNEL
+    {
+        return Err(VerificationError::PayloadOverlapsDtb(kernel.image_size));
+    }
+    Ok(kernel)
+}
+
+/// Checks the signed payload length, and the memory the kernel will occupy
+/// once running, both stay clear of the DTB
+fn get_kernel_size(payload_len: usize) -> Result<usize, VerificationError> {
+    println!("Determining kernel size...");
+    if payload_len
+        > bsp::memory::map::kernel::DTB - bsp::memory::map::kernel::KERNEL
+    {
+        return Err(VerificationError::PayloadOverlapsDtb(payload_len));
+    }
+
+    let kernel = load_image_header(payload_len)?;
+    println!("Loaded kernel image header:\n{}", kernel);
+    println!("Kernel size: 0x{:X?}", payload_len);
+    Ok(payload_len)
 }
 
-pub fn verify_kernel() -> Result<(), ed25519_compact::Error> {
-    println!("Hashing stored kernel...");
-    let hash = hash_kernel();
-    println!("Stored kernel hashed:");
+/// Re-validates the RISC-V Linux `Image` header immediately before hand off
+/// and returns the address to jump to
+pub fn kernel_entry() -> Result<usize, VerificationError> {
+    load_image_header(image::HEADER_LEN).inspect_err(|_| {
+        encryption::wipe();
+        encryption::wipe_key();
+    })?;
+    Ok(bsp::memory::map::kernel::KERNEL)
+}
+
+fn verify_component(
+    component: &Component,
+    hash_alg: HashAlgorithm,
+) -> Result<(), VerificationError> {
+    component.check_bounds()?;
+
+    println!("Hashing stored {:?} with {:?}...", component.kind, hash_alg);
+    println!("{}", component);
+    println!(
+        "Range: 0x{:X?} -> 0x{:X?}",
+        component.load_address,
+        component.load_address + component.length
+    );
+    let hash = hash_region(hash_alg, component.load_address, component.length);
+    println!("Stored {:?} hashed:", component.kind);
     pretty_print_slice(hash.as_slice());
 
-    // For the key access the libraries own error message is more useful
-    println!("Loading server public key...");
-    let public_key =
-        ed25519_compact::PublicKey::from_slice(crate::helper::PUBLIC_KEY)
-            .unwrap();
-    println!("Loaded server public key:");
-    pretty_print_slice(public_key.as_slice());
+    println!("Verifying stored {:?}...", component.kind);
+    if hash.as_slice() != component.digest {
+        return Err(VerificationError::ComponentHashMismatch(component.kind));
+    }
+    Ok(())
+}
+
+/// SHA-256 digest of `data` using the same backend as the payload
+#[cfg(feature = "lms")]
+fn sha256(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+/// Computes the fingerprint of a public key, its first 8 bytes are the
+/// identifier `tftp/hash.py` records
+fn fingerprint(public_key: &[u8]) -> [u8; FINGERPRINT_LEN] {
+    Sha256::digest(public_key).into()
+}
+
+#[cfg(feature = "ed25519")]
+fn verify_ed25519(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    let public_key = match ed25519_compact::PublicKey::from_slice(public_key) {
+        Ok(x) => x,
+        Err(_) => return false,
+    };
+    match ed25519_compact::Signature::from_slice(signature) {
+        Ok(x) => public_key.verify(message, &x).is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Checks `signature` over `message` was produced by `key` using `sig_alg`,
+/// the algorithm named by the signed data must be the one the key is for
+#[cfg_attr(
+    not(any(
+        feature = "ed25519",
+        feature = "p256",
+        feature = "rsa",
+        feature = "lms",
+        feature = "mldsa"
+    )),
+    allow(unused_variables)
+)]
+fn verify_signature(
+    key: &TrustedKey,
+    sig_alg: SignatureAlgorithm,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), VerificationError> {
+    if key.sig_alg != sig_alg {
+        return Err(VerificationError::KeyAlgorithm(key.id));
+    }
+    let failed = VerificationError::Signature;
+    if sig_alg.signature_len(key.key, signature) != Some(signature.len()) {
+        return Err(failed(sig_alg));
+    }
+    match sig_alg {
+        #[cfg(feature = "ed25519")]
+        SignatureAlgorithm::Ed25519 => {
+            if verify_ed25519(key.key, message, signature) {
+                Ok(())
+            } else {
+                Err(failed(sig_alg))
+            }
+        }
+        #[cfg(feature = "p256")]
+        SignatureAlgorithm::EcdsaP256Sha256 => {
+            use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+            let public_key = VerifyingKey::from_sec1_bytes(key.key)
+                .map_err(|_| failed(sig_alg))?;
+            let signature = Signature::from_slice(signature)
+                .map_err(|_| failed(sig_alg))?;
+            public_key
+                .verify(message, &signature)
+                .map_err(|_| failed(sig_alg))
+        }
+        #[cfg(feature = "rsa")]
+        SignatureAlgorithm::RsaPkcs1v15Sha256
+        | SignatureAlgorithm::RsaPssSha256 => {
+            pkcs1::verify(key, sig_alg, message, signature)
+        }
+        #[cfg(feature = "lms")]
+        SignatureAlgorithm::HssSha256 => {
+            if lms::verify(key.key, signature, message) {
+                Ok(())
+            } else {
+                Err(failed(sig_alg))
+            }
+        }
+        #[cfg(feature = "mldsa")]
+        SignatureAlgorithm::MlDsa65 => {
+            if mldsa::verify(key.key, signature, message) {
+                Ok(())
+            } else {
+                Err(failed(sig_alg))
+            }
+        }
+        // Each half is reported on its own so a failure names the algorithm
+        #[cfg(all(feature = "ed25519", feature = "mldsa"))]
+        SignatureAlgorithm::Ed25519MlDsa65 => {
+            let (ed25519_key, mldsa_key) =
+                key.key.split_at_checked(32).ok_or(failed(sig_alg))?;
+            let (ed25519_signature, mldsa_signature) = signature.split_at(64);
+            if !verify_ed25519(ed25519_key, message, ed25519_signature) {
+                return Err(failed(SignatureAlgorithm::Ed25519));
+            }
+            if !mldsa::verify(mldsa_key, mldsa_signature, message) {
+                return Err(failed(SignatureAlgorithm::MlDsa65));
+            }
+            Ok(())
+        }
+        // Only reachable when an algorithm's feature is disabled, in which
+        // case `SignatureAlgorithm::from_id` never produces it
+        #[allow(unreachable_patterns)]
+        _ => Err(failed(sig_alg)),
+    }
+}
+
+/// Loads and verifies the revocation update, refusing one older than the
+/// board has accepted before, or none at all once it has accepted one
+fn load_revocation_list(
+) -> Result<Option<RevocationList<'static>>, VerificationError> {
+    println!("Loading revocation update...");
+    // We have to form a data structure from the raw pointer as this pointer
+    // is defined by the MMIO we are forced to trust it; however, the slice
+    // is immutable so we can only read it.
+    let raw = unsafe {
+        slice::from_raw_parts(
+            (bsp::memory::map::kernel::REVOCATION) as *mut u8,
+            revocation::MAX_LEN,
+        )
+    };
+    let list = match RevocationList::parse(raw)? {
+        Some(x) => x,
+        None => {
+            rollback::check_revocation(0)?;
+            return Ok(None);
+        }
+    };
+    println!("Loaded revocation update:\n{}", list);
+
+    let signer = keyring::find(
+        crate::helper::KEYRING,
+        list.key_id,
+        KEY_USAGE_REVOCATION,
+    )?;
+    let signer_fingerprint = fingerprint(signer.key);
+    if crate::helper::REVOKED.contains(&signer_fingerprint)
+        || list.contains(&signer_fingerprint)
+    {
+        return Err(VerificationError::RevokedKey(signer.id));
+    }
+
+    verify_signature(
+        signer,
+        list.sig_alg,
+        list.signed_bytes(),
+        list.signature(signer.key)?,
+    )?;
+    println!("Revocation update signed by: {}", signer);
+    rollback::check_revocation(list.sequence)?;
+    rollback::raise_revocation(list.sequence)?;
+    Ok(Some(list))
+}
+
+/// Refuses keys listed in the embedded revocation list or the signed update
+fn check_revocation(
+    key: &TrustedKey,
+    update: Option<&RevocationList>,
+) -> Result<(), VerificationError> {
+    println!("Checking key revocation...");
+    let key_fingerprint = fingerprint(key.key);
+    // Guard against the keyring or a certificate disagreeing with its key
+    if key_fingerprint[..8] != key.id {
+        return Err(VerificationError::UnknownKey(key.id));
+    }
+
+    let revoked = crate::helper::REVOKED.contains(&key_fingerprint)
+        || update.is_some_and(|x| x.contains(&key_fingerprint));
+    if revoked {
+        println!("! -- Key {} has been revoked", key);
+        return Err(VerificationError::RevokedKey(key.id));
+    }
+    Ok(())
+}
+
+/// Finds the only certificate for `subject` shipped with the image
+fn find_certificate(
+    header: &Header<'static>,
+    subject: [u8; 8],
+) -> Result<Option<Certificate<'static>>, VerificationError> {
+    let mut found = None;
+    for (tag, value) in header.tlvs() {
+        if tag != TLV_CERTIFICATE {
+            continue;
+        }
+        let certificate = Certificate::parse(value)
+            .map_err(VerificationError::InvalidCertificate)?;
+        if certificate.subject_id != subject {
+            continue;
+        }
+        if found.is_some() {
+            return Err(VerificationError::Certificate(
+                certificate.issuer_id,
+                subject,
+                "subject certified more than once",
+            ));
+        }
+        found = Some(certificate);
+    }
+    Ok(found)
+}
+
+/// Finds the key which signed the image, either directly in the keyring or
+/// at the end of a certificate chain leading back to a keyring key
+fn resolve_signer(
+    header: &Header<'static>,
+    revocations: Option<&RevocationList>,
+) -> Result<TrustedKey, VerificationError> {
+    let keyring = crate::helper::KEYRING;
+    if keyring.iter().any(|x| x.id == header.key_id) {
+        let key = keyring::find(keyring, header.key_id, KEY_USAGE_IMAGE)?;
+        check_revocation(key, revocations)?;
+        return Ok(*key);
+    }
+
+    // Collect the chain from the image's signer up to a keyring key
+    println!("Signing key not in keyring, walking certificate chain...");
+    let mut chain: [Option<Certificate>; MAX_CHAIN_LEN] = [None; MAX_CHAIN_LEN];
+    let mut subject = header.key_id;
+    for depth in 0..=MAX_CHAIN_LEN {
+        let certificate = match (find_certificate(header, subject)?, depth) {
+            (Some(x), _) => x,
+            (None, 0) => return Err(VerificationError::UnknownKey(subject)),
+            (None, _) => {
+                return Err(VerificationError::Certificate(
+                    subject,
+                    chain[depth - 1].map_or(subject, |x| x.subject_id),
+                    "issuer is neither trusted nor certified",
+                ))
+            }
+        };
+        if depth == MAX_CHAIN_LEN {
+            return Err(VerificationError::Certificate(
+                certificate.issuer_id,
+                certificate.subject_id,
+                "certificate chain too long",
+            ));
+        }
+        chain[depth] = Some(certificate);
+        if keyring.iter().any(|x| x.id == certificate.issuer_id) {
+            break;
+        }
+        subject = certificate.issuer_id;
+    }
 
-    println!("Loading kernel signature...");
+    // Verify each link from the keyring key down to the image's signer
+    let mut issuer: Option<TrustedKey> = None;
+    let links = chain.iter().flatten().count();
+    for (i, certificate) in chain.iter().flatten().rev().enumerate() {
+        let link = |reason| {
+            VerificationError::Certificate(
+                certificate.issuer_id,
+                certificate.subject_id,
+                reason,
+            )
+        };
+        let signer = match issuer {
+            Some(x) => x,
+            None => {
+                let root = keyring::find(
+                    keyring,
+                    certificate.issuer_id,
+                    KEY_USAGE_CERTIFICATE,
+                )
+                .map_err(|_| link("issuer may not sign certificates"))?;
+                check_revocation(root, revocations)?;
+                *root
+            }
+        };
+        if signer.usage & KEY_USAGE_CERTIFICATE == 0 {
+            return Err(link("issuer may not sign certificates"));
+        }
+        if signer.sig_alg != certificate.sig_alg {
+            return Err(link("issuer does not sign with this algorithm"));
+        }
+        verify_signature(
+            &signer,
+            certificate.sig_alg,
+            certificate.signed_bytes(),
+            certificate.signature(),
+        )
+        .map_err(|_| link("signature does not verify"))?;
+
+        // Only the last certificate may certify the key signing the image
+        let required = if i + 1 == links {
+            KEY_USAGE_IMAGE
+        } else {
+            KEY_USAGE_CERTIFICATE
+        };
+        if certificate.usage & required != required {
+            return Err(link("usage does not permit the key's role"));
+        }
+
+        let subject = TrustedKey {
+            name: "certified",
+            id: certificate.subject_id,
+            usage: certificate.usage,
+            sig_alg: certificate.key_alg,
+            key: certificate.subject_key(),
+        };
+        check_revocation(&subject, revocations)?;
+        println!("Certificate verified:\n{}", certificate);
+        issuer = Some(subject);
+    }
+    // The walk above only ends with at least one certificate
+    issuer.ok_or(VerificationError::UnknownKey(header.key_id))
+}
+
+fn load_header() -> Result<Header<'static>, VerificationError> {
+    println!("Loading image header...");
     // We have to form a data structure from the raw pointer as this pointer
     // is defined by the MMIO we are forced to trust it; however, the slice
     // is immutable so we can only read it.
-    let signature_bytes = unsafe {
+    let raw = unsafe {
         slice::from_raw_parts(
             (bsp::memory::map::kernel::SIGNATURE) as *mut u8,
-            64,
+            bsp::memory::map::kernel::KERNEL
+                - bsp::memory::map::kernel::SIGNATURE,
         )
     };
+    let header = Header::parse(raw)?;
+    println!("Loaded image header:\n{}", header);
+    Ok(header)
+}
+
+/// Checks the signed security version against the stored anti-rollback floor
+fn check_security_version(header: &Header) -> Result<u32, VerificationError> {
+    let version = header.security_version()?;
+    println!("Security version: {}", version);
+    match rollback::load()? {
+        Some(x) => {
+            println!("Minimum security version: {}", x.security_version);
+            if x.pending != 0 {
+                println!("Unconfirmed last boot: version {}", x.pending);
+            }
+        }
+        None => println!("No persistent storage, rollback is not prevented"),
+    }
+    rollback::check(version)?;
+    Ok(version)
+}
+
+/// Records the security version of the image being booted as pending, only
+/// to be called once every check has passed. The floor rises to it once the
+/// booted OS confirms it came up.
+pub fn stage_security_version(version: u32) -> Result<(), VerificationError> {
+    rollback::stage(version)
+}
+
+/// Wipes the key material embedded in the loader, only to be called once
+/// nothing is left to decrypt
+pub fn wipe_keys() {
+    encryption::wipe_key();
+}
+
+/// Verifies the signed image and every component it describes, decrypting
+/// the kernel if it is encrypted, and returns the image's security version
+pub fn verify_kernel() -> Result<u32, VerificationError> {
+    // Neither the plaintext nor the key is left if the image is rejected
+    verify_image().inspect_err(|_| {
+        encryption::wipe();
+        encryption::wipe_key();
+    })
+}
+
+fn verify_image() -> Result<u32, VerificationError> {
+    let header = load_header()?;
+    // Parsed and verified once, every key on the way to the signer is
+    // checked against it
+    let revocations = load_revocation_list()?;
+
+    println!("Selecting signing key...");
+    let trusted = resolve_signer(&header, revocations.as_ref())?;
+
+    println!("Loaded public key:");
+    pretty_print_slice(trusted.key);
+    let signature = header.signature(trusted.key)?;
+    println!("Loaded header signature:");
+    pretty_print_slice(signature);
+
+    println!("Verifying image header...");
+    verify_signature(
+        &trusted,
+        header.sig_alg,
+        header.signed_bytes(),
+        signature,
+    )?;
+    println!("Accepted key: {}", trusted);
+
+    let security_version = check_security_version(&header)?;
+
+    let manifest = Manifest::parse(&header)?;
+    if manifest.kernel.length != header.payload_len
+        || manifest.kernel.digest != header.payload_hash()
+    {
+        return Err(VerificationError::ManifestPayloadMismatch);
+    }
+
+    manifest.kernel.check_bounds()?;
+    let encryption = header
+        .find_tlv(TLV_ENCRYPTION)?
+        .map(Encryption::parse)
+        .transpose()?;
+    let compression = header
+        .find_tlv(TLV_COMPRESSION)?
+        .map(Compression::parse)
+        .transpose()?;
+
+    for component in [manifest.kernel, manifest.dtb, manifest.ramfs] {
+        verify_component(&component, header.hash_alg)?;
+    }
+
+    // The signed digest is of the ciphertext, the kernel is only readable now
+    if let Some(x) = encryption {
+        println!("Decrypting kernel:\n{}", x);
+        x.decrypt(manifest.kernel.length)?;
+    }
+    // and of the compressed stream, which is only expanded once readable
+    let mut kernel_len = manifest.kernel.length;
+    if let Some(x) = compression {
+        println!("Decompressing kernel:\n{}", x);
+        encryption::grow(x.length);
+        x.decompress(kernel_len)?;
+        kernel_len = x.length;
+    }
+    get_kernel_size(kernel_len)?;
 
-    let signature =
-        ed25519_compact::Signature::from_slice(signature_bytes).unwrap();
-    println!("Loaded kernel signature:");
-    pretty_print_slice(signature.as_slice());
+    println!("Measuring verified image...");
+    let mut log = measurement::EventLog::new(header.hash_alg);
+    log.measure_boot(&trusted, &manifest);
+    measurement::store(log);
 
-    println!("Verifying stored kernel...");
-    public_key.verify(hash.as_slice(), &signature)
+    println!("Deriving device identity...");
+    dice::derive(header.hash_alg, manifest.kernel.digest, security_version)?;
+    Ok(security_version)
 }
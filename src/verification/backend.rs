@@ -0,0 +1,262 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! SHA-2, SM3 and AES-GCM implementations chosen at boot.
+//!
+//! `select` picks the fastest implementation the harts' extensions allow and
+//! `Sha256`, `Sha384`, `Sha512` and `Sm3` forward to it, so the same image
+//! hashes with the vector unit where there is one, with the scalar crypto
+//! instructions failing that and with the `sha2` and `sm3` crates elsewhere.
+//! `aes_gcm_decrypt` likewise decrypts with Zvkned and Zvkg or the `aes-gcm`
+//! crate. Until `select` runs everything uses those crates.
+
+use crate::cpu::isa::Extensions;
+use core::fmt;
+use sha2::digest::{
+    typenum::{U32, U48, U64},
+    FixedOutput,
+    HashMarker,
+    Output,
+    OutputSizeUser,
+    Update,
+};
+use synchronisation::{interface::Mutex, NullLock};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Implementation of a digest or cipher
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Backend {
+    /// `sha2`, `sm3` or `aes-gcm` crate
+    Software,
+    /// Scalar cryptography, see `scalar`
+    Scalar,
+    /// Vector cryptography, see `vector`
+    Vector,
+}
+
+/// Implementations of each digest and cipher
+#[derive(Clone, Copy, Debug)]
+pub struct Backends {
+    /// SHA-256
+    pub sha256: Backend,
+    /// SHA-384 and SHA-512
+    pub sha512: Backend,
+    /// SM3
+    pub sm3: Backend,
+    /// AES-128-GCM and AES-256-GCM
+    pub aes_gcm: Backend,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+static BACKENDS: NullLock<Backends> = NullLock::new(Backends {
+    sha256: Backend::Software,
+    sha512: Backend::Software,
+    sm3: Backend::Software,
+    aes_gcm: Backend::Software,
+});
+
+/// Hasher forwarding to the `Backends` field `$field` as selected when it
+/// was created, `$software` is the crate's hasher and `$scalar` names the
+/// `scalar` one if there is one
+macro_rules! dispatch {
+    (
+        $name:ident,
+        $software:ty,
+        $field:ident,
+        $size:ty,
+        $doc:literal
+        $(, $scalar:ident)?
+    ) => {
+        #[doc = $doc]
+        #[derive(Clone)]
+        pub enum $name {
+            /// Software implementation
+            Software($software),
+            $(
+            /// Scalar cryptography
+            #[cfg(target_arch = "riscv64")]
+            $scalar(super::scalar::$name),
+            )?
+            /// Vector cryptography
+            #[cfg(target_arch = "riscv64")]
+            Vector(super::vector::$name),
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                match BACKENDS.lock(|x| x.$field) {
+                    $(
+                    #[cfg(target_arch = "riscv64")]
+                    Backend::$scalar => $name::$scalar(Default::default()),
+                    )?
+                    #[cfg(target_arch = "riscv64")]
+                    Backend::Vector => $name::Vector(Default::default()),
+                    _ => $name::Software(Default::default()),
+                }
+            }
+        }
+
+        impl HashMarker for $name {}
+
+        impl OutputSizeUser for $name {
+            type OutputSize = $size;
+        }
+
+        impl Update for $name {
+            fn update(&mut self, data: &[u8]) {
+                match self {
+                    $name::Software(x) => x.update(data),
+                    $(
+                    #[cfg(target_arch = "riscv64")]
+                    $name::$scalar(x) => x.update(data),
+                    )?
+                    #[cfg(target_arch = "riscv64")]
+                    $name::Vector(x) => x.update(data),
+                }
+            }
+        }
+
+        impl FixedOutput for $name {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                match self {
+                    $name::Software(x) => x.finalize_into(out),
+                    $(
+                    #[cfg(target_arch = "riscv64")]
+                    $name::$scalar(x) => x.finalize_into(out),
+                    )?
+                    #[cfg(target_arch = "riscv64")]
+                    $name::Vector(x) => x.finalize_into(out),
+                }
+            }
+        }
+    };
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+dispatch!(
+    Sha256,
+    sha2::Sha256,
+    sha256,
+    U32,
+    "SHA-256 using the selected backend",
+    Scalar
+);
+dispatch!(
+    Sha384,
+    sha2::Sha384,
+    sha512,
+    U48,
+    "SHA-384 using the selected backend"
+);
+dispatch!(
+    Sha512,
+    sha2::Sha512,
+    sha512,
+    U64,
+    "SHA-512 using the selected backend"
+);
+dispatch!(Sm3, sm3::Sm3, sm3, U32, "SM3 using the selected backend");
+
+/// Chooses the backend of each digest and cipher from the harts' `extensions`
+pub fn select(extensions: Extensions) -> Backends {
+    let mut backends = Backends {
+        sha256: Backend::Software,
+        sha512: Backend::Software,
+        sm3: Backend::Software,
+        aes_gcm: Backend::Software,
+    };
+    #[cfg(target_arch = "riscv64")]
+    if extensions.zknh {
+        backends.sha256 = Backend::Scalar;
+    }
+    #[cfg(target_arch = "riscv64")]
+    if extensions.v {
+        if extensions.zvknha || extensions.zvknhb {
+            backends.sha256 = Backend::Vector;
+        }
+        let egw256 = super::vector::egw256_supported();
+        if extensions.zvknhb && egw256 {
+            backends.sha512 = Backend::Vector;
+        }
+        if extensions.zvksh && egw256 {
+            backends.sm3 = Backend::Vector;
+        }
+        #[cfg(feature = "aes-gcm")]
+        if extensions.zvkned && extensions.zvkg {
+            backends.aes_gcm = Backend::Vector;
+        }
+    }
+    #[cfg(not(target_arch = "riscv64"))]
+    let _ = extensions;
+    BACKENDS.lock(|x| *x = backends);
+    backends
+}
+
+/// Backends chosen by the last `select`
+pub fn backends() -> Backends {
+    BACKENDS.lock(|x| *x)
+}
+
+/// Checks `tag` over `aad` and the ciphertext in `buffer` with AES-GCM using
+/// the selected backend, then decrypts `buffer` in place. AES-128 or AES-256
+/// is chosen by the length of `key`, any other length fails, and `buffer` is
+/// left as it was whenever this returns `false`.
+#[cfg(feature = "aes-gcm")]
+pub fn aes_gcm_decrypt(
+    key: &[u8],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    buffer: &mut [u8],
+    tag: &[u8; 16],
+) -> bool {
+    use aes_gcm::{
+        aead::{AeadInPlace, KeyInit},
+        Aes128Gcm,
+        Aes256Gcm,
+    };
+    #[cfg(target_arch = "riscv64")]
+    if BACKENDS.lock(|x| x.aes_gcm) == Backend::Vector {
+        return super::vector::AesGcm::new(key).is_some_and(|x| {
+            x.decrypt_in_place_detached(nonce, aad, buffer, tag)
+        });
+    }
+    match key.len() {
+        16 => Aes128Gcm::new_from_slice(key).is_ok_and(|x| {
+            x.decrypt_in_place_detached(nonce.into(), aad, buffer, tag.into())
+                .is_ok()
+        }),
+        32 => Aes256Gcm::new_from_slice(key).is_ok_and(|x| {
+            x.decrypt_in_place_detached(nonce.into(), aad, buffer, tag.into())
+                .is_ok()
+        }),
+        _ => false,
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Backend::Software => write!(f, "software"),
+            Backend::Scalar => write!(f, "scalar"),
+            Backend::Vector => write!(f, "vector"),
+        }
+    }
+}
+
+impl fmt::Display for Backends {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SHA-256 {}, SHA-384/512 {}, SM3 {}, AES-GCM {}",
+            self.sha256, self.sha512, self.sm3, self.aes_gcm
+        )
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Key certificates.
+//!
+//! A certificate lets a key in the keyring, typically an offline root, vouch
+//! for another key without that key being compiled into SentinelBoot. Each is
+//! a `header::TLV_CERTIFICATE` record produced by `tftp/certify.py`:
+//!
+//! | Offset | Size | Field        |
+//! |--------|------|--------------|
+//! | 0x00   | 8    | `magic`      |
+//! | 0x08   | 2    | `version`    |
+//! | 0x0A   | 1    | `sig_alg`    |
+//! | 0x0B   | 1    | `key_alg`    |
+//! | 0x0C   | 4    | `usage`      |
+//! | 0x10   | 8    | `issuer_id`  |
+//! | 0x18   | 8    | `subject_id` |
+//! | 0x20   | n    | subject key  |
+//!
+//! `sig_alg` is the issuer's algorithm and `key_alg` the subject's, `usage`
+//! holds the `keyring::KEY_USAGE_*` flags the subject may be used for. The
+//! subject key's length follows from `key_alg`. The issuer's signature covers
+//! every preceding byte and fills the rest of the certificate.
+
+use super::header::{read_u16, read_u32, SignatureAlgorithm};
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Identifies a SentinelBoot key certificate
+pub const MAGIC: [u8; 8] = *b"SNTLCERT";
+/// Only certificate format understood by this loader
+pub const VERSION: u16 = 1;
+/// Size of the fixed portion of a certificate
+pub const FIXED_LEN: usize = 0x20;
+/// Most certificates walked between the image and a keyring key
+pub const MAX_CHAIN_LEN: usize = 3;
+
+/// Parsed view of a key certificate
+#[derive(Clone, Copy)]
+pub struct Certificate<'a> {
+    raw: &'a [u8],
+    /// Algorithm the issuer signed with
+    pub sig_alg: SignatureAlgorithm,
+    /// Algorithm of the certified key
+    pub key_alg: SignatureAlgorithm,
+    key_len: usize,
+    /// `KEY_USAGE_*` flags granted to the certified key
+    pub usage: u32,
+    /// Identifier of the key which signed the certificate
+    pub issuer_id: [u8; 8],
+    /// Identifier of the certified key
+    pub subject_id: [u8; 8],
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl<'a> Certificate<'a> {
+    /// Parses a certificate which must fill `raw`, failures are reported with
+    /// the link they occur in by the caller
+    pub fn parse(raw: &'a [u8]) -> Result<Self, &'static str> {
+        if raw.len() < FIXED_LEN || raw[0x00..0x08] != MAGIC {
+            return Err("not a certificate");
+        }
+        if read_u16(raw, 0x08) != VERSION {
+            return Err("unsupported certificate version");
+        }
+        let sig_alg = SignatureAlgorithm::from_id(raw[0x0A])
+            .map_err(|_| "unsupported signature algorithm")?;
+        let key_alg = SignatureAlgorithm::from_id(raw[0x0B])
+            .map_err(|_| "unsupported key algorithm")?;
+        // The signature takes the rest, its length is checked against the
+        // issuer's key when verifying
+        let key_len = key_alg
+            .public_key_len(&raw[FIXED_LEN..])
+            .ok_or("subject key malformed")?;
+
+        let mut issuer_id = [0; 8];
+        issuer_id.copy_from_slice(&raw[0x10..0x18]);
+        let mut subject_id = [0; 8];
+        subject_id.copy_from_slice(&raw[0x18..0x20]);
+        Ok(Certificate {
+            raw,
+            sig_alg,
+            key_alg,
+            key_len,
+            usage: read_u32(raw, 0x0C),
+            issuer_id,
+            subject_id,
+        })
+    }
+
+    /// Raw public key being certified
+    pub fn subject_key(&self) -> &'a [u8] {
+        &self.raw[FIXED_LEN..FIXED_LEN + self.key_len]
+    }
+
+    /// Bytes covered by the signature
+    pub fn signed_bytes(&self) -> &'a [u8] {
+        &self.raw[..FIXED_LEN + self.key_len]
+    }
+
+    /// Issuer's signature over `signed_bytes`
+    pub fn signature(&self) -> &'a [u8] {
+        &self.raw[FIXED_LEN + self.key_len..]
+    }
+}
+
+impl fmt::Display for Certificate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\tISSUER: {:02X?}\n\tSUBJECT: {:02X?}\n\tUSAGE: 0x{:X}",
+            self.issuer_id, self.subject_id, self.usage
+        )
+    }
+}
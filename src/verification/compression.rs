@@ -0,0 +1,437 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Compressed kernel payloads.
+//!
+//! A kernel compressed by `tftp/hash.py --compress` is described by a
+//! `header::TLV_COMPRESSION` record in the signed header:
+//!
+//! | Offset | Size | Field      |
+//! |--------|------|------------|
+//! | 0x00   | 1    | `format`   |
+//! | 0x01   | 7    | reserved   |
+//! | 0x08   | 8    | `length`   |
+//!
+//! The payload and its manifest digest are then those of the compressed
+//! stream, so nothing is decompressed before the signature and digest check,
+//! and an encrypted kernel is decrypted before it is decompressed. The stream
+//! is moved to the top of the kernel's space, below `DTB`, and `length`
+//! bytes are decompressed beneath it at `KERNEL`, so the decompressor's
+//! output can neither overrun the stream it is reading nor reach the DTB.
+//!
+//! gzip and LZ4 use the kernel already written as their window and need no
+//! memory of their own. zstd keeps its window on the heap, which holds far
+//! less than a kernel, so frames asking for more than `ZSTD_WINDOW_MAX` are
+//! refused; `hash.py` compresses with that window. `ZSTD_HEAP_MAX` bounds what
+//! the decoder then allocates and is exported to the linker scripts, which
+//! fail the link if the heap left after the loader cannot hold it.
+
+use super::VerificationError;
+use core::{fmt, ptr, slice};
+use zeroize::Zeroize;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Size of the compression record
+pub const RECORD_LEN: usize = 0x10;
+
+/// Compression format identifiers, those whose feature is disabled are never
+/// constructed
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+pub enum Format {
+    /// gzip, RFC 1952, a deflate stream with a header and trailer
+    Gzip,
+    /// LZ4 frame format
+    Lz4,
+    /// Zstandard, RFC 8878
+    Zstd,
+}
+
+/// Parsed compression record
+#[cfg_attr(
+    not(any(feature = "gzip", feature = "lz4", feature = "zstd")),
+    allow(dead_code)
+)]
+pub struct Compression {
+    /// Format the kernel was compressed with
+    pub format: Format,
+    /// Length of the decompressed kernel
+    pub length: usize,
+}
+
+/// Heap the zstd decoder needs for a frame within `ZSTD_WINDOW_MAX`, whose
+/// blocks RFC 8878 then limits to a window. Its ring buffer holds the window
+/// and a block, a block's content, literals and sequences of 12 bytes per
+/// three output bytes are held in vectors that may double as they grow, and
+/// its entropy tables are fixed in size
+#[cfg(feature = "zstd")]
+pub const ZSTD_HEAP_MAX: usize = 18 * ZSTD_WINDOW_MAX as usize + 0x4000;
+
+// Checked against the heap by the linker scripts
+#[cfg(feature = "zstd")]
+core::arch::global_asm!(
+    ".globl _zstd_heap_max",
+    ".set _zstd_heap_max, {}",
+    const ZSTD_HEAP_MAX
+);
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Largest zstd window decompressed, `hash.py` compresses with `wlog=13`
+#[cfg(feature = "zstd")]
+const ZSTD_WINDOW_MAX: u64 = 1 << 13;
+
+/// gzip header flags, RFC 1952 section 2.3.1
+#[cfg(feature = "gzip")]
+const GZIP_FHCRC: u8 = 0x02;
+#[cfg(feature = "gzip")]
+const GZIP_FEXTRA: u8 = 0x04;
+#[cfg(feature = "gzip")]
+const GZIP_FNAME: u8 = 0x08;
+#[cfg(feature = "gzip")]
+const GZIP_FCOMMENT: u8 = 0x10;
+/// Size of the fixed gzip header and of the CRC-32 and length trailer
+#[cfg(feature = "gzip")]
+const GZIP_HEADER_LEN: usize = 10;
+#[cfg(feature = "gzip")]
+const GZIP_TRAILER_LEN: usize = 8;
+
+/// LZ4 frame magic number
+#[cfg(feature = "lz4")]
+const LZ4_MAGIC: u32 = 0x184D_2204;
+/// LZ4 frame descriptor flags
+#[cfg(feature = "lz4")]
+const LZ4_DICT_ID: u8 = 0x01;
+#[cfg(feature = "lz4")]
+const LZ4_CONTENT_CHECKSUM: u8 = 0x04;
+#[cfg(feature = "lz4")]
+const LZ4_CONTENT_SIZE: u8 = 0x08;
+#[cfg(feature = "lz4")]
+const LZ4_BLOCK_CHECKSUM: u8 = 0x10;
+/// Block size bit marking a block stored uncompressed
+#[cfg(feature = "lz4")]
+const LZ4_UNCOMPRESSED: u32 = 0x8000_0000;
+/// Distance LZ4 matches may reach back into earlier blocks
+#[cfg(feature = "lz4")]
+const LZ4_WINDOW: usize = 0x1_0000;
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+/// Splits `len` bytes off the front of `input`
+#[cfg(any(feature = "gzip", feature = "lz4"))]
+fn take<'a>(
+    input: &mut &'a [u8],
+    len: usize,
+) -> Result<&'a [u8], VerificationError> {
+    if input.len() < len {
+        return Err(VerificationError::Decompression("truncated stream"));
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+/// Length of the gzip header at the start of `input`
+#[cfg(feature = "gzip")]
+fn gzip_header_len(mut input: &[u8]) -> Result<usize, VerificationError> {
+    let start = input.len();
+    let fixed = take(&mut input, GZIP_HEADER_LEN)?;
+    // Deflate is the only compression method and the top flags are reserved
+    if fixed[..3] != [0x1F, 0x8B, 0x08] || fixed[3] & 0xE0 != 0 {
+        return Err(VerificationError::Decompression("not a gzip stream"));
+    }
+    let flags = fixed[3];
+    if flags & GZIP_FEXTRA != 0 {
+        let len = take(&mut input, 2)?;
+        take(&mut input, u16::from_le_bytes([len[0], len[1]]) as usize)?;
+    }
+    for flag in [GZIP_FNAME, GZIP_FCOMMENT] {
+        if flags & flag != 0 {
+            let end = input
+                .iter()
+                .position(|x| *x == 0)
+                .ok_or(VerificationError::Decompression("truncated stream"))?;
+            take(&mut input, end + 1)?;
+        }
+    }
+    if flags & GZIP_FHCRC != 0 {
+        take(&mut input, 2)?;
+    }
+    Ok(start - input.len())
+}
+
+/// Inflates the gzip stream `input` into `output`
+#[cfg(feature = "gzip")]
+pub fn gunzip(
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, VerificationError> {
+    use alloc::boxed::Box;
+    use miniz_oxide::inflate::{
+        core::{
+            decompress,
+            inflate_flags::TINFL_FLAG_USING_NON_WRAPPING_OUTPUT_BUF,
+            DecompressorOxide,
+        },
+        TINFLStatus,
+    };
+
+    let start = gzip_header_len(input)?;
+    // Some 10 KiB of Huffman tables, too much for the stack
+    let mut inflater = Box::<DecompressorOxide>::default();
+    let (status, read, written) = decompress(
+        &mut inflater,
+        &input[start..],
+        output,
+        0,
+        TINFL_FLAG_USING_NON_WRAPPING_OUTPUT_BUF,
+    );
+    match status {
+        TINFLStatus::Done => (),
+        TINFLStatus::HasMoreOutput => {
+            return Err(VerificationError::Decompression(
+                "longer than its record",
+            ))
+        }
+        _ => {
+            return Err(VerificationError::Decompression("corrupt gzip stream"))
+        }
+    }
+
+    // The signature already covers the stream, so the CRC-32 adds nothing,
+    // but the length must agree and nothing may follow
+    let trailer = &input[start + read..];
+    if trailer.len() != GZIP_TRAILER_LEN
+        || trailer[4..] != (written as u32).to_le_bytes()
+    {
+        return Err(VerificationError::Decompression("corrupt gzip trailer"));
+    }
+    Ok(written)
+}
+
+/// Decompresses the LZ4 frame `input` into `output`
+#[cfg(feature = "lz4")]
+pub fn unlz4(
+    mut input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, VerificationError> {
+    use lz4_flex::block::decompress_into_with_dict;
+
+    const CORRUPT: VerificationError =
+        VerificationError::Decompression("corrupt LZ4 frame");
+    let magic = take(&mut input, 4)?;
+    if u32::from_le_bytes([magic[0], magic[1], magic[2], magic[3]]) != LZ4_MAGIC
+    {
+        return Err(VerificationError::Decompression("not an LZ4 frame"));
+    }
+    let descriptor = take(&mut input, 2)?;
+    let (flags, block_size) = (descriptor[0], descriptor[1]);
+    // Version 1, with the reserved bits clear and a defined block size
+    if flags >> 6 != 1 || flags & 0x02 != 0 || block_size & 0x8F != 0 {
+        return Err(CORRUPT);
+    }
+    if flags & LZ4_DICT_ID != 0 {
+        return Err(VerificationError::Decompression("LZ4 dictionary"));
+    }
+    let block_max = match block_size >> 4 {
+        x @ 4..=7 => 1 << (2 * x + 8),
+        _ => return Err(CORRUPT),
+    };
+    if flags & LZ4_CONTENT_SIZE != 0 {
+        let size = take(&mut input, 8)?;
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(size);
+        if u64::from_le_bytes(bytes) != output.len() as u64 {
+            return Err(VerificationError::Decompression(
+                "length does not match its record",
+            ));
+        }
+    }
+    // Descriptor checksum, the signature already covers it
+    take(&mut input, 1)?;
+
+    let mut written = 0;
+    loop {
+        let size = take(&mut input, 4)?;
+        let size = u32::from_le_bytes([size[0], size[1], size[2], size[3]]);
+        if size == 0 {
+            break;
+        }
+        let len = (size & !LZ4_UNCOMPRESSED) as usize;
+        if len > block_max {
+            return Err(CORRUPT);
+        }
+        let block = take(&mut input, len)?;
+        let (done, rest) = output.split_at_mut(written);
+        let room = rest.len().min(block_max);
+        if size & LZ4_UNCOMPRESSED != 0 {
+            if len > room {
+                return Err(VerificationError::Decompression(
+                    "longer than its record",
+                ));
+            }
+            rest[..len].copy_from_slice(block);
+            written += len;
+        } else {
+            // Linked blocks refer back into those before them
+            let dictionary = &done[done.len().saturating_sub(LZ4_WINDOW)..];
+            written +=
+                decompress_into_with_dict(block, &mut rest[..room], dictionary)
+                    .map_err(|_| CORRUPT)?;
+        }
+        if flags & LZ4_BLOCK_CHECKSUM != 0 {
+            take(&mut input, 4)?;
+        }
+    }
+    if flags & LZ4_CONTENT_CHECKSUM != 0 {
+        take(&mut input, 4)?;
+    }
+    if !input.is_empty() {
+        return Err(CORRUPT);
+    }
+    Ok(written)
+}
+
+/// Decompresses the single zstd frame `input` into `output`
+#[cfg(feature = "zstd")]
+pub fn unzstd(
+    mut input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, VerificationError> {
+    use ruzstd::{
+        frame::read_frame_header,
+        io::Read,
+        BlockDecodingStrategy,
+        FrameDecoder,
+    };
+
+    const CORRUPT: VerificationError =
+        VerificationError::Decompression("corrupt zstd frame");
+    // Checked before the decoder allocates the window
+    let (frame, _) = read_frame_header(input).map_err(|_| CORRUPT)?;
+    match frame.header.window_size() {
+        Ok(x) if x <= ZSTD_WINDOW_MAX => (),
+        Ok(_) => {
+            return Err(VerificationError::Decompression(
+                "zstd window too large",
+            ))
+        }
+        Err(_) => return Err(CORRUPT),
+    }
+
+    let mut decoder = FrameDecoder::new();
+    decoder.init(&mut input).map_err(|_| CORRUPT)?;
+    let mut written = 0;
+    while !decoder.is_finished() {
+        decoder
+            .decode_blocks(&mut input, BlockDecodingStrategy::UptoBlocks(1))
+            .map_err(|_| CORRUPT)?;
+        written += decoder.read(&mut output[written..]).map_err(|_| CORRUPT)?;
+        if decoder.can_collect() != 0 {
+            return Err(VerificationError::Decompression(
+                "longer than its record",
+            ));
+        }
+    }
+    if !input.is_empty() {
+        return Err(CORRUPT);
+    }
+    Ok(written)
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl Format {
+    /// Maps the on-disk identifier to a format, formats whose cargo feature
+    /// is disabled are treated as unknown
+    pub fn from_id(id: u8) -> Result<Self, VerificationError> {
+        match id {
+            #[cfg(feature = "gzip")]
+            1 => Ok(Format::Gzip),
+            #[cfg(feature = "lz4")]
+            2 => Ok(Format::Lz4),
+            #[cfg(feature = "zstd")]
+            3 => Ok(Format::Zstd),
+            _ => Err(VerificationError::UnsupportedCompression(id)),
+        }
+    }
+}
+
+impl Compression {
+    /// Parses the value of a `header::TLV_COMPRESSION` record
+    pub fn parse(value: &[u8]) -> Result<Self, VerificationError> {
+        if value.len() != RECORD_LEN {
+            return Err(VerificationError::Truncated);
+        }
+        let mut length = [0; 8];
+        length.copy_from_slice(&value[0x08..0x10]);
+        Ok(Compression {
+            format: Format::from_id(value[0x00])?,
+            length: u64::from_le_bytes(length) as usize,
+        })
+    }
+
+    /// Decompresses the `len` byte kernel into its place, only to be called
+    /// once it has verified and been decrypted
+    #[cfg_attr(
+        not(any(feature = "gzip", feature = "lz4", feature = "zstd")),
+        allow(unused_variables)
+    )]
+    pub fn decompress(&self, len: usize) -> Result<(), VerificationError> {
+        use bsp::memory::map::kernel::{DTB, KERNEL};
+
+        // The manifest keeps the stream below the DTB, the kernel must fit
+        // beneath where it is moved to
+        let input = DTB - len;
+        if self.length > input - KERNEL {
+            return Err(VerificationError::PayloadOverlapsDtb(self.length));
+        }
+        // Both ranges lie between KERNEL and DTB, `ptr::copy` allows overlap
+        let (input, output) = unsafe {
+            ptr::copy(KERNEL as *const u8, input as *mut u8, len);
+            (
+                slice::from_raw_parts_mut(input as *mut u8, len),
+                slice::from_raw_parts_mut(KERNEL as *mut u8, self.length),
+            )
+        };
+
+        let written: Result<usize, VerificationError> = match self.format {
+            #[cfg(feature = "gzip")]
+            Format::Gzip => gunzip(input, output),
+            #[cfg(feature = "lz4")]
+            Format::Lz4 => unlz4(input, output),
+            #[cfg(feature = "zstd")]
+            Format::Zstd => unzstd(input, output),
+            // Only reachable when a format's feature is disabled, in which
+            // case `Format::from_id` never produces it
+            #[allow(unreachable_patterns)]
+            _ => Err(VerificationError::UnsupportedCompression(0)),
+        };
+        // The stream of an encrypted kernel is plaintext too
+        input.zeroize();
+        if written? != self.length {
+            return Err(VerificationError::Decompression(
+                "length does not match its record",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\tFORMAT: {:?}\n\tLENGTH: 0x{:X}",
+            self.format, self.length
+        )
+    }
+}
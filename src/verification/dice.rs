@@ -0,0 +1,275 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! DICE layered device identity.
+//!
+//! SentinelBoot is the DICE layer holding the unique device secret (UDS),
+//! which is provisioned once, e.g. with `tftp/uds.py`, in the storage the BSP
+//! registers with `driver::register_device_secret`, such as OTP, as:
+//!
+//! | Offset | Size | Field    |
+//! |--------|------|----------|
+//! | 0x00   | 8    | `magic`  |
+//! | 0x08   | 32   | UDS      |
+//!
+//! `lock` hides the storage before the kernel runs, so storage which cannot
+//! be hidden is never registered. The kernel can always read QEMU's pflash,
+//! so QEMU has no UDS and boots without attestation or kernels encrypted to
+//! the device.
+//!
+//! The UDS is only in memory while keys are derived from it. It derives the
+//! layer key, an Ed25519 key identifying the device whichever kernel it
+//! boots, and together with the verified kernel's digest the Compound Device
+//! Identifier (CDI) handed to the kernel. The CDI derives the kernel's Ed25519
+//! attestation key, which the layer key certifies:
+//!
+//! | Offset | Size | Field              |
+//! |--------|------|--------------------|
+//! | 0x00   | 8    | `magic`            |
+//! | 0x08   | 2    | `version`          |
+//! | 0x0A   | 1    | `sig_alg`          |
+//! | 0x0B   | 1    | `hash_alg`         |
+//! | 0x0C   | 4    | `security_version` |
+//! | 0x10   | 8    | `issuer_id`        |
+//! | 0x18   | 8    | `subject_id`       |
+//! | 0x20   | 32   | layer key          |
+//! | 0x40   | 32   | attestation key    |
+//! | 0x60   | n    | kernel digest      |
+//!
+//! The layer key's signature covers every preceding byte and ends the
+//! certificate. Key identifiers are those of `keyring`, the first 8 bytes of
+//! the key's SHA-256. Once the kernel is verified the handover record is
+//! written to `bsp::memory::map::kernel::DICE`:
+//!
+//! | Offset | Size | Field             |
+//! |--------|------|-------------------|
+//! | 0x00   | 8    | `magic`           |
+//! | 0x08   | 2    | `version`         |
+//! | 0x0A   | 2    | `certificate_len` |
+//! | 0x0C   | 4    | reserved          |
+//! | 0x10   | 32   | CDI               |
+//! | 0x30   | n    | certificate       |
+//!
+//! `publish` describes the region to Linux as a `google,open-dice` reserved
+//! memory node. Every derivation is HKDF-SHA512 as in Open DICE, the CDI
+//! salted with the kernel digest and each key seed with `KEY_SALT`, as is the
+//! key of kernels `encryption` decrypts for this device alone.
+
+use super::{
+    fingerprint,
+    header::{HashAlgorithm, SignatureAlgorithm},
+    VerificationError,
+};
+use crate::fdt::{self, Reservation};
+use alloc::vec::Vec;
+use console::println;
+use core::slice;
+use ed25519_compact::{KeyPair, Seed};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use synchronisation::{interface::Mutex, NullLock};
+use zeroize::{zeroize_flat_type, Zeroize, Zeroizing};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Identifies a provisioned UDS
+pub const UDS_MAGIC: [u8; 8] = *b"SNTLUDS\0";
+/// Identifies a DICE handover record
+pub const HANDOVER_MAGIC: [u8; 8] = *b"SNTLDICE";
+/// Identifies an attestation certificate
+pub const CERTIFICATE_MAGIC: [u8; 8] = *b"SNTLATST";
+/// Only handover and certificate format written by this loader
+pub const VERSION: u16 = 1;
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Length of the UDS, the CDI and each key seed
+const SECRET_LEN: usize = 32;
+/// Size of the provisioned UDS record
+const UDS_RECORD_LEN: usize = 0x08 + SECRET_LEN;
+/// Size of the fixed portion of the handover record
+const HANDOVER_FIXED_LEN: usize = 0x30;
+
+const CDI_INFO: &[u8] = b"CDI_Attest";
+const KEY_SALT: &[u8] = b"SentinelBoot DICE";
+const KEY_INFO: &[u8] = b"Key Pair";
+const PAYLOAD_KEY_INFO: &[u8] = b"Payload Key";
+
+/// Whether the handover record has been written for `publish`
+static HANDOVER: NullLock<bool> = NullLock::new(false);
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+/// HMAC-SHA512 of the concatenated `data` under `key`
+fn hmac(key: &[u8], data: &[&[u8]]) -> Zeroizing<[u8; 64]> {
+    // HMAC takes keys of any length
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+    for x in data {
+        mac.update(x);
+    }
+    let mut out = Zeroizing::new([0; 64]);
+    out.copy_from_slice(&mac.finalize_reset().into_bytes());
+    // The keyed state is left on the stack otherwise, it is plain arrays and
+    // counters for which all zero is valid
+    unsafe { zeroize_flat_type(&mut mac) };
+    out
+}
+
+/// HKDF-SHA512 of `ikm`, RFC 5869
+fn kdf(ikm: &[u8], salt: &[u8], info: &[u8]) -> Zeroizing<[u8; SECRET_LEN]> {
+    let prk = hmac(salt, &[ikm]);
+    // One block of output is more than enough
+    let okm = hmac(&*prk, &[info, &[1]]);
+    let mut secret = Zeroizing::new([0; SECRET_LEN]);
+    secret.copy_from_slice(&okm[..SECRET_LEN]);
+    secret
+}
+
+/// Ed25519 key pair seeded from `secret`, whose secret key the caller must
+/// zeroize
+fn key_pair(secret: &[u8]) -> KeyPair {
+    let mut seed = Seed::new(*kdf(secret, KEY_SALT, KEY_INFO));
+    let key_pair = KeyPair::from_seed(seed);
+    seed.zeroize();
+    key_pair
+}
+
+fn key_id(public_key: &[u8]) -> [u8; 8] {
+    let mut id = [0; 8];
+    id.copy_from_slice(&fingerprint(public_key)[..8]);
+    id
+}
+
+/// The DICE region, which nothing else is loaded into
+fn region() -> &'static mut [u8] {
+    use bsp::memory::map::kernel::{DICE, DICE_LEN};
+    // The region is defined by the BSP memory map and lies between the DTB
+    // limit and the event log
+    unsafe { slice::from_raw_parts_mut(DICE as *mut u8, DICE_LEN) }
+}
+
+/// Reads the provisioned UDS, `None` if the board has no device secret
+/// storage or it was never provisioned
+fn read_uds() -> Result<Option<Zeroizing<[u8; SECRET_LEN]>>, VerificationError>
+{
+    let storage = match driver::device_secret() {
+        Some(x) => x,
+        None => return Ok(None),
+    };
+    let mut record = Zeroizing::new([0; UDS_RECORD_LEN]);
+    storage
+        .read(&mut *record)
+        .map_err(VerificationError::Dice)?;
+    if record[0x00..0x08] != UDS_MAGIC {
+        return Ok(None);
+    }
+    let mut uds = Zeroizing::new([0; SECRET_LEN]);
+    uds.copy_from_slice(&record[0x08..]);
+    Ok(Some(uds))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Key for kernels encrypted to this device, `None` without a provisioned
+/// UDS
+pub fn payload_key(
+) -> Result<Option<Zeroizing<[u8; SECRET_LEN]>>, VerificationError> {
+    Ok(read_uds()?.map(|x| kdf(&*x, KEY_SALT, PAYLOAD_KEY_INFO)))
+}
+
+/// Derives the CDI for the kernel with `kernel_digest`, certifies its
+/// attestation key and writes the handover record, only to be called once
+/// the kernel is verified
+pub fn derive(
+    hash_alg: HashAlgorithm,
+    kernel_digest: &[u8],
+    security_version: u32,
+) -> Result<(), VerificationError> {
+    let uds = match read_uds()? {
+        Some(x) => x,
+        None => {
+            println!("No device secret, attestation is not available");
+            return Ok(());
+        }
+    };
+    let mut layer = key_pair(&*uds);
+    let cdi = kdf(&*uds, kernel_digest, CDI_INFO);
+    drop(uds);
+    // Only its public key is certified, the kernel derives the rest from
+    // the CDI
+    let mut attestation = key_pair(&*cdi);
+    attestation.sk.zeroize();
+
+    let layer_id = key_id(&*layer.pk);
+    let attestation_id = key_id(&*attestation.pk);
+    let mut certificate = Vec::new();
+    certificate.extend_from_slice(&CERTIFICATE_MAGIC);
+    certificate.extend_from_slice(&VERSION.to_le_bytes());
+    certificate.push(SignatureAlgorithm::Ed25519.id());
+    certificate.push(hash_alg.id());
+    certificate.extend_from_slice(&security_version.to_le_bytes());
+    certificate.extend_from_slice(&layer_id);
+    certificate.extend_from_slice(&attestation_id);
+    certificate.extend_from_slice(&*layer.pk);
+    certificate.extend_from_slice(&*attestation.pk);
+    certificate.extend_from_slice(kernel_digest);
+    let signature = layer.sk.sign(&certificate, None);
+    layer.sk.zeroize();
+    certificate.extend_from_slice(&*signature);
+
+    let region = region();
+    if HANDOVER_FIXED_LEN + certificate.len() > region.len() {
+        return Err(VerificationError::Dice("handover does not fit"));
+    }
+    region[0x00..0x08].copy_from_slice(&HANDOVER_MAGIC);
+    region[0x08..0x0A].copy_from_slice(&VERSION.to_le_bytes());
+    region[0x0A..0x0C]
+        .copy_from_slice(&(certificate.len() as u16).to_le_bytes());
+    region[0x0C..0x10].fill(0);
+    region[0x10..0x30].copy_from_slice(&*cdi);
+    region[HANDOVER_FIXED_LEN..HANDOVER_FIXED_LEN + certificate.len()]
+        .copy_from_slice(&certificate);
+    HANDOVER.lock(|x| *x = true);
+
+    println!("Device ID: {:02X?}", layer_id);
+    println!("Attestation key: {:02X?}", attestation_id);
+    Ok(())
+}
+
+/// Hands the DICE region to the kernel through the DTB, wiping it instead
+/// if that fails so the CDI is not left in memory the kernel may reuse
+pub fn publish() -> Result<(), VerificationError> {
+    use bsp::memory::map::kernel::{DICE, DICE_LEN, DTB};
+    if !HANDOVER.lock(|x| *x) {
+        return Ok(());
+    }
+    let reservation = Reservation {
+        name: "dice",
+        compatible: Some("google,open-dice"),
+        address: DICE,
+        len: DICE_LEN,
+    };
+    fdt::rewrite(DTB, DICE - DTB, |tree| {
+        tree.with_reservation(&reservation, &[])
+    })
+    .map_err(|x| {
+        region().zeroize();
+        VerificationError::Dice(x)
+    })
+}
+
+/// Hides the UDS until the next reset, only to be called once nothing is
+/// left to derive from it
+pub fn lock() -> Result<(), VerificationError> {
+    match driver::device_secret() {
+        Some(x) => x.lock().map_err(VerificationError::Dice),
+        None => Ok(()),
+    }
+}
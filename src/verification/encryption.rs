@@ -0,0 +1,262 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Encrypted kernel payloads.
+//!
+//! A kernel encrypted by `tftp/hash.py --encrypt` is described by a
+//! `header::TLV_ENCRYPTION` record in the signed header:
+//!
+//! | Offset | Size | Field        |
+//! |--------|------|--------------|
+//! | 0x00   | 1    | `cipher`     |
+//! | 0x01   | 1    | `key_source` |
+//! | 0x02   | 2    | reserved     |
+//! | 0x04   | 12   | `nonce`      |
+//! | 0x10   | 16   | `tag`        |
+//!
+//! The payload and its manifest digest are then those of the ciphertext, so
+//! the signature is checked before anything is decrypted. The kernel is
+//! decrypted in place at `bsp::memory::map::kernel::KERNEL` with no associated
+//! data, the tag is checked before any plaintext is written, and `wipe` clears
+//! the plaintext, decompressed or not, on any later failure. The key is either
+//! embedded at build time by `gen_helper.py`, writable so `wipe_key` can clear
+//! it before the kernel runs, or derived from the device secret by `dice`, and
+//! AES-128-GCM uses its first half. AES-GCM runs on the `backend` selected at
+//! boot.
+
+#[cfg(feature = "aes-gcm")]
+use super::backend;
+use super::{dice, VerificationError};
+use core::{fmt, slice};
+use synchronisation::{interface::Mutex, NullLock};
+use zeroize::{Zeroize, Zeroizing};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Size of the encryption record
+pub const RECORD_LEN: usize = 0x20;
+/// Length of the key, AES-128-GCM only uses the first 16 bytes
+pub const KEY_LEN: usize = 32;
+/// Length of every cipher's nonce
+pub const NONCE_LEN: usize = 12;
+/// Length of every cipher's authentication tag
+pub const TAG_LEN: usize = 16;
+
+/// AEAD cipher identifiers, those whose feature is disabled are never
+/// constructed
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+pub enum Cipher {
+    /// ChaCha20-Poly1305, RFC 8439
+    ChaCha20Poly1305,
+    /// AES-256-GCM, NIST SP 800-38D
+    Aes256Gcm,
+    /// AES-128-GCM, NIST SP 800-38D
+    Aes128Gcm,
+}
+
+/// Where the kernel's key comes from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KeySource {
+    /// `helper::PAYLOAD_KEY`, shared by every device running this build
+    Build,
+    /// Derived from the device secret, the kernel boots on that device alone
+    Device,
+}
+
+/// Parsed encryption record
+#[cfg_attr(
+    not(any(feature = "chacha20poly1305", feature = "aes-gcm")),
+    allow(dead_code)
+)]
+pub struct Encryption {
+    /// Cipher the kernel was encrypted with
+    pub cipher: Cipher,
+    /// Where the key comes from
+    pub key_source: KeySource,
+    /// Nonce the kernel was encrypted with
+    pub nonce: [u8; NONCE_LEN],
+    /// Authentication tag over the ciphertext
+    pub tag: [u8; TAG_LEN],
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Length of plaintext at the kernel address for `wipe`
+static DECRYPTED: NullLock<usize> = NullLock::new(0);
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+/// The first `len` bytes at the kernel address
+fn kernel(len: usize) -> &'static mut [u8] {
+    // The length is bounded by the manifest's check against the DTB
+    unsafe {
+        slice::from_raw_parts_mut(
+            bsp::memory::map::kernel::KERNEL as *mut u8,
+            len,
+        )
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl Cipher {
+    /// Maps the on-disk identifier to a cipher, ciphers whose cargo feature
+    /// is disabled are treated as unknown
+    pub fn from_id(id: u8) -> Result<Self, VerificationError> {
+        match id {
+            #[cfg(feature = "chacha20poly1305")]
+            1 => Ok(Cipher::ChaCha20Poly1305),
+            #[cfg(feature = "aes-gcm")]
+            2 => Ok(Cipher::Aes256Gcm),
+            #[cfg(feature = "aes-gcm")]
+            3 => Ok(Cipher::Aes128Gcm),
+            _ => Err(VerificationError::UnsupportedCipher(id)),
+        }
+    }
+}
+
+impl KeySource {
+    /// Maps the on-disk identifier to a key source
+    pub fn from_id(id: u8) -> Result<Self, VerificationError> {
+        match id {
+            1 => Ok(KeySource::Build),
+            2 => Ok(KeySource::Device),
+            _ => Err(VerificationError::Decryption("unknown key source")),
+        }
+    }
+}
+
+impl Encryption {
+    /// Parses the value of a `header::TLV_ENCRYPTION` record
+    pub fn parse(value: &[u8]) -> Result<Self, VerificationError> {
+        if value.len() != RECORD_LEN {
+            return Err(VerificationError::Truncated);
+        }
+        let mut encryption = Encryption {
+            cipher: Cipher::from_id(value[0x00])?,
+            key_source: KeySource::from_id(value[0x01])?,
+            nonce: [0; NONCE_LEN],
+            tag: [0; TAG_LEN],
+        };
+        encryption.nonce.copy_from_slice(&value[0x04..0x10]);
+        encryption.tag.copy_from_slice(&value[0x10..0x20]);
+        Ok(encryption)
+    }
+
+    /// Copy of the key, wiped when dropped
+    fn key(&self) -> Result<Zeroizing<[u8; KEY_LEN]>, VerificationError> {
+        match self.key_source {
+            // Copied straight into the wiped buffer, never through a
+            // temporary
+            KeySource::Build => crate::helper::PAYLOAD_KEY
+                .lock(|x| {
+                    x.as_ref().map(|key| {
+                        let mut copy = Zeroizing::new([0; KEY_LEN]);
+                        copy.copy_from_slice(key);
+                        copy
+                    })
+                })
+                .ok_or(VerificationError::Decryption("no key in this build")),
+            KeySource::Device => dice::payload_key()?
+                .ok_or(VerificationError::Decryption("no device secret")),
+        }
+    }
+
+    /// Authenticates and decrypts the `len` byte kernel in place, only to be
+    /// called once the record's signature and the ciphertext's digest verify
+    #[cfg_attr(
+        not(any(feature = "chacha20poly1305", feature = "aes-gcm")),
+        allow(unused_variables)
+    )]
+    pub fn decrypt(&self, len: usize) -> Result<(), VerificationError> {
+        let key = self.key()?;
+        let kernel = kernel(len);
+        DECRYPTED.lock(|x| *x = len);
+        let authentic = match self.cipher {
+            #[cfg(feature = "chacha20poly1305")]
+            Cipher::ChaCha20Poly1305 => {
+                use chacha20poly1305::{
+                    aead::{AeadInPlace, KeyInit},
+                    ChaCha20Poly1305,
+                };
+                ChaCha20Poly1305::new((&*key).into())
+                    .decrypt_in_place_detached(
+                        &self.nonce.into(),
+                        &[],
+                        kernel,
+                        &self.tag.into(),
+                    )
+                    .is_ok()
+            }
+            #[cfg(feature = "aes-gcm")]
+            Cipher::Aes256Gcm => backend::aes_gcm_decrypt(
+                &key[..],
+                &self.nonce,
+                &[],
+                kernel,
+                &self.tag,
+            ),
+            #[cfg(feature = "aes-gcm")]
+            Cipher::Aes128Gcm => backend::aes_gcm_decrypt(
+                &key[..16],
+                &self.nonce,
+                &[],
+                kernel,
+                &self.tag,
+            ),
+            // Only reachable when a cipher's feature is disabled, in which
+            // case `Cipher::from_id` never produces it
+            #[allow(unreachable_patterns)]
+            _ => false,
+        };
+        if !authentic {
+            return Err(VerificationError::Decryption(
+                "ciphertext failed authentication",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Extends the plaintext `wipe` clears to the first `len` bytes at the
+/// kernel address, for a decrypted kernel about to be decompressed there
+pub fn grow(len: usize) {
+    DECRYPTED.lock(|x| {
+        if *x > 0 {
+            *x = (*x).max(len);
+        }
+    });
+}
+
+/// Wipes the build key, called before the kernel runs or once it is rejected
+/// so it cannot be read back out of the loader's memory
+pub fn wipe_key() {
+    crate::helper::PAYLOAD_KEY.lock(|x| x.zeroize());
+}
+
+/// Wipes the decrypted kernel, called on any failure once decryption has
+/// begun so the plaintext is not left in memory
+pub fn wipe() {
+    let len = DECRYPTED.lock(core::mem::take);
+    if len > 0 {
+        kernel(len).zeroize();
+    }
+}
+
+impl fmt::Display for Encryption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\tCIPHER: {:?}\n\tKEY: {:?}\n\tNONCE: {:02X?}",
+            self.cipher, self.key_source, self.nonce
+        )
+    }
+}
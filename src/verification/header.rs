@@ -0,0 +1,417 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Signed image header.
+//!
+//! The header sits at `bsp::memory::map::kernel::SIGNATURE` directly in front
+//! of the kernel and is produced by `tftp/hash.py`. All multi-byte fields are
+//! little endian.
+//!
+//! | Offset | Size | Field          |
+//! |--------|------|----------------|
+//! | 0x00   | 8    | `magic`        |
+//! | 0x08   | 2    | `version`      |
+//! | 0x0A   | 1    | `sig_alg`      |
+//! | 0x0B   | 1    | `hash_alg`     |
+//! | 0x0C   | 4    | `header_len`   |
+//! | 0x10   | 4    | `signed_len`   |
+//! | 0x14   | 4    | reserved       |
+//! | 0x18   | 8    | `key_id`       |
+//! | 0x20   | 8    | `payload_len`  |
+//! | 0x28   | 64   | `payload_hash` |
+//!
+//! The fixed fields are followed by protected TLV records, each a `u16` tag,
+//! a `u16` value length and the value, filling the header up to `signed_len`.
+//! The signature covers bytes `0..signed_len` and immediately follows them.
+
+#[cfg(feature = "lms")]
+use super::lms;
+use super::{pkcs1, VerificationError};
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Identifies a SentinelBoot signed image
+pub const MAGIC: [u8; 8] = *b"SNTLBOOT";
+/// Only header format understood by this loader
+pub const VERSION: u16 = 1;
+/// Size of the fixed portion of the header
+pub const FIXED_LEN: usize = 0x68;
+/// Size of a TLV record's tag and length
+pub const TLV_HEADER_LEN: usize = 4;
+/// Space for a digest in `payload_hash` and manifest components
+pub const MAX_DIGEST_LEN: usize = 64;
+
+/// TLV tag of a manifest component, see `manifest`
+pub const TLV_COMPONENT: u16 = 0x0001;
+/// TLV tag of the `u32` anti-rollback security version, see `rollback`
+pub const TLV_SECURITY_VERSION: u16 = 0x0002;
+/// TLV tag of a key certificate, see `certificate`
+pub const TLV_CERTIFICATE: u16 = 0x0003;
+/// TLV tag of the kernel encryption record, see `encryption`
+pub const TLV_ENCRYPTION: u16 = 0x0004;
+/// TLV tag of the kernel compression record, see `compression`
+pub const TLV_COMPRESSION: u16 = 0x0005;
+
+/// Signature algorithm identifiers, those whose feature is disabled are never
+/// constructed
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[allow(dead_code)]
+pub enum SignatureAlgorithm {
+    /// Ed25519 over the signed header bytes
+    Ed25519,
+    /// ECDSA over NIST P-256 of the SHA-256 digest of the signed header
+    /// bytes, with a SEC1 uncompressed public key and an `r || s` signature
+    EcdsaP256Sha256,
+    /// RSA PKCS#1 v1.5 over the SHA-256 digest, see `pkcs1`
+    RsaPkcs1v15Sha256,
+    /// RSA-PSS over the SHA-256 digest with MGF1 and a digest sized salt
+    RsaPssSha256,
+    /// HSS/LMS hash-based signature with SHA-256, see `lms`
+    HssSha256,
+    /// ML-DSA-65 with an empty context, see `mldsa`
+    MlDsa65,
+    /// Ed25519 and ML-DSA-65 over the same bytes, the keys and signatures
+    /// are concatenated in that order and both must verify
+    Ed25519MlDsa65,
+}
+
+/// Payload digest algorithm identifiers, the same algorithm hashes every
+/// manifest component
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HashAlgorithm {
+    /// SHA-256
+    Sha256,
+    /// SHA-384
+    Sha384,
+    /// SHA-512
+    Sha512,
+    /// SHA3-256
+    Sha3_256,
+    /// SM3, GB/T 32905-2016
+    Sm3,
+}
+
+/// Parsed view of the signed image header
+pub struct Header<'a> {
+    raw: &'a [u8],
+    /// Header format version
+    pub version: u16,
+    /// Algorithm the signature was produced with
+    pub sig_alg: SignatureAlgorithm,
+    /// Algorithm the payload was hashed with
+    pub hash_alg: HashAlgorithm,
+    /// Total header length, the payload starts this many bytes after the
+    /// header
+    pub header_len: usize,
+    /// Number of header bytes covered by the signature
+    pub signed_len: usize,
+    /// Identifier of the key which produced the signature
+    pub key_id: [u8; 8],
+    /// Length of the payload in bytes
+    pub payload_len: usize,
+}
+
+/// Iterator over the protected TLV records as `(tag, value)`
+pub struct Tlvs<'a> {
+    raw: &'a [u8],
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Reads a little endian `u16` at `offset`
+pub fn read_u16(raw: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([raw[offset], raw[offset + 1]])
+}
+
+/// Reads a little endian `u32` at `offset`
+pub fn read_u32(raw: &[u8], offset: usize) -> u32 {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&raw[offset..offset + 4]);
+    u32::from_le_bytes(bytes)
+}
+
+/// Reads a little endian `u64` at `offset`
+pub fn read_u64(raw: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&raw[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl SignatureAlgorithm {
+    /// Maps the on-disk identifier to an algorithm, algorithms whose cargo
+    /// feature is disabled are treated as unknown
+    pub fn from_id(id: u8) -> Result<Self, VerificationError> {
+        match id {
+            #[cfg(feature = "ed25519")]
+            1 => Ok(SignatureAlgorithm::Ed25519),
+            #[cfg(feature = "p256")]
+            2 => Ok(SignatureAlgorithm::EcdsaP256Sha256),
+            #[cfg(feature = "rsa")]
+            3 => Ok(SignatureAlgorithm::RsaPkcs1v15Sha256),
+            #[cfg(feature = "rsa")]
+            4 => Ok(SignatureAlgorithm::RsaPssSha256),
+            #[cfg(feature = "lms")]
+            5 => Ok(SignatureAlgorithm::HssSha256),
+            #[cfg(feature = "mldsa")]
+            6 => Ok(SignatureAlgorithm::MlDsa65),
+            #[cfg(all(feature = "ed25519", feature = "mldsa"))]
+            7 => Ok(SignatureAlgorithm::Ed25519MlDsa65),
+            _ => Err(VerificationError::UnsupportedSignatureAlgorithm(id)),
+        }
+    }
+
+    /// On-disk identifier of the algorithm
+    pub fn id(&self) -> u8 {
+        match self {
+            SignatureAlgorithm::Ed25519 => 1,
+            SignatureAlgorithm::EcdsaP256Sha256 => 2,
+            SignatureAlgorithm::RsaPkcs1v15Sha256 => 3,
+            SignatureAlgorithm::RsaPssSha256 => 4,
+            SignatureAlgorithm::HssSha256 => 5,
+            SignatureAlgorithm::MlDsa65 => 6,
+            SignatureAlgorithm::Ed25519MlDsa65 => 7,
+        }
+    }
+
+    /// Length of the signature produced by the algorithm with `public_key`
+    /// at the start of `raw`, HSS signatures describe their own length
+    #[cfg_attr(not(feature = "lms"), allow(unused_variables))]
+    pub fn signature_len(
+        &self,
+        public_key: &[u8],
+        raw: &[u8],
+    ) -> Option<usize> {
+        match self {
+            SignatureAlgorithm::Ed25519 => Some(64),
+            SignatureAlgorithm::EcdsaP256Sha256 => Some(64),
+            SignatureAlgorithm::RsaPkcs1v15Sha256
+            | SignatureAlgorithm::RsaPssSha256 => {
+                pkcs1::modulus_len(public_key)
+            }
+            #[cfg(feature = "lms")]
+            SignatureAlgorithm::HssSha256 => lms::signature_len(raw),
+            #[cfg(not(feature = "lms"))]
+            SignatureAlgorithm::HssSha256 => None,
+            SignatureAlgorithm::MlDsa65 => Some(3309),
+            SignatureAlgorithm::Ed25519MlDsa65 => Some(64 + 3309),
+        }
+    }
+
+    /// Length of the public key for the algorithm at the start of `raw`
+    pub fn public_key_len(&self, raw: &[u8]) -> Option<usize> {
+        let len = match self {
+            SignatureAlgorithm::Ed25519 => 32,
+            SignatureAlgorithm::EcdsaP256Sha256 => 65,
+            SignatureAlgorithm::RsaPkcs1v15Sha256
+            | SignatureAlgorithm::RsaPssSha256 => return pkcs1::key_len(raw),
+            SignatureAlgorithm::HssSha256 => 60,
+            SignatureAlgorithm::MlDsa65 => 1952,
+            SignatureAlgorithm::Ed25519MlDsa65 => 32 + 1952,
+        };
+        (len <= raw.len()).then_some(len)
+    }
+}
+
+impl HashAlgorithm {
+    /// Maps the on-disk identifier to an algorithm
+    pub fn from_id(id: u8) -> Result<Self, VerificationError> {
+        match id {
+            1 => Ok(HashAlgorithm::Sha256),
+            2 => Ok(HashAlgorithm::Sha384),
+            3 => Ok(HashAlgorithm::Sha512),
+            4 => Ok(HashAlgorithm::Sha3_256),
+            5 => Ok(HashAlgorithm::Sm3),
+            _ => Err(VerificationError::UnsupportedHashAlgorithm(id)),
+        }
+    }
+
+    /// On-disk identifier of the algorithm
+    pub fn id(&self) -> u8 {
+        match self {
+            HashAlgorithm::Sha256 => 1,
+            HashAlgorithm::Sha384 => 2,
+            HashAlgorithm::Sha512 => 3,
+            HashAlgorithm::Sha3_256 => 4,
+            HashAlgorithm::Sm3 => 5,
+        }
+    }
+
+    /// Length of a digest produced by the algorithm
+    pub fn digest_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Sha256 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 => 64,
+            HashAlgorithm::Sha3_256 => 32,
+            HashAlgorithm::Sm3 => 32,
+        }
+    }
+
+    /// TPM 2.0 algorithm identifier, `TPM_ALG_ID`, naming the digest in the
+    /// measured boot event log
+    pub fn tpm_alg_id(&self) -> u16 {
+        match self {
+            HashAlgorithm::Sha256 => 0x000B,
+            HashAlgorithm::Sha384 => 0x000C,
+            HashAlgorithm::Sha512 => 0x000D,
+            HashAlgorithm::Sha3_256 => 0x0027,
+            HashAlgorithm::Sm3 => 0x0012,
+        }
+    }
+}
+
+impl<'a> Header<'a> {
+    /// Parses and sanity checks the header held in `raw`, which must span
+    /// exactly from the header start to the payload start
+    pub fn parse(raw: &'a [u8]) -> Result<Self, VerificationError> {
+        if raw.len() < FIXED_LEN {
+            return Err(VerificationError::Truncated);
+        }
+        if raw[0..8] != MAGIC {
+            return Err(VerificationError::BadMagic);
+        }
+
+        let version = read_u16(raw, 0x08);
+        if version != VERSION {
+            return Err(VerificationError::UnsupportedVersion(version));
+        }
+
+        let sig_alg = SignatureAlgorithm::from_id(raw[0x0A])?;
+        let hash_alg = HashAlgorithm::from_id(raw[0x0B])?;
+
+        // The payload has to start exactly where the BSP expects the kernel
+        let header_len = read_u32(raw, 0x0C) as usize;
+        if header_len != raw.len() {
+            return Err(VerificationError::HeaderLength(header_len));
+        }
+
+        // The signature length depends on the key so is checked once the key
+        // is known, see `signature`
+        let signed_len = read_u32(raw, 0x10) as usize;
+        if signed_len < FIXED_LEN || signed_len > header_len {
+            return Err(VerificationError::Truncated);
+        }
+
+        // Walk the TLV records once so later lookups can trust the lengths
+        let mut offset = FIXED_LEN;
+        while offset < signed_len {
+            if offset + TLV_HEADER_LEN > signed_len {
+                return Err(VerificationError::Truncated);
+            }
+            offset += TLV_HEADER_LEN + read_u16(raw, offset + 2) as usize;
+        }
+        if offset != signed_len {
+            return Err(VerificationError::Truncated);
+        }
+
+        let mut key_id = [0; 8];
+        key_id.copy_from_slice(&raw[0x18..0x20]);
+
+        let payload_len = read_u64(raw, 0x20) as usize;
+
+        Ok(Header {
+            raw,
+            version,
+            sig_alg,
+            hash_alg,
+            header_len,
+            signed_len,
+            key_id,
+            payload_len,
+        })
+    }
+
+    /// Expected payload digest
+    pub fn payload_hash(&self) -> &'a [u8] {
+        &self.raw[0x28..0x28 + self.hash_alg.digest_len()]
+    }
+
+    /// Protected TLV records
+    pub fn tlvs(&self) -> Tlvs<'a> {
+        Tlvs {
+            raw: &self.raw[FIXED_LEN..self.signed_len],
+        }
+    }
+
+    /// Value of the only TLV record with `tag`, if present
+    pub fn find_tlv(
+        &self,
+        tag: u16,
+    ) -> Result<Option<&'a [u8]>, VerificationError> {
+        let mut found = None;
+        for (x, value) in self.tlvs() {
+            if x != tag {
+                continue;
+            }
+            if found.is_some() {
+                return Err(VerificationError::DuplicateTlv(tag));
+            }
+            found = Some(value);
+        }
+        Ok(found)
+    }
+
+    /// Anti-rollback security version, images without one are version 0
+    pub fn security_version(&self) -> Result<u32, VerificationError> {
+        match self.find_tlv(TLV_SECURITY_VERSION)? {
+            Some(x) if x.len() == 4 => Ok(read_u32(x, 0)),
+            Some(_) => Err(VerificationError::Truncated),
+            None => Ok(0),
+        }
+    }
+
+    /// Bytes covered by the signature
+    pub fn signed_bytes(&self) -> &'a [u8] {
+        &self.raw[..self.signed_len]
+    }
+
+    /// Signature over `signed_bytes` made with `public_key`
+    pub fn signature(
+        &self,
+        public_key: &[u8],
+    ) -> Result<&'a [u8], VerificationError> {
+        let raw = &self.raw[self.signed_len..];
+        self.sig_alg
+            .signature_len(public_key, raw)
+            .and_then(|x| raw.get(..x))
+            .ok_or(VerificationError::Truncated)
+    }
+}
+
+impl<'a> Iterator for Tlvs<'a> {
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.raw.is_empty() {
+            return None;
+        }
+        // Lengths were checked by `Header::parse`
+        let tag = read_u16(self.raw, 0);
+        let len = read_u16(self.raw, 2) as usize;
+        let value = &self.raw[TLV_HEADER_LEN..TLV_HEADER_LEN + len];
+        self.raw = &self.raw[TLV_HEADER_LEN + len..];
+        Some((tag, value))
+    }
+}
+
+impl fmt::Display for Header<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\tVERSION: {}\n\tSIGNATURE: {:?}\n\tHASH: {:?}\n\tHEADER LENGTH: \
+             0x{:X}\n\tSIGNED LENGTH: 0x{:X}\n\tKEY ID: {:02X?}\n\tPAYLOAD \
+             LENGTH: 0x{:X}",
+            self.version,
+            self.sig_alg,
+            self.hash_alg,
+            self.header_len,
+            self.signed_len,
+            self.key_id,
+            self.payload_len
+        )
+    }
+}
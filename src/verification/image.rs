@@ -0,0 +1,133 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! RISC-V Linux `Image` header.
+//!
+//! See: https://www.kernel.org/doc/html/latest/arch/riscv/boot-image-header.html
+//!
+//! | Offset | Size | Field         |
+//! |--------|------|---------------|
+//! | 0x00   | 4    | `code0`       |
+//! | 0x04   | 4    | `code1`       |
+//! | 0x08   | 8    | `text_offset` |
+//! | 0x10   | 8    | `image_size`  |
+//! | 0x18   | 8    | `flags`       |
+//! | 0x20   | 4    | `version`     |
+//! | 0x24   | 4    | reserved      |
+//! | 0x28   | 8    | reserved      |
+//! | 0x30   | 8    | `magic`       |
+//! | 0x38   | 4    | `magic2`      |
+//! | 0x3C   | 4    | reserved      |
+
+use super::header::{read_u32, read_u64};
+use super::VerificationError;
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Size of the RISC-V Linux `Image` header
+pub const HEADER_LEN: usize = 64;
+/// `magic` field contents, deprecated since header version 0.2
+pub const MAGIC: [u8; 8] = *b"RISCV\0\0\0";
+/// `magic2` field contents
+pub const MAGIC2: [u8; 4] = *b"RSC\x05";
+/// RV64 kernels must be placed on a 2 MiB boundary
+pub const ALIGNMENT: usize = 0x20_0000;
+
+/// Parsed RISC-V Linux `Image` header
+pub struct ImageHeader {
+    /// Executable code
+    pub code0: u32,
+    /// Executable code
+    pub code1: u32,
+    /// Offset from the start of RAM the image expects to be loaded at
+    pub text_offset: usize,
+    /// Memory the kernel occupies once running including its bss
+    pub image_size: usize,
+    /// Kernel flags, bit 0 is endianness
+    pub flags: u64,
+    /// Header version, major in the upper 16 bits
+    pub version: u32,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl ImageHeader {
+    /// Parses the header at the start of `raw`
+    pub fn parse(raw: &[u8]) -> Result<Self, VerificationError> {
+        if raw.len() < HEADER_LEN {
+            return Err(VerificationError::InvalidKernelImage(
+                "image shorter than its header",
+            ));
+        }
+        // Version 0.1 headers only carried the first magic so accept either
+        if raw[0x38..0x3C] != MAGIC2 && raw[0x30..0x38] != MAGIC {
+            return Err(VerificationError::InvalidKernelImage(
+                "RISC-V image magic not found",
+            ));
+        }
+
+        Ok(ImageHeader {
+            code0: read_u32(raw, 0x00),
+            code1: read_u32(raw, 0x04),
+            text_offset: read_u64(raw, 0x08) as usize,
+            image_size: read_u64(raw, 0x10) as usize,
+            flags: read_u64(raw, 0x18),
+            version: read_u32(raw, 0x20),
+        })
+    }
+
+    /// Checks the kernel can run from `address` given physical memory starts
+    /// at `ram`
+    pub fn validate(
+        &self,
+        address: usize,
+        ram: usize,
+    ) -> Result<(), VerificationError> {
+        if self.flags & 1 != 0 {
+            return Err(VerificationError::InvalidKernelImage(
+                "big endian kernel",
+            ));
+        }
+        if self.image_size == 0 {
+            return Err(VerificationError::InvalidKernelImage(
+                "image size is zero",
+            ));
+        }
+        if !address.is_multiple_of(ALIGNMENT) {
+            return Err(VerificationError::InvalidKernelImage(
+                "kernel address is not 2 MiB aligned",
+            ));
+        }
+        // Memory below text_offset is reserved for firmware by the kernel
+        let lowest = ram.checked_add(self.text_offset).ok_or(
+            VerificationError::InvalidKernelImage("text_offset out of range"),
+        )?;
+        if address < lowest {
+            return Err(VerificationError::InvalidKernelImage(
+                "kernel address does not honour text_offset",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ImageHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\tCODE: 0x{:08X} 0x{:08X}\n\tTEXT OFFSET: 0x{:X}\n\tIMAGE SIZE: \
+             0x{:X}\n\tFLAGS: 0x{:X}\n\tVERSION: {}.{}",
+            self.code0,
+            self.code1,
+            self.text_offset,
+            self.image_size,
+            self.flags,
+            self.version >> 16,
+            self.version & 0xFFFF
+        )
+    }
+}
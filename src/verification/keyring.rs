@@ -0,0 +1,69 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Trusted public keys embedded at build time.
+//!
+//! `gen_helper.py` reads `tftp/keyring.json` and emits `helper::KEYRING`, a
+//! list of [`TrustedKey`]s. Images name the key they were signed with through
+//! the key ID in their signed header, either directly or through a chain of
+//! certificates leading back to a keyring key.
+
+pub use super::header::SignatureAlgorithm;
+use super::VerificationError;
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Key may sign boot images
+pub const KEY_USAGE_IMAGE: u32 = 1 << 0;
+/// Key may sign revocation updates
+pub const KEY_USAGE_REVOCATION: u32 = 1 << 1;
+/// Key may sign certificates for other keys, see `certificate`
+pub const KEY_USAGE_CERTIFICATE: u32 = 1 << 2;
+
+/// Public key the loader trusts
+#[derive(Clone, Copy)]
+pub struct TrustedKey {
+    /// Human readable name from the keyring, or of the certificate role
+    pub name: &'static str,
+    /// First 8 bytes of the SHA-256 digest of `key`
+    pub id: [u8; 8],
+    /// `KEY_USAGE_*` flags the key is trusted for
+    pub usage: u32,
+    /// Algorithm the key signs with, it may not be used with any other
+    pub sig_alg: SignatureAlgorithm,
+    /// Raw public key
+    pub key: &'static [u8],
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Looks up the key with `id` and checks it is trusted for every flag in
+/// `usage`
+pub fn find(
+    keyring: &'static [TrustedKey],
+    id: [u8; 8],
+    usage: u32,
+) -> Result<&'static TrustedKey, VerificationError> {
+    let key = keyring
+        .iter()
+        .find(|x| x.id == id)
+        .ok_or(VerificationError::UnknownKey(id))?;
+    if key.usage & usage != usage {
+        return Err(VerificationError::KeyUsage(id));
+    }
+    Ok(key)
+}
+
+impl fmt::Display for TrustedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (", self.name)?;
+        for byte in self.id {
+            write!(f, "{:02X}", byte)?;
+        }
+        write!(f, ", {:?}, usage 0x{:X})", self.sig_alg, self.usage)
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Hierarchical hash-based signatures, HSS/LMS per RFC 8554.
+//!
+//! Only the SHA-256 parameter sets with 32 byte outputs are supported, which
+//! covers every LMS tree height and LM-OTS Winternitz width in the RFC. The
+//! public key is the 60 byte HSS key, `u32` levels followed by the top LMS
+//! key, and the signature is the HSS signature whose length follows from the
+//! types it carries. Every hash goes through `super::sha256` so the vector
+//! backend is used when built for it.
+//!
+//! Signing is stateful: `tftp/lms.py` records the next unused leaf in the
+//! private key file, which must never be restored from an older copy.
+
+use super::sha256;
+use alloc::vec::Vec;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Length of an HSS public key
+pub const PUBLIC_KEY_LEN: usize = 4 + LMS_PUBLIC_KEY_LEN;
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Output length of SHA-256, `n` and `m` in the RFC
+const N: usize = 32;
+/// Length of the key pair identifier `I`
+const I_LEN: usize = 16;
+/// Length of an LMS public key, types, `I` and the root `T[1]`
+const LMS_PUBLIC_KEY_LEN: usize = 4 + 4 + I_LEN + N;
+/// Most levels an HSS key may have
+const MAX_LEVELS: u32 = 8;
+
+const D_PBLC: [u8; 2] = [0x80, 0x80];
+const D_MESG: [u8; 2] = [0x81, 0x81];
+const D_LEAF: [u8; 2] = [0x82, 0x82];
+const D_INTR: [u8; 2] = [0x83, 0x83];
+
+/// LM-OTS parameter set
+#[derive(Clone, Copy)]
+struct OtsType {
+    /// Winternitz width in bits
+    w: usize,
+    /// Number of hash chains
+    p: usize,
+    /// Left shift applied to the checksum
+    ls: usize,
+}
+
+/// View of one LMS signature within an HSS signature
+struct LmsSignature<'a> {
+    q: u32,
+    ots: OtsType,
+    c: &'a [u8],
+    y: &'a [u8],
+    path: &'a [u8],
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+fn read_u32(raw: &[u8], offset: usize) -> Option<u32> {
+    let bytes = raw.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn ots_type(id: u32) -> Option<OtsType> {
+    match id {
+        1 => Some(OtsType {
+            w: 1,
+            p: 265,
+            ls: 7,
+        }),
+        2 => Some(OtsType {
+            w: 2,
+            p: 133,
+            ls: 6,
+        }),
+        3 => Some(OtsType { w: 4, p: 67, ls: 4 }),
+        4 => Some(OtsType { w: 8, p: 34, ls: 0 }),
+        _ => None,
+    }
+}
+
+/// Tree height of an LMS type
+fn lms_height(id: u32) -> Option<usize> {
+    match id {
+        5 => Some(5),
+        6 => Some(10),
+        7 => Some(15),
+        8 => Some(20),
+        9 => Some(25),
+        _ => None,
+    }
+}
+
+/// Length of the LMS signature at the start of `raw`
+fn lms_signature_len(raw: &[u8]) -> Option<usize> {
+    let ots = ots_type(read_u32(raw, 4)?)?;
+    let ots_len = 4 + N + ots.p * N;
+    let height = lms_height(read_u32(raw, 4 + ots_len)?)?;
+    Some(4 + ots_len + 4 + height * N)
+}
+
+/// Parses the LMS signature filling `raw`, which must match `public_key`
+fn parse_lms_signature<'a>(
+    raw: &'a [u8],
+    public_key: &[u8],
+) -> Option<LmsSignature<'a>> {
+    let lms_type = read_u32(public_key, 0)?;
+    let ots_id = read_u32(public_key, 4)?;
+    if read_u32(raw, 4)? != ots_id {
+        return None;
+    }
+    let ots = ots_type(ots_id)?;
+    let y_start = 8 + N;
+    let y_end = y_start + ots.p * N;
+    if read_u32(raw, y_end)? != lms_type {
+        return None;
+    }
+    let height = lms_height(lms_type)?;
+    let q = read_u32(raw, 0)?;
+    if raw.len() != y_end + 4 + height * N || q as u64 >= 1 << height {
+        return None;
+    }
+    Some(LmsSignature {
+        q,
+        ots,
+        c: &raw[8..y_start],
+        y: &raw[y_start..y_end],
+        path: &raw[y_end + 4..],
+    })
+}
+
+/// `w` bit digit `i` of `s`
+fn coef(s: &[u8], i: usize, w: usize) -> usize {
+    let byte = s[i * w / 8] as usize;
+    let shift = 8 - (w * (i % (8 / w)) + w);
+    (byte >> shift) & ((1 << w) - 1)
+}
+
+/// Computes the candidate LM-OTS public key, algorithm 4b
+fn ots_candidate(
+    id: &[u8],
+    signature: &LmsSignature,
+    message: &[u8],
+) -> [u8; N] {
+    let ots = signature.ots;
+    let q = signature.q.to_be_bytes();
+
+    let mut buffer = Vec::with_capacity(22 + N + message.len());
+    buffer.extend_from_slice(id);
+    buffer.extend_from_slice(&q);
+    buffer.extend_from_slice(&D_MESG);
+    buffer.extend_from_slice(signature.c);
+    buffer.extend_from_slice(message);
+    let digest = sha256(&buffer);
+
+    let max = (1 << ots.w) - 1;
+    let mut checksum = 0u16;
+    for i in 0..N * 8 / ots.w {
+        checksum += (max - coef(&digest, i, ots.w)) as u16;
+    }
+    let mut digits = [0; N + 2];
+    digits[..N].copy_from_slice(&digest);
+    digits[N..].copy_from_slice(&(checksum << ots.ls).to_be_bytes());
+
+    let mut public = Vec::with_capacity(22 + ots.p * N);
+    public.extend_from_slice(id);
+    public.extend_from_slice(&q);
+    public.extend_from_slice(&D_PBLC);
+    let mut chain = Vec::with_capacity(23 + N);
+    for i in 0..ots.p {
+        let mut tmp = [0; N];
+        tmp.copy_from_slice(&signature.y[i * N..(i + 1) * N]);
+        for j in coef(&digits, i, ots.w)..max {
+            chain.clear();
+            chain.extend_from_slice(id);
+            chain.extend_from_slice(&q);
+            chain.extend_from_slice(&(i as u16).to_be_bytes());
+            chain.push(j as u8);
+            chain.extend_from_slice(&tmp);
+            tmp = sha256(&chain);
+        }
+        public.extend_from_slice(&tmp);
+    }
+    sha256(&public)
+}
+
+/// Checks the LMS `signature` over `message` against `public_key`,
+/// algorithm 6a
+fn verify_lms(public_key: &[u8], signature: &[u8], message: &[u8]) -> bool {
+    let signature = match parse_lms_signature(signature, public_key) {
+        Some(x) => x,
+        None => return false,
+    };
+    let id = &public_key[8..8 + I_LEN];
+    let root = &public_key[8 + I_LEN..LMS_PUBLIC_KEY_LEN];
+    let height = signature.path.len() / N;
+
+    let mut node = (1u32 << height) + signature.q;
+    let mut buffer = Vec::with_capacity(22 + 2 * N);
+    buffer.extend_from_slice(id);
+    buffer.extend_from_slice(&node.to_be_bytes());
+    buffer.extend_from_slice(&D_LEAF);
+    buffer.extend_from_slice(&ots_candidate(id, &signature, message));
+    let mut tmp = sha256(&buffer);
+
+    for sibling in signature.path.chunks(N) {
+        buffer.clear();
+        buffer.extend_from_slice(id);
+        buffer.extend_from_slice(&(node / 2).to_be_bytes());
+        buffer.extend_from_slice(&D_INTR);
+        if node % 2 == 1 {
+            buffer.extend_from_slice(sibling);
+            buffer.extend_from_slice(&tmp);
+        } else {
+            buffer.extend_from_slice(&tmp);
+            buffer.extend_from_slice(sibling);
+        }
+        tmp = sha256(&buffer);
+        node /= 2;
+    }
+    tmp == root
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Length of the HSS signature at the start of `raw`
+pub fn signature_len(raw: &[u8]) -> Option<usize> {
+    let signed_keys = read_u32(raw, 0)?;
+    if signed_keys >= MAX_LEVELS {
+        return None;
+    }
+    let mut offset = 4;
+    for _ in 0..signed_keys {
+        offset += lms_signature_len(raw.get(offset..)?)? + LMS_PUBLIC_KEY_LEN;
+    }
+    offset += lms_signature_len(raw.get(offset..)?)?;
+    (offset <= raw.len()).then_some(offset)
+}
+
+/// Checks the HSS `signature` over `message` against `public_key`, each level
+/// signing the public key of the one below, algorithm 8
+pub fn verify(public_key: &[u8], signature: &[u8], message: &[u8]) -> bool {
+    let levels = match read_u32(public_key, 0) {
+        Some(x) if public_key.len() == PUBLIC_KEY_LEN => x,
+        _ => return false,
+    };
+    if signature_len(signature) != Some(signature.len())
+        || read_u32(signature, 0) != Some(levels.wrapping_sub(1))
+    {
+        return false;
+    }
+
+    let mut key = &public_key[4..];
+    let mut offset = 4;
+    for _ in 1..levels {
+        // Lengths were checked by `signature_len`
+        let len = lms_signature_len(&signature[offset..]).unwrap_or(0);
+        let next = &signature[offset + len..offset + len + LMS_PUBLIC_KEY_LEN];
+        if !verify_lms(key, &signature[offset..offset + len], next) {
+            return false;
+        }
+        key = next;
+        offset += len + LMS_PUBLIC_KEY_LEN;
+    }
+    verify_lms(key, &signature[offset..], message)
+}
@@ -0,0 +1,176 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Signed manifest of the components loaded alongside SentinelBoot.
+//!
+//! Each component is a `header::TLV_COMPONENT` record in the signed header:
+//!
+//! | Offset | Size | Field          |
+//! |--------|------|----------------|
+//! | 0x00   | 4    | `kind`         |
+//! | 0x04   | 4    | reserved       |
+//! | 0x08   | 8    | `load_address` |
+//! | 0x10   | 8    | `length`       |
+//! | 0x18   | 64   | `digest`       |
+
+use super::header::{read_u32, read_u64, Header, TLV_COMPONENT};
+use super::VerificationError;
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Size of a component record
+pub const COMPONENT_LEN: usize = 0x58;
+
+/// Components the manifest must describe
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ComponentKind {
+    /// Linux kernel
+    Kernel,
+    /// Flattened device tree
+    Dtb,
+    /// Initial RAM file system
+    Ramfs,
+}
+
+/// Signed description of a single component
+#[derive(Clone, Copy)]
+pub struct Component<'a> {
+    /// What the component is
+    pub kind: ComponentKind,
+    /// Address the component was loaded to
+    pub load_address: usize,
+    /// Length of the component in bytes
+    pub length: usize,
+    /// Expected digest of the component
+    pub digest: &'a [u8],
+}
+
+/// Every component required to boot
+pub struct Manifest<'a> {
+    /// Linux kernel
+    pub kernel: Component<'a>,
+    /// Flattened device tree
+    pub dtb: Component<'a>,
+    /// Initial RAM file system
+    pub ramfs: Component<'a>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl ComponentKind {
+    /// Maps the on-disk identifier to a component kind
+    pub fn from_id(id: u32) -> Result<Self, VerificationError> {
+        match id {
+            1 => Ok(ComponentKind::Kernel),
+            2 => Ok(ComponentKind::Dtb),
+            3 => Ok(ComponentKind::Ramfs),
+            _ => Err(VerificationError::UnknownComponent(id)),
+        }
+    }
+
+    /// Address the BSP loads the component to
+    pub fn load_address(&self) -> usize {
+        match self {
+            ComponentKind::Kernel => bsp::memory::map::kernel::KERNEL,
+            ComponentKind::Dtb => bsp::memory::map::kernel::DTB,
+            ComponentKind::Ramfs => bsp::memory::map::kernel::RAMFS,
+        }
+    }
+
+    /// Address the component must end before
+    pub fn limit(&self) -> usize {
+        match self {
+            ComponentKind::Kernel => bsp::memory::map::kernel::DTB,
+            // The top of the DTB's space holds the DICE handover and the
+            // measured boot event log
+            ComponentKind::Dtb => bsp::memory::map::kernel::DICE,
+            ComponentKind::Ramfs => bsp::memory::map::kernel::RAM_END,
+        }
+    }
+}
+
+impl<'a> Component<'a> {
+    fn parse(
+        value: &'a [u8],
+        digest_len: usize,
+    ) -> Result<Self, VerificationError> {
+        if value.len() != COMPONENT_LEN {
+            return Err(VerificationError::Truncated);
+        }
+        Ok(Component {
+            kind: ComponentKind::from_id(read_u32(value, 0x00))?,
+            load_address: read_u64(value, 0x08) as usize,
+            length: read_u64(value, 0x10) as usize,
+            digest: &value[0x18..0x18 + digest_len],
+        })
+    }
+
+    /// Checks the component sits where the BSP expects and does not run into
+    /// the following region
+    pub fn check_bounds(&self) -> Result<(), VerificationError> {
+        if self.load_address != self.kind.load_address() {
+            return Err(VerificationError::ComponentAddress(self.kind));
+        }
+        match self.load_address.checked_add(self.length) {
+            Some(end) if end <= self.kind.limit() => Ok(()),
+            _ => Err(VerificationError::ComponentOverlap(self.kind)),
+        }
+    }
+}
+
+impl<'a> Manifest<'a> {
+    /// Collects the components from the header's TLV records, each kind must
+    /// be present exactly once
+    pub fn parse(header: &Header<'a>) -> Result<Self, VerificationError> {
+        let mut kernel = None;
+        let mut dtb = None;
+        let mut ramfs = None;
+
+        for (tag, value) in header.tlvs() {
+            if tag != TLV_COMPONENT {
+                continue;
+            }
+            let component =
+                Component::parse(value, header.hash_alg.digest_len())?;
+            let slot = match component.kind {
+                ComponentKind::Kernel => &mut kernel,
+                ComponentKind::Dtb => &mut dtb,
+                ComponentKind::Ramfs => &mut ramfs,
+            };
+            if slot.is_some() {
+                return Err(VerificationError::DuplicateComponent(
+                    component.kind,
+                ));
+            }
+            *slot = Some(component);
+        }
+
+        Ok(Manifest {
+            kernel: kernel.ok_or(VerificationError::MissingComponent(
+                ComponentKind::Kernel,
+            ))?,
+            dtb: dtb.ok_or(VerificationError::MissingComponent(
+                ComponentKind::Dtb,
+            ))?,
+            ramfs: ramfs.ok_or(VerificationError::MissingComponent(
+                ComponentKind::Ramfs,
+            ))?,
+        })
+    }
+}
+
+impl fmt::Display for Component<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\t{:?}: 0x{:X} -> 0x{:X}",
+            self.kind,
+            self.load_address,
+            self.load_address + self.length
+        )
+    }
+}
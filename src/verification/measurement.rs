@@ -0,0 +1,263 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Measured boot.
+//!
+//! Once an image verifies, the SentinelBoot version, the key that signed it
+//! and the digest of each component are extended into software PCRs and
+//! recorded in an event log in the crypto agile format of the TCG PC Client
+//! Platform Firmware Profile, with the header's digest as the only bank.
+//! `publish` copies the log to `EVENT_LOG` and points the kernel at it from
+//! the DTB, through a `/reserved-memory` node and the `linux,sml-base` and
+//! `linux,sml-size` properties of `/chosen`.
+
+use super::{
+    hash_region,
+    header::HashAlgorithm,
+    keyring::TrustedKey,
+    manifest::{ComponentKind, Manifest},
+    Hash,
+    VerificationError,
+};
+use crate::fdt::{self, Reservation};
+use alloc::{format, vec::Vec};
+use console::{print, println};
+use core::ptr;
+use synchronisation::{interface::Mutex, NullLock};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Software PCRs and the events extended into them
+pub struct EventLog {
+    hash_alg: HashAlgorithm,
+    pcrs: [Hash; PCR_COUNT],
+    extended: [bool; PCR_COUNT],
+    events: Vec<u8>,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// PCRs of a TPM 2.0 PC Client platform
+const PCR_COUNT: usize = 24;
+/// Static root of trust, the SentinelBoot version
+const PCR_LOADER: usize = 0;
+/// Kernel
+const PCR_KERNEL: usize = 4;
+/// Platform configuration, the DTB
+const PCR_DTB: usize = 5;
+/// Key authorising the image
+const PCR_AUTHORITY: usize = 7;
+/// Initramfs
+const PCR_RAMFS: usize = 9;
+
+const EV_NO_ACTION: u32 = 0x3;
+const EV_S_CRTM_VERSION: u32 = 0x8;
+const EV_IPL: u32 = 0xD;
+
+/// `TCG_EfiSpecIDEvent` signature of a crypto agile log
+const SPEC_ID_SIGNATURE: &[u8; 16] = b"Spec ID Event03\0";
+/// Digest of the SHA-1 format header event, which is not extended
+const SPEC_ID_DIGEST_LEN: usize = 20;
+
+static LOG: NullLock<Option<EventLog>> = NullLock::new(None);
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+impl EventLog {
+    /// Appends a `TCG_PCR_EVENT2` to the log
+    fn record(
+        &mut self,
+        pcr: usize,
+        event_type: u32,
+        digest: &[u8],
+        data: &[u8],
+    ) {
+        let events = &mut self.events;
+        events.extend_from_slice(&(pcr as u32).to_le_bytes());
+        events.extend_from_slice(&event_type.to_le_bytes());
+        events.extend_from_slice(&1u32.to_le_bytes());
+        events.extend_from_slice(&self.hash_alg.tpm_alg_id().to_le_bytes());
+        events.extend_from_slice(digest);
+        events.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        events.extend_from_slice(data);
+    }
+
+    /// Extends `digest` into `pcr`, new value H(old value || `digest`), and
+    /// records the event described by `data`
+    fn extend(
+        &mut self,
+        pcr: usize,
+        event_type: u32,
+        digest: &[u8],
+        data: &[u8],
+    ) {
+        let mut message = Vec::with_capacity(2 * digest.len());
+        message.extend_from_slice(self.pcrs[pcr].as_slice());
+        message.extend_from_slice(digest);
+        self.pcrs[pcr] = hash_region(
+            self.hash_alg,
+            message.as_ptr() as usize,
+            message.len(),
+        );
+        self.extended[pcr] = true;
+        self.record(pcr, event_type, digest, data);
+    }
+
+    /// Extends the digest of `data` itself into `pcr`
+    fn measure(&mut self, pcr: usize, event_type: u32, data: &[u8]) {
+        let digest =
+            hash_region(self.hash_alg, data.as_ptr() as usize, data.len());
+        self.extend(pcr, event_type, digest.as_slice(), data);
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl EventLog {
+    /// Empty log with PCRs of `hash_alg` at zero, holding only the
+    /// `TCG_EfiSpecIDEvent` describing its format
+    pub fn new(hash_alg: HashAlgorithm) -> Self {
+        let zero = [0; super::header::MAX_DIGEST_LEN];
+        let mut log = EventLog {
+            hash_alg,
+            pcrs: core::array::from_fn(|_| {
+                Hash::new(&zero[..hash_alg.digest_len()])
+            }),
+            extended: [false; PCR_COUNT],
+            events: Vec::new(),
+        };
+
+        let mut spec_id = Vec::new();
+        spec_id.extend_from_slice(SPEC_ID_SIGNATURE);
+        // Platform class, client, then spec version 2.0 errata 0 and UINT64
+        // sized UINTN
+        spec_id.extend_from_slice(&0u32.to_le_bytes());
+        spec_id.extend_from_slice(&[0, 2, 0, 2]);
+        spec_id.extend_from_slice(&1u32.to_le_bytes());
+        spec_id.extend_from_slice(&hash_alg.tpm_alg_id().to_le_bytes());
+        spec_id
+            .extend_from_slice(&(hash_alg.digest_len() as u16).to_le_bytes());
+        // No vendor information
+        spec_id.push(0);
+
+        let events = &mut log.events;
+        events.extend_from_slice(&(PCR_LOADER as u32).to_le_bytes());
+        events.extend_from_slice(&EV_NO_ACTION.to_le_bytes());
+        events.extend_from_slice(&[0; SPEC_ID_DIGEST_LEN]);
+        events.extend_from_slice(&(spec_id.len() as u32).to_le_bytes());
+        events.extend_from_slice(&spec_id);
+        log
+    }
+
+    /// Measures the loader version, the key which authorised the image and
+    /// every component of `manifest`, which must already be verified
+    pub fn measure_boot(&mut self, key: &TrustedKey, manifest: &Manifest) {
+        // The event data is a NUL terminated UCS-2 string
+        let version: Vec<u8> = format!(
+            "{} {} ({})",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            crate::helper::SHA
+        )
+        .encode_utf16()
+        .chain([0])
+        .flat_map(u16::to_le_bytes)
+        .collect();
+        self.measure(PCR_LOADER, EV_S_CRTM_VERSION, &version);
+
+        // The whole key is measured, the log names it by identifier
+        let digest = hash_region(
+            self.hash_alg,
+            key.key.as_ptr() as usize,
+            key.key.len(),
+        );
+        self.extend(PCR_AUTHORITY, EV_IPL, digest.as_slice(), &key.id);
+
+        for component in [manifest.kernel, manifest.dtb, manifest.ramfs] {
+            let (pcr, name) = match component.kind {
+                ComponentKind::Kernel => (PCR_KERNEL, "kernel"),
+                ComponentKind::Dtb => (PCR_DTB, "dtb"),
+                ComponentKind::Ramfs => (PCR_RAMFS, "initramfs"),
+            };
+            self.extend(pcr, EV_IPL, component.digest, name.as_bytes());
+        }
+    }
+
+    /// Log in its binary form
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.events
+    }
+
+    /// Prints every PCR that has been extended
+    pub fn print_pcrs(&self) {
+        for (index, pcr) in self.pcrs.iter().enumerate() {
+            if !self.extended[index] {
+                continue;
+            }
+            print!("PCR[{:2}]: ", index);
+            for byte in pcr.as_slice() {
+                print!("{:02X}", byte);
+            }
+            println!();
+        }
+    }
+}
+
+/// Keeps `log` for `publish`
+pub fn store(log: EventLog) {
+    LOG.lock(|x| *x = Some(log));
+}
+
+/// Copies the stored event log to `EVENT_LOG` and rewrites the DTB to hand
+/// it to the kernel, only to be called once every check has passed
+pub fn publish() -> Result<(), VerificationError> {
+    use bsp::memory::map::kernel::{DICE, DTB, EVENT_LOG, EVENT_LOG_LEN};
+    LOG.lock(|log| {
+        let log = log
+            .as_ref()
+            .ok_or(VerificationError::EventLog("nothing has been measured"))?;
+        let events = log.as_bytes();
+        if events.len() > EVENT_LOG_LEN {
+            return Err(VerificationError::EventLog("log too long"));
+        }
+        let reservation = Reservation {
+            name: "event-log",
+            compatible: None,
+            address: EVENT_LOG,
+            len: EVENT_LOG_LEN,
+        };
+        let base = (EVENT_LOG as u64).to_be_bytes();
+        let size = (events.len() as u32).to_be_bytes();
+        fdt::rewrite(DTB, DICE - DTB, |tree| {
+            tree.with_reservation(
+                &reservation,
+                &[("linux,sml-base", &base), ("linux,sml-size", &size)],
+            )
+        })
+        .map_err(VerificationError::EventLog)?;
+
+        // No component or the heap reaches the log, the DTB limit stops
+        // short of it
+        unsafe {
+            ptr::copy_nonoverlapping(
+                events.as_ptr(),
+                EVENT_LOG as *mut u8,
+                events.len(),
+            );
+        }
+        println!(
+            "Event log of 0x{:X} bytes at 0x{:X}:",
+            events.len(),
+            EVENT_LOG
+        );
+        log.print_pcrs();
+        Ok(())
+    })
+}
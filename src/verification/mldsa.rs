@@ -0,0 +1,319 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! ML-DSA-65 signature verification, FIPS 204.
+//!
+//! Keys and signatures use the raw FIPS 204 encodings, 1952 and 3309 bytes,
+//! and signatures are over the message with an empty context string. The
+//! implementations on crates.io expand the whole public matrix up front and
+//! need far more than the 32K of loader stack, so this one samples each
+//! matrix entry only when it is needed and keeps the larger vectors on the
+//! `global_allocator` heap, under 8K of it.
+
+use alloc::{vec, vec::Vec};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake128,
+    Shake256,
+};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Length of an encoded public key
+pub const PUBLIC_KEY_LEN: usize = 32 + K * T1_POLY_LEN;
+/// Length of an encoded signature
+pub const SIGNATURE_LEN: usize = C_TILDE_LEN + L * Z_POLY_LEN + OMEGA + K;
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+const N: usize = 256;
+const Q: i32 = 8380417;
+/// Bits dropped from `t`
+const D: u32 = 13;
+/// Number of non-zero coefficients of the challenge
+const TAU: usize = 49;
+const GAMMA1: i32 = 1 << 19;
+const GAMMA2: i32 = (Q - 1) / 32;
+const BETA: i32 = 196;
+const OMEGA: usize = 55;
+/// Rows of the public matrix
+const K: usize = 6;
+/// Columns of the public matrix
+const L: usize = 5;
+
+const C_TILDE_LEN: usize = 48;
+const T1_POLY_LEN: usize = N * 10 / 8;
+const Z_POLY_LEN: usize = N * 20 / 8;
+const W1_POLY_LEN: usize = N * 4 / 8;
+
+/// `256^-1 mod q` applied at the end of the inverse NTT
+const N_INV: i64 = 8347681;
+/// Powers of the root of unity 1753 in bit reversed order
+const ZETAS: [i32; N] = zetas();
+
+type Poly = [i32; N];
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+const fn zetas() -> [i32; N] {
+    let mut zetas = [0; N];
+    let mut k = 0;
+    while k < N {
+        let mut exponent = (k as u8).reverse_bits();
+        let mut base = 1753i64;
+        let mut result = 1i64;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base % Q as i64;
+            }
+            base = base * base % Q as i64;
+            exponent >>= 1;
+        }
+        zetas[k] = result as i32;
+        k += 1;
+    }
+    zetas
+}
+
+fn mul(a: i32, b: i32) -> i32 {
+    (a as i64 * b as i64 % Q as i64) as i32
+}
+
+fn add(a: i32, b: i32) -> i32 {
+    (a + b) % Q
+}
+
+fn sub(a: i32, b: i32) -> i32 {
+    (a - b + Q) % Q
+}
+
+fn ntt(w: &mut Poly) {
+    let mut m = 0;
+    let mut len = 128;
+    while len >= 1 {
+        for start in (0..N).step_by(2 * len) {
+            m += 1;
+            for j in start..start + len {
+                let t = mul(ZETAS[m], w[j + len]);
+                w[j + len] = sub(w[j], t);
+                w[j] = add(w[j], t);
+            }
+        }
+        len /= 2;
+    }
+}
+
+fn inverse_ntt(w: &mut Poly) {
+    let mut m = N;
+    let mut len = 1;
+    while len < N {
+        for start in (0..N).step_by(2 * len) {
+            m -= 1;
+            let zeta = Q - ZETAS[m];
+            for j in start..start + len {
+                let t = w[j];
+                w[j] = add(t, w[j + len]);
+                w[j + len] = mul(zeta, sub(t, w[j + len]));
+            }
+        }
+        len *= 2;
+    }
+    for x in w.iter_mut() {
+        *x = (*x as i64 * N_INV % Q as i64) as i32;
+    }
+}
+
+/// Unpacks `N` little endian values of `bits` bits each
+fn unpack(raw: &[u8], bits: usize) -> Poly {
+    let mut poly = [0; N];
+    let mut acc = 0u64;
+    let mut held = 0;
+    let mut bytes = raw.iter();
+    for x in poly.iter_mut() {
+        while held < bits {
+            acc |= (*bytes.next().unwrap_or(&0) as u64) << held;
+            held += 8;
+        }
+        *x = (acc & ((1 << bits) - 1)) as i32;
+        acc >>= bits;
+        held -= bits;
+    }
+    poly
+}
+
+/// Entry `row`, `column` of the public matrix in NTT form, `RejNTTPoly`
+fn matrix_entry(rho: &[u8], row: usize, column: usize) -> Poly {
+    let mut xof = Shake128::default();
+    xof.update(rho);
+    xof.update(&[column as u8, row as u8]);
+    let mut reader = xof.finalize_xof();
+
+    let mut poly = [0; N];
+    let mut filled = 0;
+    let mut bytes = [0; 3];
+    while filled < N {
+        reader.read(&mut bytes);
+        let x = i32::from_le_bytes([bytes[0], bytes[1], bytes[2] & 0x7F, 0]);
+        if x < Q {
+            poly[filled] = x;
+            filled += 1;
+        }
+    }
+    poly
+}
+
+/// Challenge polynomial with `TAU` coefficients of +/-1, `SampleInBall`
+fn challenge(c_tilde: &[u8]) -> Poly {
+    let mut xof = Shake256::default();
+    xof.update(c_tilde);
+    let mut reader = xof.finalize_xof();
+    let mut signs = [0; 8];
+    reader.read(&mut signs);
+    let signs = u64::from_le_bytes(signs);
+
+    let mut c = [0; N];
+    for i in N - TAU..N {
+        let mut j = [0];
+        loop {
+            reader.read(&mut j);
+            if j[0] as usize <= i {
+                break;
+            }
+        }
+        let j = j[0] as usize;
+        c[i] = c[j];
+        c[j] = if signs >> (i + TAU - N) & 1 == 1 {
+            Q - 1
+        } else {
+            1
+        };
+    }
+    c
+}
+
+/// Unpacks the hint into one bit per coefficient, rejecting any encoding
+/// other than the unique valid one, `HintBitUnpack`
+fn unpack_hint(raw: &[u8]) -> Option<Vec<[bool; N]>> {
+    let mut hint = vec![[false; N]; K];
+    let mut index = 0;
+    for (i, row) in hint.iter_mut().enumerate() {
+        let end = raw[OMEGA + i] as usize;
+        if end < index || end > OMEGA {
+            return None;
+        }
+        let first = index;
+        while index < end {
+            if index > first && raw[index - 1] >= raw[index] {
+                return None;
+            }
+            row[raw[index] as usize] = true;
+            index += 1;
+        }
+    }
+    raw[index..OMEGA].iter().all(|x| *x == 0).then_some(hint)
+}
+
+/// High bits of `r` corrected by `hint`, `UseHint`
+fn use_hint(hint: bool, r: i32) -> i32 {
+    let mut r0 = r % (2 * GAMMA2);
+    if r0 > GAMMA2 {
+        r0 -= 2 * GAMMA2;
+    }
+    // The top of the range wraps to high bits 0 with r0 - 1 <= 0
+    if r - r0 == Q - 1 {
+        return if hint { 15 } else { 0 };
+    }
+    let r1 = (r - r0) / (2 * GAMMA2);
+    match (hint, r0 > 0) {
+        (false, _) => r1,
+        (true, true) => (r1 + 1) % 16,
+        (true, false) => (r1 + 15) % 16,
+    }
+}
+
+fn shake256(parts: &[&[u8]], out: &mut [u8]) {
+    let mut xof = Shake256::default();
+    for x in parts {
+        xof.update(x);
+    }
+    xof.finalize_xof().read(out);
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Checks the ML-DSA-65 `signature` over `message` against `public_key`,
+/// `ML-DSA.Verify` with an empty context
+pub fn verify(public_key: &[u8], signature: &[u8], message: &[u8]) -> bool {
+    if public_key.len() != PUBLIC_KEY_LEN || signature.len() != SIGNATURE_LEN {
+        return false;
+    }
+    let (rho, t1) = public_key.split_at(32);
+    let (c_tilde, rest) = signature.split_at(C_TILDE_LEN);
+    let (z, hint) = rest.split_at(L * Z_POLY_LEN);
+    let hint = match unpack_hint(hint) {
+        Some(x) => x,
+        None => return false,
+    };
+
+    // z is stored as gamma1 - z and must be short
+    let mut z_hat = vec![[0; N]; L];
+    for (x, raw) in z_hat.iter_mut().zip(z.chunks(Z_POLY_LEN)) {
+        *x = unpack(raw, 20);
+        for coefficient in x.iter_mut() {
+            let centred = GAMMA1 - *coefficient;
+            if centred.abs() >= GAMMA1 - BETA {
+                return false;
+            }
+            *coefficient = (centred + Q) % Q;
+        }
+        ntt(x);
+    }
+
+    let mut tr = [0; 64];
+    shake256(&[public_key], &mut tr);
+    let mut mu = [0; 64];
+    // Pure ML-DSA prefix with an empty context
+    shake256(&[&tr, &[0, 0], message], &mut mu);
+
+    let mut c_hat = challenge(c_tilde);
+    ntt(&mut c_hat);
+
+    // One row of A z - c t1 2^d at a time
+    let mut w1 = vec![0; K * W1_POLY_LEN];
+    for (i, packed) in w1.chunks_mut(W1_POLY_LEN).enumerate() {
+        let mut t1_hat = unpack(&t1[i * T1_POLY_LEN..], 10);
+        for x in t1_hat.iter_mut() {
+            *x <<= D;
+        }
+        ntt(&mut t1_hat);
+
+        let mut w = [0; N];
+        for (j, z_hat) in z_hat.iter().enumerate() {
+            let a_hat = matrix_entry(rho, i, j);
+            for k in 0..N {
+                w[k] = add(w[k], mul(a_hat[k], z_hat[k]));
+            }
+        }
+        for k in 0..N {
+            w[k] = sub(w[k], mul(c_hat[k], t1_hat[k]));
+        }
+        inverse_ntt(&mut w);
+
+        for (k, byte) in packed.iter_mut().enumerate() {
+            let low = use_hint(hint[i][2 * k], w[2 * k]);
+            let high = use_hint(hint[i][2 * k + 1], w[2 * k + 1]);
+            *byte = (low | high << 4) as u8;
+        }
+    }
+
+    let mut expected = [0; C_TILDE_LEN];
+    shake256(&[&mu, &w1], &mut expected);
+    expected == c_tilde
+}
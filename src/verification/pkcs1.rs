@@ -0,0 +1,115 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! RSA public keys and signatures.
+//!
+//! RSA keys are held as a DER encoded PKCS#1 `RSAPublicKey`, the form
+//! `tftp/generate_rsa_keys.py` writes and most existing PKI tooling can
+//! export. Unlike the other algorithms their length varies, so it is read
+//! from the DER rather than fixed, and signatures are as long as the modulus.
+//! The arithmetic needs the `global_allocator` heap.
+
+#[cfg(feature = "rsa")]
+use super::{keyring::TrustedKey, SignatureAlgorithm, VerificationError};
+#[cfg(feature = "rsa")]
+use console::println;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Keys shorter than this are refused outright
+pub const MIN_BITS: usize = 2048;
+/// Keys shorter than this are accepted with a warning
+#[cfg(feature = "rsa")]
+pub const RECOMMENDED_BITS: usize = 3072;
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+const DER_INTEGER: u8 = 0x02;
+const DER_SEQUENCE: u8 = 0x30;
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+/// Reads the DER tag and length at the start of `raw`, returning the length
+/// of the tag and length bytes and that of the contents
+fn read_der(raw: &[u8], tag: u8) -> Option<(usize, usize)> {
+    if *raw.first()? != tag {
+        return None;
+    }
+    let first = *raw.get(1)? as usize;
+    if first < 0x80 {
+        return Some((2, first));
+    }
+    // Two length bytes already cover far larger keys than are accepted
+    let count = first & 0x7F;
+    if count == 0 || count > 2 {
+        return None;
+    }
+    let mut len = 0;
+    for i in 0..count {
+        len = len << 8 | *raw.get(2 + i)? as usize;
+    }
+    Some((2 + count, len))
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Length of the DER encoded key at the start of `raw`
+pub fn key_len(raw: &[u8]) -> Option<usize> {
+    let (header, contents) = read_der(raw, DER_SEQUENCE)?;
+    let len = header.checked_add(contents)?;
+    (len <= raw.len()).then_some(len)
+}
+
+/// Length of the modulus of `key` in bytes, which every signature matches
+pub fn modulus_len(key: &[u8]) -> Option<usize> {
+    let (header, _) = read_der(key, DER_SEQUENCE)?;
+    let (int_header, int_len) = read_der(key.get(header..)?, DER_INTEGER)?;
+    let start = header + int_header;
+    let modulus = key.get(start..start + int_len)?;
+    // DER prepends a zero byte when the top bit of the modulus is set
+    Some(modulus.iter().skip_while(|x| **x == 0).count())
+}
+
+/// Checks `signature` over `message` with the RSA `key`, refusing keys below
+/// `MIN_BITS` and warning of those below `RECOMMENDED_BITS`
+#[cfg(feature = "rsa")]
+pub fn verify(
+    key: &TrustedKey,
+    sig_alg: SignatureAlgorithm,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<(), VerificationError> {
+    use rsa::traits::PublicKeyParts;
+    use rsa::{pkcs1::DecodeRsaPublicKey, Pkcs1v15Sign, Pss, RsaPublicKey};
+    use sha2::{Digest, Sha256};
+
+    let failed = VerificationError::Signature;
+    let public_key =
+        RsaPublicKey::from_pkcs1_der(key.key).map_err(|_| failed(sig_alg))?;
+    let bits = public_key.n().bits();
+    if bits < MIN_BITS {
+        return Err(VerificationError::WeakKey(key.id, bits));
+    }
+    if bits < RECOMMENDED_BITS {
+        println!(
+            "! -- Key {} is only {} bits, {} or more is recommended",
+            key, bits, RECOMMENDED_BITS
+        );
+    }
+
+    let digest = Sha256::digest(message);
+    let result = match sig_alg {
+        SignatureAlgorithm::RsaPkcs1v15Sha256 => {
+            public_key.verify(Pkcs1v15Sign::new::<Sha256>(), &digest, signature)
+        }
+        _ => public_key.verify(Pss::new::<Sha256>(), &digest, signature),
+    };
+    result.map_err(|_| failed(sig_alg))
+}
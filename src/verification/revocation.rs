@@ -0,0 +1,131 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Revoked key fingerprints.
+//!
+//! A fingerprint is the SHA-256 digest of a raw public key, the key ID is its
+//! first 8 bytes. `gen_helper.py` embeds `tftp/revoked.json` as
+//! `helper::REVOKED`; further keys can be revoked without rebuilding by a
+//! signed update loaded to `bsp::memory::map::kernel::REVOCATION`, produced by
+//! `tftp/revoke.py`:
+//!
+//! | Offset | Size       | Field          |
+//! |--------|------------|----------------|
+//! | 0x00   | 8          | `magic`        |
+//! | 0x08   | 2          | `version`      |
+//! | 0x0A   | 1          | `sig_alg`      |
+//! | 0x0B   | 1          | reserved       |
+//! | 0x0C   | 4          | `count`        |
+//! | 0x10   | 8          | `key_id`       |
+//! | 0x18   | 4          | `sequence`     |
+//! | 0x1C   | 4          | reserved       |
+//! | 0x20   | 32 * count | fingerprints   |
+//!
+//! The signature covers every preceding byte and immediately follows them. An
+//! update may only add to the embedded list, it cannot reinstate a key. Each
+//! update carries a higher `sequence` than the last, and the highest one seen
+//! is kept with the anti-rollback floor, so once a board has accepted an
+//! update it refuses older ones and boots without one no longer.
+
+use super::header::{read_u16, read_u32, SignatureAlgorithm};
+use super::VerificationError;
+use core::fmt;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Identifies a SentinelBoot revocation update
+pub const MAGIC: [u8; 8] = *b"SNTLRVKL";
+/// Only revocation update format understood by this loader
+pub const VERSION: u16 = 1;
+/// Size of the fixed portion of the update
+pub const FIXED_LEN: usize = 0x20;
+/// Size of a key fingerprint
+pub const FINGERPRINT_LEN: usize = 32;
+/// Largest update accepted, the size of its load slot
+pub const MAX_LEN: usize = 0x1000;
+
+/// Parsed view of a signed revocation update
+pub struct RevocationList<'a> {
+    raw: &'a [u8],
+    /// Algorithm the signature was produced with
+    pub sig_alg: SignatureAlgorithm,
+    /// Identifier of the key which produced the signature
+    pub key_id: [u8; 8],
+    /// Position of the update in the issuer's series, higher is newer
+    pub sequence: u32,
+    /// Number of revoked fingerprints
+    pub count: usize,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl<'a> RevocationList<'a> {
+    /// Parses the update at the start of `raw`, no update was loaded if the
+    /// magic is absent
+    pub fn parse(raw: &'a [u8]) -> Result<Option<Self>, VerificationError> {
+        if raw.len() < FIXED_LEN || raw[0x00..0x08] != MAGIC {
+            return Ok(None);
+        }
+        if read_u16(raw, 0x08) != VERSION {
+            return Err(VerificationError::InvalidRevocationList(
+                "unsupported version",
+            ));
+        }
+        let sig_alg = SignatureAlgorithm::from_id(raw[0x0A])?;
+        let count = read_u32(raw, 0x0C) as usize;
+        let end = count
+            .checked_mul(FINGERPRINT_LEN)
+            .and_then(|x| x.checked_add(FIXED_LEN));
+        if !matches!(end, Some(x) if x <= raw.len()) {
+            return Err(VerificationError::InvalidRevocationList("truncated"));
+        }
+
+        let mut key_id = [0; 8];
+        key_id.copy_from_slice(&raw[0x10..0x18]);
+        Ok(Some(RevocationList {
+            raw,
+            sig_alg,
+            key_id,
+            sequence: read_u32(raw, 0x18),
+            count,
+        }))
+    }
+
+    /// Bytes covered by the signature
+    pub fn signed_bytes(&self) -> &'a [u8] {
+        &self.raw[..FIXED_LEN + self.count * FINGERPRINT_LEN]
+    }
+
+    /// Signature over `signed_bytes` made with `public_key`
+    pub fn signature(
+        &self,
+        public_key: &[u8],
+    ) -> Result<&'a [u8], VerificationError> {
+        let raw = &self.raw[FIXED_LEN + self.count * FINGERPRINT_LEN..];
+        self.sig_alg
+            .signature_len(public_key, raw)
+            .and_then(|x| raw.get(..x))
+            .ok_or(VerificationError::InvalidRevocationList("truncated"))
+    }
+
+    /// Whether `fingerprint` is listed
+    pub fn contains(&self, fingerprint: &[u8; FINGERPRINT_LEN]) -> bool {
+        self.raw[FIXED_LEN..FIXED_LEN + self.count * FINGERPRINT_LEN]
+            .chunks_exact(FINGERPRINT_LEN)
+            .any(|x| x == fingerprint)
+    }
+}
+
+impl fmt::Display for RevocationList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\tSIGNATURE ALGORITHM: {:?}\n\tKEY ID: {:02X?}\n\tSEQUENCE: \
+             {}\n\tREVOKED: {}",
+            self.sig_alg, self.key_id, self.sequence, self.count
+        )
+    }
+}
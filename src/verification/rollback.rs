@@ -0,0 +1,196 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Anti-rollback floors.
+//!
+//! The lowest security version SentinelBoot will boot, and the lowest
+//! revocation update sequence it will accept, are kept in the board's
+//! persistent storage as a single record:
+//!
+//! | Offset | Size | Field                  |
+//! |--------|------|------------------------|
+//! | 0x00   | 8    | `magic`                |
+//! | 0x08   | 4    | `security_version`     |
+//! | 0x0C   | 4    | `!security_version`    |
+//! | 0x10   | 4    | `revocation_sequence`  |
+//! | 0x14   | 4    | `!revocation_sequence` |
+//! | 0x18   | 4    | `pending`              |
+//! | 0x1C   | 4    | `!pending`             |
+//! | 0x20   | 4    | `confirmed`            |
+//!
+//! Blank or corrupt storage, or a corrupt field, reads as a floor of 0. The
+//! floors only ever rise. The revocation sequence rises as soon as a signed
+//! update carrying a higher one has verified. The security version of an
+//! image about to be booted is only recorded as `pending`, with `confirmed`
+//! left erased. Once the booted OS is up it clears `confirmed` to 0, which
+//! flash can do without an erase, and the next run raises the floor to the
+//! pending version. A kernel that never comes up therefore leaves the floor
+//! where it was, and the last good version still boots.
+//!
+//! Nothing authenticates the record, it is only as safe as the storage
+//! holding it. SentinelBoot runs in S-mode so cannot fence the flash off with
+//! PMP, and QEMU's pflash ignores the CFI block lock, so the booted OS can
+//! rewrite or erase the record just as `tftp/confirm_boot.sh` clears
+//! `confirmed`. On QEMU the floors therefore only stop an old image being
+//! booted by accident, a rolled back kernel can lower them itself. A board
+//! relying on them needs storage it can lock before handing over, with the
+//! OS confirming through a write-once word in a separate block.
+
+use super::header::read_u32;
+use super::VerificationError;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Identifies a stored floor
+pub const MAGIC: [u8; 8] = *b"SNTLFLOR";
+/// Size of the stored record
+pub const RECORD_LEN: usize = 0x24;
+/// `confirmed` once the OS has confirmed the pending version booted
+pub const BOOT_CONFIRMED: u32 = 0;
+/// `confirmed` as SentinelBoot writes it, erased flash
+pub const BOOT_UNCONFIRMED: u32 = u32::MAX;
+
+/// Stored floors
+#[derive(Clone, Copy, Default)]
+pub struct Floors {
+    /// Lowest security version that may boot, including a confirmed pending
+    /// version
+    pub security_version: u32,
+    /// Lowest revocation update sequence that is accepted
+    pub revocation_sequence: u32,
+    /// Security version of the last boot awaiting confirmation, 0 if none
+    pub pending: u32,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+/// Reads a value stored alongside its complement, 0 if they disagree
+fn read_checked(record: &[u8], offset: usize) -> u32 {
+    let value = read_u32(record, offset);
+    if read_u32(record, offset + 4) != !value {
+        return 0;
+    }
+    value
+}
+
+fn write_checked(record: &mut [u8], offset: usize, value: u32) {
+    record[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    record[offset + 4..offset + 8].copy_from_slice(&(!value).to_le_bytes());
+}
+
+/// Replaces the stored record with `floors`
+fn store(
+    storage: &(dyn driver::interface::Storage + Sync),
+    floors: Floors,
+) -> Result<(), VerificationError> {
+    let mut record = [0; RECORD_LEN];
+    record[0x00..0x08].copy_from_slice(&MAGIC);
+    write_checked(&mut record, 0x08, floors.security_version);
+    write_checked(&mut record, 0x10, floors.revocation_sequence);
+    write_checked(&mut record, 0x18, floors.pending);
+    record[0x20..0x24].copy_from_slice(&BOOT_UNCONFIRMED.to_le_bytes());
+    storage.write(&record).map_err(VerificationError::Storage)?;
+
+    // Read back so a failed write is not mistaken for a raised floor
+    match load()? {
+        Some(x)
+            if x.security_version == floors.security_version
+                && x.revocation_sequence == floors.revocation_sequence
+                && x.pending == floors.pending =>
+        {
+            Ok(())
+        }
+        _ => Err(VerificationError::Storage("floor did not persist")),
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Reads the stored floors, `None` if the board has no persistent storage
+pub fn load() -> Result<Option<Floors>, VerificationError> {
+    let storage = match driver::storage() {
+        Some(x) => x,
+        None => return Ok(None),
+    };
+    let mut record = [0; RECORD_LEN];
+    storage
+        .read(&mut record)
+        .map_err(VerificationError::Storage)?;
+
+    if record[0x00..0x08] != MAGIC {
+        return Ok(Some(Floors::default()));
+    }
+    let mut floors = Floors {
+        security_version: read_checked(&record, 0x08),
+        revocation_sequence: read_checked(&record, 0x10),
+        pending: read_checked(&record, 0x18),
+    };
+    // The OS confirmed the last boot, its version is the floor from now on
+    if read_u32(&record, 0x20) == BOOT_CONFIRMED {
+        floors.security_version = floors.security_version.max(floors.pending);
+        floors.pending = 0;
+    }
+    Ok(Some(floors))
+}
+
+/// Rejects `version` if it is below the stored floor
+pub fn check(version: u32) -> Result<(), VerificationError> {
+    match load()? {
+        Some(x) if version < x.security_version => {
+            Err(VerificationError::Rollback(version, x.security_version))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Records `version` as pending, the floor is raised to it on the first run
+/// after the OS confirms it booted
+pub fn stage(version: u32) -> Result<(), VerificationError> {
+    match (driver::storage(), load()?) {
+        (Some(storage), Some(x))
+            if version > x.security_version && version != x.pending =>
+        {
+            store(
+                storage,
+                Floors {
+                    pending: version,
+                    ..x
+                },
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Rejects a revocation update `sequence` below the stored floor, an absent
+/// update counting as sequence 0
+pub fn check_revocation(sequence: u32) -> Result<(), VerificationError> {
+    match load()? {
+        Some(x) if sequence < x.revocation_sequence => {
+            Err(VerificationError::RevocationRollback(
+                sequence,
+                x.revocation_sequence,
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Raises the stored revocation floor to `sequence`, it is never lowered
+pub fn raise_revocation(sequence: u32) -> Result<(), VerificationError> {
+    match (driver::storage(), load()?) {
+        (Some(storage), Some(x)) if sequence > x.revocation_sequence => store(
+            storage,
+            Floors {
+                revocation_sequence: sequence,
+                ..x
+            },
+        ),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! SHA-256 with the RISC-V scalar cryptography extension.
+//!
+//! Zknh only provides the four sigma functions of FIPS 180-4, each replacing
+//! three rotates or shifts and two exclusive ors, so the rounds themselves
+//! are plain Rust around them. `Sha256` is a drop in replacement for that of
+//! `sha2` for harts with Zknh but no vector unit.
+
+use super::vector::SHA256_ROUND_CONSTANTS;
+use core::arch::asm;
+use sha2::digest::{
+    block_buffer::Eager,
+    core_api::{
+        Block,
+        BlockSizeUser,
+        Buffer,
+        BufferKindUser,
+        CoreWrapper,
+        CtVariableCoreWrapper,
+        OutputSizeUser,
+        TruncSide,
+        UpdateCore,
+        VariableOutputCore,
+    },
+    typenum::{U32, U64},
+    HashMarker,
+    InvalidOutputSize,
+    Output,
+};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// SHA-256 using Zknh
+pub type Sha256 = CoreWrapper<CtVariableCoreWrapper<Sha256ScalarCore, U32>>;
+
+/// Block level SHA-256 state for `compress`
+#[derive(Clone)]
+pub struct Sha256ScalarCore {
+    state: [u32; 8],
+    block_len: u64,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// SHA256 initial hash, A to H
+const SHA256_INITIAL_HASH: [u32; 8] = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, // A-D
+    0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19, // E-H
+];
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+// ? As with `vector` the Zknh instructions are pre-assembled by
+// ? src/assemble.py and the comments give the assembled instruction. On
+// ? RV64 they read the low 32 bits of the source and sign extend the result.
+
+/// Σ0 of FIPS 180-4
+#[inline(always)]
+fn sum0(x: u32) -> u32 {
+    let y: usize;
+    unsafe {
+        asm!(
+            ".word 0x10051513", // sha256sum0 a0 a0
+            inlateout("a0") x as usize => y,
+            options(pure, nomem, nostack),
+        );
+    }
+    y as u32
+}
+
+/// Σ1 of FIPS 180-4
+#[inline(always)]
+fn sum1(x: u32) -> u32 {
+    let y: usize;
+    unsafe {
+        asm!(
+            ".word 0x10151513", // sha256sum1 a0 a0
+            inlateout("a0") x as usize => y,
+            options(pure, nomem, nostack),
+        );
+    }
+    y as u32
+}
+
+/// σ0 of FIPS 180-4
+#[inline(always)]
+fn sig0(x: u32) -> u32 {
+    let y: usize;
+    unsafe {
+        asm!(
+            ".word 0x10251513", // sha256sig0 a0 a0
+            inlateout("a0") x as usize => y,
+            options(pure, nomem, nostack),
+        );
+    }
+    y as u32
+}
+
+/// σ1 of FIPS 180-4
+#[inline(always)]
+fn sig1(x: u32) -> u32 {
+    let y: usize;
+    unsafe {
+        asm!(
+            ".word 0x10351513", // sha256sig1 a0 a0
+            inlateout("a0") x as usize => y,
+            options(pure, nomem, nostack),
+        );
+    }
+    y as u32
+}
+
+/// Compresses one 64 byte block into `state`
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut w = [0u32; 64];
+    for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for t in 16..64 {
+        w[t] = sig1(w[t - 2])
+            .wrapping_add(w[t - 7])
+            .wrapping_add(sig0(w[t - 15]))
+            .wrapping_add(w[t - 16]);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (k, w) in SHA256_ROUND_CONSTANTS.iter().zip(w) {
+        let t1 = h
+            .wrapping_add(sum1(e))
+            .wrapping_add((e & f) ^ (!e & g))
+            .wrapping_add(*k)
+            .wrapping_add(w);
+        let t2 = sum0(a).wrapping_add((a & b) ^ (a & c) ^ (b & c));
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+
+    for (x, y) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *x = x.wrapping_add(y);
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl HashMarker for Sha256ScalarCore {}
+
+impl BlockSizeUser for Sha256ScalarCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for Sha256ScalarCore {
+    type BufferKind = Eager;
+}
+
+impl UpdateCore for Sha256ScalarCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.block_len += blocks.len() as u64;
+        for block in blocks {
+            compress(&mut self.state, block);
+        }
+    }
+}
+
+impl OutputSizeUser for Sha256ScalarCore {
+    type OutputSize = U32;
+}
+
+impl VariableOutputCore for Sha256ScalarCore {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size != 32 {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Sha256ScalarCore {
+            state: SHA256_INITIAL_HASH,
+            block_len: 0,
+        })
+    }
+
+    fn finalize_variable_core(
+        &mut self,
+        buffer: &mut Buffer<Self>,
+        out: &mut Output<Self>,
+    ) {
+        let bit_len = 8 * (buffer.get_pos() as u64 + 64 * self.block_len);
+        buffer.len64_padding_be(bit_len, |block| {
+            self.update_blocks(core::slice::from_ref(block))
+        });
+
+        for (chunk, x) in out.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&x.to_be_bytes());
+        }
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! Power-on known answer tests.
+//!
+//! In the manner FIPS 140 asks of a cryptographic module, every digest is
+//! run over published vectors through the backend `backend::select` chose,
+//! and the Ed25519 verifier must accept an RFC 8032 signature and reject it
+//! once altered, before `verify_kernel` is trusted with the image. AES-GCM
+//! must likewise decrypt the GCM specification's vectors and reject them
+//! under an altered tag. A wrong digest would otherwise only show up as a
+//! kernel that never verifies, or worse one that verifies when it should not.
+
+use super::backend::{self, Sha256, Sha384, Sha512, Sm3};
+use core::fmt;
+use sha2::Digest;
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Known answer test that failed
+#[derive(Debug)]
+pub enum SelfTestError {
+    /// Digest, named, gave the wrong answer with the selected backend
+    Digest(&'static str, backend::Backend),
+    /// Ed25519 verifier accepted a bad signature or rejected a good one
+    #[cfg_attr(not(feature = "ed25519"), allow(dead_code))]
+    Ed25519,
+    /// Cipher, named, decrypted wrongly or accepted an altered tag with the
+    /// selected backend
+    #[cfg_attr(not(feature = "aes-gcm"), allow(dead_code))]
+    Cipher(&'static str, backend::Backend),
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// FIPS 180-4 and GB/T 32905-2016 example messages
+const ABC: &[u8] = b"abc";
+/// Two block SHA-256 message, the padding does not fit after it
+const ABCDBCDE: &[u8] =
+    b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq";
+
+const SHA256_ABC: [u8; 32] = [
+    0xBA, 0x78, 0x16, 0xBF, 0x8F, 0x01, 0xCF, 0xEA, 0x41, 0x41, 0x40, 0xDE,
+    0x5D, 0xAE, 0x22, 0x23, 0xB0, 0x03, 0x61, 0xA3, 0x96, 0x17, 0x7A, 0x9C,
+    0xB4, 0x10, 0xFF, 0x61, 0xF2, 0x00, 0x15, 0xAD,
+];
+
+const SHA256_ABCDBCDE: [u8; 32] = [
+    0x24, 0x8D, 0x6A, 0x61, 0xD2, 0x06, 0x38, 0xB8, 0xE5, 0xC0, 0x26, 0x93,
+    0x0C, 0x3E, 0x60, 0x39, 0xA3, 0x3C, 0xE4, 0x59, 0x64, 0xFF, 0x21, 0x67,
+    0xF6, 0xEC, 0xED, 0xD4, 0x19, 0xDB, 0x06, 0xC1,
+];
+
+const SHA384_ABC: [u8; 48] = [
+    0xCB, 0x00, 0x75, 0x3F, 0x45, 0xA3, 0x5E, 0x8B, 0xB5, 0xA0, 0x3D, 0x69,
+    0x9A, 0xC6, 0x50, 0x07, 0x27, 0x2C, 0x32, 0xAB, 0x0E, 0xDE, 0xD1, 0x63,
+    0x1A, 0x8B, 0x60, 0x5A, 0x43, 0xFF, 0x5B, 0xED, 0x80, 0x86, 0x07, 0x2B,
+    0xA1, 0xE7, 0xCC, 0x23, 0x58, 0xBA, 0xEC, 0xA1, 0x34, 0xC8, 0x25, 0xA7,
+];
+
+const SHA512_ABC: [u8; 64] = [
+    0xDD, 0xAF, 0x35, 0xA1, 0x93, 0x61, 0x7A, 0xBA, 0xCC, 0x41, 0x73, 0x49,
+    0xAE, 0x20, 0x41, 0x31, 0x12, 0xE6, 0xFA, 0x4E, 0x89, 0xA9, 0x7E, 0xA2,
+    0x0A, 0x9E, 0xEE, 0xE6, 0x4B, 0x55, 0xD3, 0x9A, 0x21, 0x92, 0x99, 0x2A,
+    0x27, 0x4F, 0xC1, 0xA8, 0x36, 0xBA, 0x3C, 0x23, 0xA3, 0xFE, 0xEB, 0xBD,
+    0x45, 0x4D, 0x44, 0x23, 0x64, 0x3C, 0xE8, 0x0E, 0x2A, 0x9A, 0xC9, 0x4F,
+    0xA5, 0x4C, 0xA4, 0x9F,
+];
+
+const SM3_ABC: [u8; 32] = [
+    0x66, 0xC7, 0xF0, 0xF4, 0x62, 0xEE, 0xED, 0xD9, 0xD1, 0xF2, 0xD4, 0x6B,
+    0xDC, 0x10, 0xE4, 0xE2, 0x41, 0x67, 0xC4, 0x87, 0x5C, 0xF2, 0xF7, 0xA2,
+    0x29, 0x7D, 0xA0, 0x2B, 0x8F, 0x4B, 0xA8, 0xE0,
+];
+
+/// RFC 8032 section 7.1 test 2
+#[cfg(feature = "ed25519")]
+const ED25519_PUBLIC_KEY: [u8; 32] = [
+    0x3D, 0x40, 0x17, 0xC3, 0xE8, 0x43, 0x89, 0x5A, 0x92, 0xB7, 0x0A, 0xA7,
+    0x4D, 0x1B, 0x7E, 0xBC, 0x9C, 0x98, 0x2C, 0xCF, 0x2E, 0xC4, 0x96, 0x8C,
+    0xC0, 0xCD, 0x55, 0xF1, 0x2A, 0xF4, 0x66, 0x0C,
+];
+
+#[cfg(feature = "ed25519")]
+const ED25519_MESSAGE: [u8; 1] = [0x72];
+
+#[cfg(feature = "ed25519")]
+const ED25519_SIGNATURE: [u8; 64] = [
+    0x92, 0xA0, 0x09, 0xA9, 0xF0, 0xD4, 0xCA, 0xB8, 0x72, 0x0E, 0x82, 0x0B,
+    0x5F, 0x64, 0x25, 0x40, 0xA2, 0xB2, 0x7B, 0x54, 0x16, 0x50, 0x3F, 0x8F,
+    0xB3, 0x76, 0x22, 0x23, 0xEB, 0xDB, 0x69, 0xDA, 0x08, 0x5A, 0xC1, 0xE4,
+    0x3E, 0x15, 0x99, 0x6E, 0x45, 0x8F, 0x36, 0x13, 0xD0, 0xF1, 0x1D, 0x8C,
+    0x38, 0x7B, 0x2E, 0xAE, 0xB4, 0x30, 0x2A, 0xEE, 0xB0, 0x0D, 0x29, 0x16,
+    0x12, 0xBB, 0x0C, 0x00,
+];
+
+/// McGrew and Viega GCM test cases 4 and 16, AES-128 takes the first 16
+/// bytes of the key
+#[cfg(feature = "aes-gcm")]
+const GCM_KEY: [u8; 32] = [
+    0xFE, 0xFF, 0xE9, 0x92, 0x86, 0x65, 0x73, 0x1C, 0x6D, 0x6A, 0x8F, 0x94,
+    0x67, 0x30, 0x83, 0x08, 0xFE, 0xFF, 0xE9, 0x92, 0x86, 0x65, 0x73, 0x1C,
+    0x6D, 0x6A, 0x8F, 0x94, 0x67, 0x30, 0x83, 0x08,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM_NONCE: [u8; 12] = [
+    0xCA, 0xFE, 0xBA, 0xBE, 0xFA, 0xCE, 0xDB, 0xAD, 0xDE, 0xCA, 0xF8, 0x88,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM_AAD: [u8; 20] = [
+    0xFE, 0xED, 0xFA, 0xCE, 0xDE, 0xAD, 0xBE, 0xEF, 0xFE, 0xED, 0xFA, 0xCE,
+    0xDE, 0xAD, 0xBE, 0xEF, 0xAB, 0xAD, 0xDA, 0xD2,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM_PLAINTEXT: [u8; 60] = [
+    0xD9, 0x31, 0x32, 0x25, 0xF8, 0x84, 0x06, 0xE5, 0xA5, 0x59, 0x09, 0xC5,
+    0xAF, 0xF5, 0x26, 0x9A, 0x86, 0xA7, 0xA9, 0x53, 0x15, 0x34, 0xF7, 0xDA,
+    0x2E, 0x4C, 0x30, 0x3D, 0x8A, 0x31, 0x8A, 0x72, 0x1C, 0x3C, 0x0C, 0x95,
+    0x95, 0x68, 0x09, 0x53, 0x2F, 0xCF, 0x0E, 0x24, 0x49, 0xA6, 0xB5, 0x25,
+    0xB1, 0x6A, 0xED, 0xF5, 0xAA, 0x0D, 0xE6, 0x57, 0xBA, 0x63, 0x7B, 0x39,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM128_CIPHERTEXT: [u8; 60] = [
+    0x42, 0x83, 0x1E, 0xC2, 0x21, 0x77, 0x74, 0x24, 0x4B, 0x72, 0x21, 0xB7,
+    0x84, 0xD0, 0xD4, 0x9C, 0xE3, 0xAA, 0x21, 0x2F, 0x2C, 0x02, 0xA4, 0xE0,
+    0x35, 0xC1, 0x7E, 0x23, 0x29, 0xAC, 0xA1, 0x2E, 0x21, 0xD5, 0x14, 0xB2,
+    0x54, 0x66, 0x93, 0x1C, 0x7D, 0x8F, 0x6A, 0x5A, 0xAC, 0x84, 0xAA, 0x05,
+    0x1B, 0xA3, 0x0B, 0x39, 0x6A, 0x0A, 0xAC, 0x97, 0x3D, 0x58, 0xE0, 0x91,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM128_TAG: [u8; 16] = [
+    0x5B, 0xC9, 0x4F, 0xBC, 0x32, 0x21, 0xA5, 0xDB, 0x94, 0xFA, 0xE9, 0x5A,
+    0xE7, 0x12, 0x1A, 0x47,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM256_CIPHERTEXT: [u8; 60] = [
+    0x52, 0x2D, 0xC1, 0xF0, 0x99, 0x56, 0x7D, 0x07, 0xF4, 0x7F, 0x37, 0xA3,
+    0x2A, 0x84, 0x42, 0x7D, 0x64, 0x3A, 0x8C, 0xDC, 0xBF, 0xE5, 0xC0, 0xC9,
+    0x75, 0x98, 0xA2, 0xBD, 0x25, 0x55, 0xD1, 0xAA, 0x8C, 0xB0, 0x8E, 0x48,
+    0x59, 0x0D, 0xBB, 0x3D, 0xA7, 0xB0, 0x8B, 0x10, 0x56, 0x82, 0x88, 0x38,
+    0xC5, 0xF6, 0x1E, 0x63, 0x93, 0xBA, 0x7A, 0x0A, 0xBC, 0xC9, 0xF6, 0x62,
+];
+
+#[cfg(feature = "aes-gcm")]
+const GCM256_TAG: [u8; 16] = [
+    0x76, 0xFC, 0x6E, 0xCE, 0x0F, 0x4E, 0x17, 0x68, 0xCD, 0xDF, 0x88, 0x53,
+    0xBB, 0x2D, 0x55, 0x1B,
+];
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+/// Digests `message` whole and a byte at a time with `D`, both must give
+/// `expected`
+fn check_digest<D: Digest>(message: &[u8], expected: &[u8]) -> bool {
+    let mut hasher = D::new();
+    for byte in message {
+        hasher.update([*byte]);
+    }
+    D::digest(message)[..] == *expected && hasher.finalize()[..] == *expected
+}
+
+#[cfg(feature = "ed25519")]
+fn check_ed25519() -> Result<(), SelfTestError> {
+    let mut forged = ED25519_SIGNATURE;
+    forged[0] ^= 1;
+    let good = super::verify_ed25519(
+        &ED25519_PUBLIC_KEY,
+        &ED25519_MESSAGE,
+        &ED25519_SIGNATURE,
+    );
+    let bad =
+        super::verify_ed25519(&ED25519_PUBLIC_KEY, &ED25519_MESSAGE, &forged);
+    if good && !bad {
+        Ok(())
+    } else {
+        Err(SelfTestError::Ed25519)
+    }
+}
+
+/// Decrypts `ciphertext` with `key` to `GCM_PLAINTEXT`, and must refuse to
+/// once the tag is altered
+#[cfg(feature = "aes-gcm")]
+fn check_aes_gcm(key: &[u8], ciphertext: &[u8; 60], tag: &[u8; 16]) -> bool {
+    let mut buffer = *ciphertext;
+    let good =
+        backend::aes_gcm_decrypt(key, &GCM_NONCE, &GCM_AAD, &mut buffer, tag)
+            && buffer == GCM_PLAINTEXT;
+    let mut forged = *tag;
+    forged[15] ^= 1;
+    let mut buffer = *ciphertext;
+    let bad = backend::aes_gcm_decrypt(
+        key,
+        &GCM_NONCE,
+        &GCM_AAD,
+        &mut buffer,
+        &forged,
+    ) || buffer != *ciphertext;
+    good && !bad
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Runs every known answer test, stopping at the first failure
+pub fn run() -> Result<(), SelfTestError> {
+    let backends = backend::backends();
+    if !check_digest::<Sha256>(ABC, &SHA256_ABC)
+        || !check_digest::<Sha256>(ABCDBCDE, &SHA256_ABCDBCDE)
+    {
+        return Err(SelfTestError::Digest("SHA-256", backends.sha256));
+    }
+    if !check_digest::<Sha384>(ABC, &SHA384_ABC) {
+        return Err(SelfTestError::Digest("SHA-384", backends.sha512));
+    }
+    if !check_digest::<Sha512>(ABC, &SHA512_ABC) {
+        return Err(SelfTestError::Digest("SHA-512", backends.sha512));
+    }
+    if !check_digest::<Sm3>(ABC, &SM3_ABC) {
+        return Err(SelfTestError::Digest("SM3", backends.sm3));
+    }
+    #[cfg(feature = "ed25519")]
+    check_ed25519()?;
+    #[cfg(feature = "aes-gcm")]
+    if !check_aes_gcm(&GCM_KEY[..16], &GCM128_CIPHERTEXT, &GCM128_TAG) {
+        return Err(SelfTestError::Cipher("AES-128-GCM", backends.aes_gcm));
+    }
+    #[cfg(feature = "aes-gcm")]
+    if !check_aes_gcm(&GCM_KEY, &GCM256_CIPHERTEXT, &GCM256_TAG) {
+        return Err(SelfTestError::Cipher("AES-256-GCM", backends.aes_gcm));
+    }
+    Ok(())
+}
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelfTestError::Digest(name, backend) => {
+                write!(
+                    f,
+                    "{} known answer wrong with {} backend",
+                    name, backend
+                )
+            }
+            SelfTestError::Ed25519 => {
+                write!(f, "Ed25519 known answer signature check wrong")
+            }
+            SelfTestError::Cipher(name, backend) => {
+                write!(
+                    f,
+                    "{} known answer decryption wrong with {} backend",
+                    name, backend
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,991 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! SHA-2, SM3 and AES-GCM with the RISC-V vector cryptography extension.
+//!
+//! `asm_hash`, `asm_hash512` and `asm_sm3` are the Zvknha, Zvknhb and Zvksh
+//! compression functions, each consuming a single block. The cores below
+//! feed them from the block buffering and padding of the `digest` crate, so
+//! `Sha256`, `Sha384` and `Sha512` are drop in replacements for those of
+//! `sha2`, `Sm3` is one for that of `sm3`, and all hash any buffer passed in
+//! pieces of any length.
+//!
+//! The SHA-2 compression functions keep the working state in the order the
+//! instructions expect, {F, E, B, A} then {H, G, D, C}, which is only put
+//! back into A to H order when the digest is written out. The SM3 state is A
+//! to H with each word held big endian in memory, as the digest is.
+//!
+//! `AesGcm`, in `gcm`, decrypts with Zvkned and Zvkg.
+
+#[cfg(feature = "aes-gcm")]
+mod gcm;
+
+use core::arch::asm;
+use sha2::digest::{
+    block_buffer::Eager,
+    core_api::{
+        Block,
+        BlockSizeUser,
+        Buffer,
+        BufferKindUser,
+        CoreWrapper,
+        CtVariableCoreWrapper,
+        OutputSizeUser,
+        TruncSide,
+        UpdateCore,
+        VariableOutputCore,
+    },
+    typenum::{U128, U32, U48, U64},
+    HashMarker,
+    InvalidOutputSize,
+    Output,
+};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// SHA-256 using Zvknha
+pub type Sha256 = CoreWrapper<CtVariableCoreWrapper<Sha256VectorCore, U32>>;
+/// SHA-384 using Zvknhb
+pub type Sha384 = CoreWrapper<CtVariableCoreWrapper<Sha512VectorCore, U48>>;
+/// SHA-512 using Zvknhb
+pub type Sha512 = CoreWrapper<CtVariableCoreWrapper<Sha512VectorCore, U64>>;
+/// SM3 using Zvksh
+pub type Sm3 = CoreWrapper<CtVariableCoreWrapper<Sm3VectorCore, U32>>;
+#[cfg(feature = "aes-gcm")]
+pub use gcm::AesGcm;
+
+/// Block level SHA-256 state for `asm_hash`
+#[derive(Clone)]
+pub struct Sha256VectorCore {
+    state: [u32; 8],
+    block_len: u64,
+}
+
+/// Block level SHA-384 and SHA-512 state for `asm_hash512`
+#[derive(Clone)]
+pub struct Sha512VectorCore {
+    state: [u64; 8],
+    block_len: u128,
+}
+
+/// Block level SM3 state for `asm_sm3`
+#[derive(Clone)]
+pub struct Sm3VectorCore {
+    state: [u32; 8],
+    block_len: u64,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// SHA256 initial hash
+const SHA256_INITIAL_HASH: [u32; 8] = [
+    0x9B05688C, // F
+    0x510E527F, // E
+    0xBB67AE85, // B
+    0x6A09E667, // A
+    0x5BE0CD19, // H
+    0x1F83D9AB, // G
+    0xA54FF53A, // D
+    0x3C6EF372, // C
+];
+
+/// SHA384 initial hash
+const SHA384_INITIAL_HASH: [u64; 8] = [
+    0x8EB44A8768581511, // F
+    0x67332667FFC00B31, // E
+    0x629A292A367CD507, // B
+    0xCBBB9D5DC1059ED8, // A
+    0x47B5481DBEFA4FA4, // H
+    0xDB0C2E0D64F98FA7, // G
+    0x152FECD8F70E5939, // D
+    0x9159015A3070DD17, // C
+];
+
+/// SHA512 initial hash
+const SHA512_INITIAL_HASH: [u64; 8] = [
+    0x9B05688C2B3E6C1F, // F
+    0x510E527FADE682D1, // E
+    0xBB67AE8584CAA73B, // B
+    0x6A09E667F3BCC908, // A
+    0x5BE0CD19137E2179, // H
+    0x1F83D9ABFB41BD6B, // G
+    0xA54FF53A5F1D36F1, // D
+    0x3C6EF372FE94F82B, // C
+];
+
+/// SM3 initial hash, A to H
+const SM3_INITIAL_HASH: [u32; 8] = [
+    0x7380166F, 0x4914B2B9, 0x172442D7, 0xDA8A0600, // A-D
+    0xA96F30BC, 0x163138AA, 0xE38DEE4D, 0xB0FB0E4E, // E-H
+];
+
+/// Position of A to H in the working state
+const OUTPUT_ORDER: [usize; 8] = [3, 2, 7, 6, 1, 0, 5, 4];
+
+/// SHA256 round constants, shared with `scalar`
+pub(super) const SHA256_ROUND_CONSTANTS: [u32; 64] = [
+    0x428A2F98, 0x71374491, 0xB5C0FBCF, 0xE9B5DBA5, // 0-3
+    0x3956C25B, 0x59F111F1, 0x923F82A4, 0xAB1C5ED5, // 4-7
+    0xD807AA98, 0x12835B01, 0x243185BE, 0x550C7DC3, // 8-11
+    0x72BE5D74, 0x80DEB1FE, 0x9BDC06A7, 0xC19BF174, // 12-15
+    0xE49B69C1, 0xEFBE4786, 0x0FC19DC6, 0x240CA1CC, // 16-19
+    0x2DE92C6F, 0x4A7484AA, 0x5CB0A9DC, 0x76F988DA, // 20-23
+    0x983E5152, 0xA831C66D, 0xB00327C8, 0xBF597FC7, // 24-27
+    0xC6E00BF3, 0xD5A79147, 0x06CA6351, 0x14292967, // 28-31
+    0x27B70A85, 0x2E1B2138, 0x4D2C6DFC, 0x53380D13, // 32-35
+    0x650A7354, 0x766A0ABB, 0x81C2C92E, 0x92722C85, // 36-39
+    0xA2BFE8A1, 0xA81A664B, 0xC24B8B70, 0xC76C51A3, // 40-43
+    0xD192E819, 0xD6990624, 0xF40E3585, 0x106AA070, // 44-47
+    0x19A4C116, 0x1E376C08, 0x2748774C, 0x34B0BCB5, // 48-51
+    0x391C0CB3, 0x4ED8AA4A, 0x5B9CCA4F, 0x682E6FF3, // 52-55
+    0x748F82EE, 0x78A5636F, 0x84C87814, 0x8CC70208, // 56-59
+    0x90BEFFFA, 0xA4506CEB, 0xBEF9A3F7, 0xC67178F2, // 60-63
+];
+
+#[rustfmt::skip]
+const SHA512_ROUND_CONSTANTS: [u64; 80] = [
+    0x428A2F98D728AE22, 0x7137449123EF65CD, // 0-1
+    0xB5C0FBCFEC4D3B2F, 0xE9B5DBA58189DBBC, // 2-3
+    0x3956C25BF348B538, 0x59F111F1B605D019, // 4-5
+    0x923F82A4AF194F9B, 0xAB1C5ED5DA6D8118, // 6-7
+    0xD807AA98A3030242, 0x12835B0145706FBE, // 8-9
+    0x243185BE4EE4B28C, 0x550C7DC3D5FFB4E2, // 10-11
+    0x72BE5D74F27B896F, 0x80DEB1FE3B1696B1, // 12-13
+    0x9BDC06A725C71235, 0xC19BF174CF692694, // 14-15
+    0xE49B69C19EF14AD2, 0xEFBE4786384F25E3, // 16-17
+    0x0FC19DC68B8CD5B5, 0x240CA1CC77AC9C65, // 18-19
+    0x2DE92C6F592B0275, 0x4A7484AA6EA6E483, // 20-21
+    0x5CB0A9DCBD41FBD4, 0x76F988DA831153B5, // 22-23
+    0x983E5152EE66DFAB, 0xA831C66D2DB43210, // 24-25
+    0xB00327C898FB213F, 0xBF597FC7BEEF0EE4, // 26-27
+    0xC6E00BF33DA88FC2, 0xD5A79147930AA725, // 28-29
+    0x06CA6351E003826F, 0x142929670A0E6E70, // 30-31
+    0x27B70A8546D22FFC, 0x2E1B21385C26C926, // 32-33
+    0x4D2C6DFC5AC42AED, 0x53380D139D95B3DF, // 34-35
+    0x650A73548BAF63DE, 0x766A0ABB3C77B2A8, // 36-37
+    0x81C2C92E47EDAEE6, 0x92722C851482353B, // 38-39
+    0xA2BFE8A14CF10364, 0xA81A664BBC423001, // 40-41
+    0xC24B8B70D0F89791, 0xC76C51A30654BE30, // 42-43
+    0xD192E819D6EF5218, 0xD69906245565A910, // 44-45
+    0xF40E35855771202A, 0x106AA07032BBD1B8, // 46-47
+    0x19A4C116B8D2D0C8, 0x1E376C085141AB53, // 48-49
+    0x2748774CDF8EEB99, 0x34B0BCB5E19B48A8, // 50-51
+    0x391C0CB3C5C95A63, 0x4ED8AA4AE3418ACB, // 52-53
+    0x5B9CCA4F7763E373, 0x682E6FF3D6B2B8A3, // 54-55
+    0x748F82EE5DEFB2FC, 0x78A5636F43172F60, // 56-57
+    0x84C87814A1F0AB72, 0x8CC702081A6439EC, // 58-59
+    0x90BEFFFA23631E28, 0xA4506CEBDE82BDE9, // 60-61
+    0xBEF9A3F7B2C67915, 0xC67178F2E372532B, // 62-63
+    0xCA273ECEEA26619C, 0xD186B8C721C0C207, // 64-65
+    0xEADA7DD6CDE0EB1E, 0xF57D4F7FEE6ED178, // 66-67
+    0x06F067AA72176FBA, 0x0A637DC5A2C898A6, // 68-69
+    0x113F9804BEF90DAE, 0x1B710B35131C471B, // 70-71
+    0x28DB77F523047D84, 0x32CAAB7B40C72493, // 72-73
+    0x3C9EBE0A15C9BEBC, 0x431D67C49C100D4C, // 74-75
+    0x4CC5D4BECB3E42B6, 0x597F299CFC657E2A, // 76-77
+    0x5FCB6FAB3AD6FAEC, 0x6C44198C4A475817, // 78-79
+];
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+#[inline(never)]
+fn asm_hash(a0: *mut usize, a1: *mut usize, a2: *mut usize) {
+    // Only unsafe as assembly code the assembly is an implementation of SHA256
+    // as per RISC-V Crypto
+
+    // Reference: RISC-V Cryptographic Extensions Vector Code Sample
+    // The code sample is part of the riscv-crypto project on GitHub
+    // commit 6589bcd6edb5abd91e758a67b28ae05b347c0470.
+    // See: https://github.com/riscv/riscv-crypto/blob/
+    //  6589bcd6edb5abd91e758a67b28ae05b347c0470/doc/vector/code-samples/zvknh.s
+
+    // ? Rust's RISC-V targets do not support vector operations nor vector
+    // ? cryptography operations as such the instructions are pre-assembled
+    // ? into their binary equivalents and hardcoded the comments represent
+    // ? the assembled instruction this solely bypasses the assembler and does
+    // ? not further negate security guarantees.
+
+    unsafe {
+        asm!(
+            /* ------------------------- Sanity ------------------------- */
+            "addi a3, a3, 1",
+
+            /* ------------------------- Setup -------------------------- */
+            // Set vector configuration
+            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
+
+            // Load 512 bits of the message block into v10-v13 endian swaping
+            ".word 0x0205e507", // vle32.v v10,(a1)
+            ".word 0x4aa4a557", // vrev8.v v10 v10
+            "add a1, a1, 16",
+            ".word 0x0205e587", // vle32.v v11,(a1)
+            ".word 0x4ab4a5d7", // vrev8.v v11 v11
+            "add a1, a1, 16",
+            ".word 0x0205e607", // vle32.v v12,(a1)
+            ".word 0x4ac4a657", // vrev8.v v12 v12
+            "add a1, a1, 16",
+            ".word 0x0205e687", // vle32.v v13,(a1)
+            ".word 0x4ad4a6d7", // vrev8.v v13 v13
+
+            /* ---------------------- Round loop ------------------------ */
+            // Load H[0..8]
+            // v26 = v16 = {a[t],b[t],e[t],f[t]}
+            // v27 = v17 = {c[t],d[t],g[t],h[t]}
+            ".word 0x02056807", // vle32.v v16,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056887", // vle32.v v17,(a0)
+
+            // Capture initial H to allow computing H'
+            ".word 0x5e080d57", // vmv.v.v v26,v16
+            ".word 0x5e088dd7", // vmv.v.v v27,v17
+
+            // Set v0 for vmerge that replaces first word
+            // v0.mask[i] = (i == 0 ? 1 : 0)
+            ".word 0x5208a057", // vid.v v0
+            ".word 0x62003057", // vmseq.vi v0,v0,0
+
+            /* ----------------------- Quad rounds ---------------------- */
+            // Round 0
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 1
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 2
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 3
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 4
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 5
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 6
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 7
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 8
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 9
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 10
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 11
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 12
+            // We no longer generate new message schedules
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            // Round 13
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            // Round 14
+            ".word 0x02066787", // vle32.v v15,(a2)
+            "addi a2, a2, 16",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            // Round 15
+            // a2 increment not needed
+            ".word 0x02066787", // vle32.v v15,(a2)
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            /* ----------------------- Update hash ---------------------- */
+            ".word 0x03a80857", // vadd.vv v16, v26, v16
+            ".word 0x03b888d7", // vadd.vv v17, v27, v17
+
+            /* ------------------------ Save hash ----------------------- */
+            ".word 0x020568a7", // vse32.v v17,(a0)
+            "addi a0, a0, -16",
+            ".word 0x02056827", // vse32.v v16,(a0)
+            in("a0") a0,
+            in("a1") a1,
+            in("a2") a2,
+        );
+    }
+}
+
+#[inline(never)]
+fn asm_hash512(a0: *mut usize, a1: *mut usize, a2: *mut usize) {
+    // Only unsafe as assembly code the assembly is an implementation of SHA512
+    // as per RISC-V Crypto, SHA384 differs only in initial hash and length
+
+    // The structure follows `asm_hash` with 64 bit words (Zvknhb), each
+    // element group is 256 bits so there are 20 quad rounds of which the
+    // first 16 generate the message schedule.
+
+    // ? See `asm_hash` for why the instructions are pre-assembled, the
+    // ? vector crypto instructions are encoded with assemble.py.
+
+    unsafe {
+        asm!(
+            /* ------------------------- Setup -------------------------- */
+            // Set vector configuration, four 64 bit words needs VLEN >= 256
+            ".word 0xcd827057", // vsetivli zero,4,e64,m1,ta,ma
+
+            // Load 1024 bits of the message block into v10-v13 endian swaping
+            ".word 0x0205f507", // vle64.v v10,(a1)
+            ".word 0x4aa4a557", // vrev8.v v10 v10
+            "add a1, a1, 32",
+            ".word 0x0205f587", // vle64.v v11,(a1)
+            ".word 0x4ab4a5d7", // vrev8.v v11 v11
+            "add a1, a1, 32",
+            ".word 0x0205f607", // vle64.v v12,(a1)
+            ".word 0x4ac4a657", // vrev8.v v12 v12
+            "add a1, a1, 32",
+            ".word 0x0205f687", // vle64.v v13,(a1)
+            ".word 0x4ad4a6d7", // vrev8.v v13 v13
+
+            /* ---------------------- Round loop ------------------------ */
+            // Load H[0..8]
+            // v26 = v16 = {a[t],b[t],e[t],f[t]}
+            // v27 = v17 = {c[t],d[t],g[t],h[t]}
+            ".word 0x02057807", // vle64.v v16,(a0)
+            "addi a0, a0, 32",
+            ".word 0x02057887", // vle64.v v17,(a0)
+
+            // Capture initial H to allow computing H'
+            ".word 0x5e080d57", // vmv.v.v v26,v16
+            ".word 0x5e088dd7", // vmv.v.v v27,v17
+
+            // Set v0 for vmerge that replaces first word
+            // v0.mask[i] = (i == 0 ? 1 : 0)
+            ".word 0x5208a057", // vid.v v0
+            ".word 0x62003057", // vmseq.vi v0,v0,0
+
+            /* ----------------------- Quad rounds ---------------------- */
+            // Round 0
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 1
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 2
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 3
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 4
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 5
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 6
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 7
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 8
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 9
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 10
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 11
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 12
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cc58757", // vmerge.vvm v14,v12,v11,v0
+            ".word 0xb6e6a577", // vsha2ms.vv v10 v14 v13
+
+            // Round 13
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cd60757", // vmerge.vvm v14,v13,v12,v0
+            ".word 0xb6e525f7", // vsha2ms.vv v11 v14 v10
+
+            // Round 14
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5ca68757", // vmerge.vvm v14,v10,v13,v0
+            ".word 0xb6e5a677", // vsha2ms.vv v12 v14 v11
+
+            // Round 15
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+            ".word 0x5cb50757", // vmerge.vvm v14,v11,v10,v0
+            ".word 0xb6e626f7", // vsha2ms.vv v13 v14 v12
+
+            // Round 16
+            // We no longer generate new message schedules
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f50757", // vadd.vv v14,v15,v10
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            // Round 17
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f58757", // vadd.vv v14,v15,v11
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            // Round 18
+            ".word 0x02067787", // vle64.v v15,(a2)
+            "addi a2, a2, 32",
+            ".word 0x02f60757", // vadd.vv v14,v15,v12
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            // Round 19
+            // a2 increment not needed
+            ".word 0x02067787", // vle64.v v15,(a2)
+            ".word 0x02f68757", // vadd.vv v14,v15,v13
+            ".word 0xbf0728f7", // vsha2cl.vv v17 v16 v14
+            ".word 0xbb172877", // vsha2ch.vv v16 v17 v14
+
+            /* ----------------------- Update hash ---------------------- */
+            ".word 0x03a80857", // vadd.vv v16, v26, v16
+            ".word 0x03b888d7", // vadd.vv v17, v27, v17
+
+            /* ------------------------ Save hash ----------------------- */
+            ".word 0x020578a7", // vse64.v v17,(a0)
+            "addi a0, a0, -32",
+            ".word 0x02057827", // vse64.v v16,(a0)
+            inout("a0") a0 => _,
+            inout("a1") a1 => _,
+            inout("a2") a2 => _,
+        );
+    }
+}
+
+#[inline(never)]
+fn asm_sm3(a0: *mut usize, a1: *mut usize) {
+    // Only unsafe as assembly code the assembly is an implementation of SM3
+    // as per RISC-V Crypto (Zvksh)
+
+    // Each vsm3c performs two rounds on the state {A, ..., H} in v2 using
+    // W[j..j + 8] of which it reads W[j], W[j + 1], W[j + 4] and W[j + 5],
+    // the slides build that window from the two vsm3me outputs in flight.
+    // The immediate is the round pair so the rounds are fully unrolled.
+
+    // ? See `asm_hash` for why the instructions are pre-assembled, the
+    // ? vector crypto instructions are encoded with assemble.py.
+
+    unsafe {
+        asm!(
+            /* ------------------------- Setup -------------------------- */
+            // Set vector configuration, eight 32 bit words needs VLEN >= 256
+            ".word 0xcd047057", // vsetivli zero,8,e32,m1,ta,ma
+
+            // Load the state and keep it for the final exclusive or, Zvksh
+            // byte swaps the big endian words itself so neither needs vrev8
+            ".word 0x02056107", // vle32.v v2,(a0)
+            ".word 0x5e010257", // vmv.v.v v4,v2
+
+            // Load W[0..8] into v6 and W[8..16] into v8
+            ".word 0x0205e307", // vle32.v v6,(a1)
+            "addi a1, a1, 32",
+            ".word 0x0205e407", // vle32.v v8,(a1)
+
+            /* ----------------------- Rounds 0-7 ----------------------- */
+            // v6 = W[0..8], v8 = W[8..16], v12 = W[j..j + 8]
+            ".word 0xae602177", // vsm3c.vi v2 v6 0
+            ".word 0x3e613657", // vslidedown.vi v12,v6,2
+            ".word 0x3a833657", // vslideup.vi v12,v8,6
+            ".word 0xaec0a177", // vsm3c.vi v2 v12 1
+            ".word 0x3e623657", // vslidedown.vi v12,v6,4
+            ".word 0x3a823657", // vslideup.vi v12,v8,4
+            ".word 0xaec12177", // vsm3c.vi v2 v12 2
+            ".word 0x3e633657", // vslidedown.vi v12,v6,6
+            ".word 0x3a813657", // vslideup.vi v12,v8,2
+            ".word 0xaec1a177", // vsm3c.vi v2 v12 3
+            // W[16..24]
+            ".word 0x82832577", // vsm3me.vv v10 v8 v6
+
+            /* ---------------------- Rounds 8-15 ----------------------- */
+            // v8 = W[8..16], v10 = W[16..24], v12 = W[j..j + 8]
+            ".word 0xae822177", // vsm3c.vi v2 v8 4
+            ".word 0x3e813657", // vslidedown.vi v12,v8,2
+            ".word 0x3aa33657", // vslideup.vi v12,v10,6
+            ".word 0xaec2a177", // vsm3c.vi v2 v12 5
+            ".word 0x3e823657", // vslidedown.vi v12,v8,4
+            ".word 0x3aa23657", // vslideup.vi v12,v10,4
+            ".word 0xaec32177", // vsm3c.vi v2 v12 6
+            ".word 0x3e833657", // vslidedown.vi v12,v8,6
+            ".word 0x3aa13657", // vslideup.vi v12,v10,2
+            ".word 0xaec3a177", // vsm3c.vi v2 v12 7
+            // W[24..32]
+            ".word 0x82a42377", // vsm3me.vv v6 v10 v8
+
+            /* ---------------------- Rounds 16-23 ---------------------- */
+            // v10 = W[16..24], v6 = W[24..32], v12 = W[j..j + 8]
+            ".word 0xaea42177", // vsm3c.vi v2 v10 8
+            ".word 0x3ea13657", // vslidedown.vi v12,v10,2
+            ".word 0x3a633657", // vslideup.vi v12,v6,6
+            ".word 0xaec4a177", // vsm3c.vi v2 v12 9
+            ".word 0x3ea23657", // vslidedown.vi v12,v10,4
+            ".word 0x3a623657", // vslideup.vi v12,v6,4
+            ".word 0xaec52177", // vsm3c.vi v2 v12 10
+            ".word 0x3ea33657", // vslidedown.vi v12,v10,6
+            ".word 0x3a613657", // vslideup.vi v12,v6,2
+            ".word 0xaec5a177", // vsm3c.vi v2 v12 11
+            // W[32..40]
+            ".word 0x82652477", // vsm3me.vv v8 v6 v10
+
+            /* ---------------------- Rounds 24-31 ---------------------- */
+            // v6 = W[24..32], v8 = W[32..40], v12 = W[j..j + 8]
+            ".word 0xae662177", // vsm3c.vi v2 v6 12
+            ".word 0x3e613657", // vslidedown.vi v12,v6,2
+            ".word 0x3a833657", // vslideup.vi v12,v8,6
+            ".word 0xaec6a177", // vsm3c.vi v2 v12 13
+            ".word 0x3e623657", // vslidedown.vi v12,v6,4
+            ".word 0x3a823657", // vslideup.vi v12,v8,4
+            ".word 0xaec72177", // vsm3c.vi v2 v12 14
+            ".word 0x3e633657", // vslidedown.vi v12,v6,6
+            ".word 0x3a813657", // vslideup.vi v12,v8,2
+            ".word 0xaec7a177", // vsm3c.vi v2 v12 15
+            // W[40..48]
+            ".word 0x82832577", // vsm3me.vv v10 v8 v6
+
+            /* ---------------------- Rounds 32-39 ---------------------- */
+            // v8 = W[32..40], v10 = W[40..48], v12 = W[j..j + 8]
+            ".word 0xae882177", // vsm3c.vi v2 v8 16
+            ".word 0x3e813657", // vslidedown.vi v12,v8,2
+            ".word 0x3aa33657", // vslideup.vi v12,v10,6
+            ".word 0xaec8a177", // vsm3c.vi v2 v12 17
+            ".word 0x3e823657", // vslidedown.vi v12,v8,4
+            ".word 0x3aa23657", // vslideup.vi v12,v10,4
+            ".word 0xaec92177", // vsm3c.vi v2 v12 18
+            ".word 0x3e833657", // vslidedown.vi v12,v8,6
+            ".word 0x3aa13657", // vslideup.vi v12,v10,2
+            ".word 0xaec9a177", // vsm3c.vi v2 v12 19
+            // W[48..56]
+            ".word 0x82a42377", // vsm3me.vv v6 v10 v8
+
+            /* ---------------------- Rounds 40-47 ---------------------- */
+            // v10 = W[40..48], v6 = W[48..56], v12 = W[j..j + 8]
+            ".word 0xaeaa2177", // vsm3c.vi v2 v10 20
+            ".word 0x3ea13657", // vslidedown.vi v12,v10,2
+            ".word 0x3a633657", // vslideup.vi v12,v6,6
+            ".word 0xaecaa177", // vsm3c.vi v2 v12 21
+            ".word 0x3ea23657", // vslidedown.vi v12,v10,4
+            ".word 0x3a623657", // vslideup.vi v12,v6,4
+            ".word 0xaecb2177", // vsm3c.vi v2 v12 22
+            ".word 0x3ea33657", // vslidedown.vi v12,v10,6
+            ".word 0x3a613657", // vslideup.vi v12,v6,2
+            ".word 0xaecba177", // vsm3c.vi v2 v12 23
+            // W[56..64]
+            ".word 0x82652477", // vsm3me.vv v8 v6 v10
+
+            /* ---------------------- Rounds 48-55 ---------------------- */
+            // v6 = W[48..56], v8 = W[56..64], v12 = W[j..j + 8]
+            ".word 0xae6c2177", // vsm3c.vi v2 v6 24
+            ".word 0x3e613657", // vslidedown.vi v12,v6,2
+            ".word 0x3a833657", // vslideup.vi v12,v8,6
+            ".word 0xaecca177", // vsm3c.vi v2 v12 25
+            ".word 0x3e623657", // vslidedown.vi v12,v6,4
+            ".word 0x3a823657", // vslideup.vi v12,v8,4
+            ".word 0xaecd2177", // vsm3c.vi v2 v12 26
+            ".word 0x3e633657", // vslidedown.vi v12,v6,6
+            ".word 0x3a813657", // vslideup.vi v12,v8,2
+            ".word 0xaecda177", // vsm3c.vi v2 v12 27
+            // W[64..72]
+            ".word 0x82832577", // vsm3me.vv v10 v8 v6
+
+            /* ---------------------- Rounds 56-63 ---------------------- */
+            // v8 = W[56..64], v10 = W[64..72], v12 = W[j..j + 8]
+            ".word 0xae8e2177", // vsm3c.vi v2 v8 28
+            ".word 0x3e813657", // vslidedown.vi v12,v8,2
+            ".word 0x3aa33657", // vslideup.vi v12,v10,6
+            ".word 0xaecea177", // vsm3c.vi v2 v12 29
+            ".word 0x3e823657", // vslidedown.vi v12,v8,4
+            ".word 0x3aa23657", // vslideup.vi v12,v10,4
+            ".word 0xaecf2177", // vsm3c.vi v2 v12 30
+            ".word 0x3e833657", // vslidedown.vi v12,v8,6
+            ".word 0x3aa13657", // vslideup.vi v12,v10,2
+            ".word 0xaecfa177", // vsm3c.vi v2 v12 31
+
+            /* ------------------------- Output ------------------------- */
+            // V(i + 1) = ABCDEFGH ^ V(i)
+            ".word 0x2e220157", // vxor.vv v2,v2,v4
+            ".word 0x02056127", // vse32.v v2,(a0)
+            inout("a0") a0 => _,
+            inout("a1") a1 => _,
+        );
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+/// Whether one vector register holds the 256 bit element groups
+/// `asm_hash512` and `asm_sm3` work on, which needs VLEN >= 256 where V
+/// only promises 128
+pub fn egw256_supported() -> bool {
+    let vl: usize;
+    unsafe {
+        asm!(
+            ".word 0xcd8272d7", // vsetivli t0,4,e64,m1,ta,ma
+            out("t0") vl,
+        );
+    }
+    vl == 4
+}
+
+// ? Note the vector unit has to be enabled before any of these are used
+//      The boot code sets sstatus.VS (mstatus.VS from machine mode) and
+//      `cpu::isa::Extensions::detect` drops V when that did not stick, so
+//      `backend` only selects these on harts where they will not trap.
+
+impl HashMarker for Sha256VectorCore {}
+
+impl BlockSizeUser for Sha256VectorCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for Sha256VectorCore {
+    type BufferKind = Eager;
+}
+
+impl UpdateCore for Sha256VectorCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.block_len += blocks.len() as u64;
+        for block in blocks {
+            asm_hash(
+                self.state.as_mut_ptr() as *mut usize,
+                block.as_ptr() as *mut usize,
+                SHA256_ROUND_CONSTANTS.as_ptr() as *mut usize,
+            );
+        }
+    }
+}
+
+impl OutputSizeUser for Sha256VectorCore {
+    type OutputSize = U32;
+}
+
+impl VariableOutputCore for Sha256VectorCore {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size != 32 {
+            return Err(InvalidOutputSize);
+        }
+        Ok(Sha256VectorCore {
+            state: SHA256_INITIAL_HASH,
+            block_len: 0,
+        })
+    }
+
+    fn finalize_variable_core(
+        &mut self,
+        buffer: &mut Buffer<Self>,
+        out: &mut Output<Self>,
+    ) {
+        let bit_len = 8 * (buffer.get_pos() as u64 + 64 * self.block_len);
+        buffer.len64_padding_be(bit_len, |block| {
+            self.update_blocks(core::slice::from_ref(block))
+        });
+
+        for (chunk, i) in out.chunks_exact_mut(4).zip(OUTPUT_ORDER) {
+            chunk.copy_from_slice(&self.state[i].to_be_bytes());
+        }
+    }
+}
+
+impl HashMarker for Sha512VectorCore {}
+
+impl BlockSizeUser for Sha512VectorCore {
+    type BlockSize = U128;
+}
+
+impl BufferKindUser for Sha512VectorCore {
+    type BufferKind = Eager;
+}
+
+impl UpdateCore for Sha512VectorCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.block_len += blocks.len() as u128;
+        for block in blocks {
+            asm_hash512(
+                self.state.as_mut_ptr() as *mut usize,
+                block.as_ptr() as *mut usize,
+                SHA512_ROUND_CONSTANTS.as_ptr() as *mut usize,
+            );
+        }
+    }
+}
+
+impl OutputSizeUser for Sha512VectorCore {
+    type OutputSize = U64;
+}
+
+impl VariableOutputCore for Sha512VectorCore {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        // SHA384 differs only in initial hash and truncation
+        let state = match output_size {
+            48 => SHA384_INITIAL_HASH,
+            64 => SHA512_INITIAL_HASH,
+            _ => return Err(InvalidOutputSize),
+        };
+        Ok(Sha512VectorCore {
+            state,
+            block_len: 0,
+        })
+    }
+
+    fn finalize_variable_core(
+        &mut self,
+        buffer: &mut Buffer<Self>,
+        out: &mut Output<Self>,
+    ) {
+        let bit_len = 8 * (buffer.get_pos() as u128 + 128 * self.block_len);
+        buffer.len128_padding_be(bit_len, |block| {
+            self.update_blocks(core::slice::from_ref(block))
+        });
+
+        for (chunk, i) in out.chunks_exact_mut(8).zip(OUTPUT_ORDER) {
+            chunk.copy_from_slice(&self.state[i].to_be_bytes());
+        }
+    }
+}
+
+impl HashMarker for Sm3VectorCore {}
+
+impl BlockSizeUser for Sm3VectorCore {
+    type BlockSize = U64;
+}
+
+impl BufferKindUser for Sm3VectorCore {
+    type BufferKind = Eager;
+}
+
+impl UpdateCore for Sm3VectorCore {
+    fn update_blocks(&mut self, blocks: &[Block<Self>]) {
+        self.block_len += blocks.len() as u64;
+        for block in blocks {
+            asm_sm3(
+                self.state.as_mut_ptr() as *mut usize,
+                block.as_ptr() as *mut usize,
+            );
+        }
+    }
+}
+
+impl OutputSizeUser for Sm3VectorCore {
+    type OutputSize = U32;
+}
+
+impl VariableOutputCore for Sm3VectorCore {
+    const TRUNC_SIDE: TruncSide = TruncSide::Left;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size != 32 {
+            return Err(InvalidOutputSize);
+        }
+        // Zvksh takes the words big endian, as loaded straight from memory
+        Ok(Sm3VectorCore {
+            state: SM3_INITIAL_HASH.map(u32::to_be),
+            block_len: 0,
+        })
+    }
+
+    fn finalize_variable_core(
+        &mut self,
+        buffer: &mut Buffer<Self>,
+        out: &mut Output<Self>,
+    ) {
+        let bit_len = 8 * (buffer.get_pos() as u64 + 64 * self.block_len);
+        buffer.len64_padding_be(bit_len, |block| {
+            self.update_blocks(core::slice::from_ref(block))
+        });
+
+        for (chunk, x) in out.chunks_exact_mut(4).zip(self.state) {
+            chunk.copy_from_slice(&x.to_ne_bytes());
+        }
+    }
+}
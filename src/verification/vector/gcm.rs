@@ -0,0 +1,483 @@
+// Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com>
+
+//! AES-GCM with the RISC-V vector cryptography extension.
+//!
+//! `asm_aes128_expand` and `asm_aes256_expand` build the round keys with the
+//! Zvkned key schedule instructions, `asm_aes_ctr` runs the Zvkned rounds over
+//! four counter blocks per instruction and `asm_ghash` folds one block per
+//! `vghsh` into the Zvkg hash. Only the forward cipher is needed, GCM
+//! decrypts by counter mode. Decryption hashes the whole ciphertext and
+//! checks the tag before a single block is decrypted, so a forged payload is
+//! never turned into plaintext.
+//!
+//! Blocks, keys and hash values are loaded with `vle32.v` straight from
+//! memory, the instructions take bytes in the order NIST SP 800-38D writes
+//! them.
+
+use core::arch::asm;
+use zeroize::{Zeroize, Zeroizing};
+
+//--------------------------------------------------------------------------------------------------
+// Public Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// AES-128-GCM or AES-256-GCM using Zvkned and Zvkg
+pub struct AesGcm {
+    /// Round keys as `vle32.v` loads them, those AES-128 does not use zero
+    schedule: [u32; 60],
+    /// Whether the key is 256 bits, 14 rounds rather than 10
+    aes256: bool,
+}
+
+//--------------------------------------------------------------------------------------------------
+// Private Definitions
+//--------------------------------------------------------------------------------------------------
+
+/// Size of an AES block
+const BLOCK_LEN: usize = 16;
+/// Blocks `asm_aes_ctr` handles per iteration, one per vector register of
+/// an LMUL 4 group
+const CHUNK_LEN: usize = 4 * BLOCK_LEN;
+/// Blocks given to `asm_aes_ctr` per call
+const BATCH_BLOCKS: usize = 64;
+
+//--------------------------------------------------------------------------------------------------
+// Private Code
+//--------------------------------------------------------------------------------------------------
+
+#[inline(never)]
+fn asm_aes128_expand(a0: *const u32, a1: *mut u32) {
+    // Only unsafe as assembly code the assembly is an implementation of the
+    // AES-128 key schedule as per RISC-V Crypto (Zvkned)
+
+    // ? See `super::asm_hash` for why the instructions are pre-assembled,
+    // ? the vector crypto instructions are encoded with assemble.py.
+
+    unsafe {
+        asm!(
+            // One 128 bit element group
+            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
+
+            // Round key 0 is the key, each vaeskf1 derives the next
+            ".word 0x02056507", // vle32.v v10,(a0)
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8aa0a5f7", // vaeskf1.vi v11 v10 1
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8ab12577", // vaeskf1.vi v10 v11 2
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8aa1a5f7", // vaeskf1.vi v11 v10 3
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8ab22577", // vaeskf1.vi v10 v11 4
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8aa2a5f7", // vaeskf1.vi v11 v10 5
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8ab32577", // vaeskf1.vi v10 v11 6
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8aa3a5f7", // vaeskf1.vi v11 v10 7
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8ab42577", // vaeskf1.vi v10 v11 8
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8aa4a5f7", // vaeskf1.vi v11 v10 9
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0x8ab52577", // vaeskf1.vi v10 v11 10
+            ".word 0x0205e527", // vse32.v v10,(a1)
+
+            // The key is not left in the vector registers
+            ".word 0x2ea50557", // vxor.vv v10,v10,v10
+            ".word 0x2eb585d7", // vxor.vv v11,v11,v11
+            inout("a0") a0 => _,
+            inout("a1") a1 => _,
+        );
+    }
+}
+
+#[inline(never)]
+fn asm_aes256_expand(a0: *const u32, a1: *mut u32) {
+    // Only unsafe as assembly code the assembly is an implementation of the
+    // AES-256 key schedule as per RISC-V Crypto (Zvkned)
+
+    unsafe {
+        asm!(
+            // One 128 bit element group
+            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
+
+            // Round keys 0 and 1 are the key, each vaeskf2 derives the next
+            // from the two before it, overwriting the older
+            ".word 0x02056507", // vle32.v v10,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056587", // vle32.v v11,(a0)
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab12577", // vaeskf2.vi v10 v11 2
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaaa1a5f7", // vaeskf2.vi v11 v10 3
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab22577", // vaeskf2.vi v10 v11 4
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaaa2a5f7", // vaeskf2.vi v11 v10 5
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab32577", // vaeskf2.vi v10 v11 6
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaaa3a5f7", // vaeskf2.vi v11 v10 7
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab42577", // vaeskf2.vi v10 v11 8
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaaa4a5f7", // vaeskf2.vi v11 v10 9
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab52577", // vaeskf2.vi v10 v11 10
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaaa5a5f7", // vaeskf2.vi v11 v10 11
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab62577", // vaeskf2.vi v10 v11 12
+            ".word 0x0205e527", // vse32.v v10,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaaa6a5f7", // vaeskf2.vi v11 v10 13
+            ".word 0x0205e5a7", // vse32.v v11,(a1)
+            "addi a1, a1, 16",
+            ".word 0xaab72577", // vaeskf2.vi v10 v11 14
+            ".word 0x0205e527", // vse32.v v10,(a1)
+
+            // The key is not left in the vector registers
+            ".word 0x2ea50557", // vxor.vv v10,v10,v10
+            ".word 0x2eb585d7", // vxor.vv v11,v11,v11
+            inout("a0") a0 => _,
+            inout("a1") a1 => _,
+        );
+    }
+}
+
+#[inline(never)]
+fn asm_aes_ctr(
+    a0: *const u32,
+    a1: *const u32,
+    a2: *mut u8,
+    a3: usize,
+    a4: usize,
+) {
+    // Only unsafe as assembly code the assembly is an implementation of AES
+    // counter mode as per RISC-V Crypto (Zvkned), a3 chunks of four counter
+    // blocks at a1 are encrypted with the round keys at a0 and xored into
+    // the data at a2, with 14 rounds when a4 is set and 10 otherwise
+
+    // Every round key stays in a register, the .vs forms apply element group
+    // 0 of the key to each of the four blocks in the v16 and v20 groups.
+
+    unsafe {
+        asm!(
+            /* ------------------------- Setup -------------------------- */
+            // Load round keys 0 to 14 into v1 to v15
+            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
+            ".word 0x02056087", // vle32.v v1,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056107", // vle32.v v2,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056187", // vle32.v v3,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056207", // vle32.v v4,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056287", // vle32.v v5,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056307", // vle32.v v6,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056387", // vle32.v v7,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056407", // vle32.v v8,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056487", // vle32.v v9,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056507", // vle32.v v10,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056587", // vle32.v v11,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056607", // vle32.v v12,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056687", // vle32.v v13,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056707", // vle32.v v14,(a0)
+            "addi a0, a0, 16",
+            ".word 0x02056787", // vle32.v v15,(a0)
+
+            // Four blocks per register group
+            ".word 0xcd287057", // vsetivli zero,16,e32,m4,ta,ma
+
+            /* ------------------------- Chunk -------------------------- */
+            "2:",
+            ".word 0x0205e807", // vle32.v v16,(a1)
+            ".word 0xa613a877", // vaesz.vs v16 v1
+            ".word 0xa6212877", // vaesem.vs v16 v2
+            ".word 0xa6312877", // vaesem.vs v16 v3
+            ".word 0xa6412877", // vaesem.vs v16 v4
+            ".word 0xa6512877", // vaesem.vs v16 v5
+            ".word 0xa6612877", // vaesem.vs v16 v6
+            ".word 0xa6712877", // vaesem.vs v16 v7
+            ".word 0xa6812877", // vaesem.vs v16 v8
+            ".word 0xa6912877", // vaesem.vs v16 v9
+            ".word 0xa6a12877", // vaesem.vs v16 v10
+            "beqz a4, 3f",
+            ".word 0xa6b12877", // vaesem.vs v16 v11
+            ".word 0xa6c12877", // vaesem.vs v16 v12
+            ".word 0xa6d12877", // vaesem.vs v16 v13
+            ".word 0xa6e12877", // vaesem.vs v16 v14
+            ".word 0xa6f1a877", // vaesef.vs v16 v15
+            "j 4f",
+            "3:",
+            ".word 0xa6b1a877", // vaesef.vs v16 v11
+            "4:",
+            ".word 0x02066a07", // vle32.v v20,(a2)
+            ".word 0x2f0a0857", // vxor.vv v16,v16,v20
+            ".word 0x02066827", // vse32.v v16,(a2)
+            "addi a1, a1, 64",
+            "addi a2, a2, 64",
+            "addi a3, a3, -1",
+            "bnez a3, 2b",
+
+            /* ------------------------- Output ------------------------- */
+            // Neither the round keys nor the key stream are left behind
+            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
+            ".word 0x2e1080d7", // vxor.vv v1,v1,v1
+            ".word 0x2e210157", // vxor.vv v2,v2,v2
+            ".word 0x2e3181d7", // vxor.vv v3,v3,v3
+            ".word 0x2e420257", // vxor.vv v4,v4,v4
+            ".word 0x2e5282d7", // vxor.vv v5,v5,v5
+            ".word 0x2e630357", // vxor.vv v6,v6,v6
+            ".word 0x2e7383d7", // vxor.vv v7,v7,v7
+            ".word 0x2e840457", // vxor.vv v8,v8,v8
+            ".word 0x2e9484d7", // vxor.vv v9,v9,v9
+            ".word 0x2ea50557", // vxor.vv v10,v10,v10
+            ".word 0x2eb585d7", // vxor.vv v11,v11,v11
+            ".word 0x2ec60657", // vxor.vv v12,v12,v12
+            ".word 0x2ed686d7", // vxor.vv v13,v13,v13
+            ".word 0x2ee70757", // vxor.vv v14,v14,v14
+            ".word 0x2ef787d7", // vxor.vv v15,v15,v15
+            ".word 0xcd287057", // vsetivli zero,16,e32,m4,ta,ma
+            ".word 0x2f080857", // vxor.vv v16,v16,v16
+            inout("a0") a0 => _,
+            inout("a1") a1 => _,
+            inout("a2") a2 => _,
+            inout("a3") a3 => _,
+            in("a4") a4,
+        );
+    }
+}
+
+#[inline(never)]
+fn asm_ghash(a0: *const u32, a1: *mut u32, a2: *const u8, a3: usize) {
+    // Only unsafe as assembly code the assembly is an implementation of
+    // GHASH as per RISC-V Crypto (Zvkg), the a3 blocks at a2 are folded into
+    // the hash at a1 with the hash key at a0
+
+    unsafe {
+        asm!(
+            // One 128 bit element group
+            ".word 0xcd027057", // vsetivli zero,4,e32,m1,ta,ma
+            ".word 0x02056087", // vle32.v v1,(a0)
+            ".word 0x0205e107", // vle32.v v2,(a1)
+
+            // Y = (Y ^ X) * H
+            "2:",
+            ".word 0x02066187", // vle32.v v3,(a2)
+            ".word 0xb211a177", // vghsh.vv v2 v1 v3
+            "addi a2, a2, 16",
+            "addi a3, a3, -1",
+            "bnez a3, 2b",
+
+            ".word 0x0205e127", // vse32.v v2,(a1)
+            ".word 0x2e1080d7", // vxor.vv v1,v1,v1
+            inout("a0") a0 => _,
+            inout("a1") a1 => _,
+            inout("a2") a2 => _,
+            inout("a3") a3 => _,
+        );
+    }
+}
+
+/// Fills `words` with `bytes` as `vle32.v` loads them
+fn words(bytes: &[u8], words: &mut [u32; 4]) {
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+}
+
+impl AesGcm {
+    /// Encrypts `counters`, one chunk of four blocks, and xors them into
+    /// `data`
+    fn encrypt_chunk(&self, counters: &[u32; 16], data: &mut [u8; CHUNK_LEN]) {
+        asm_aes_ctr(
+            self.schedule.as_ptr(),
+            counters.as_ptr(),
+            data.as_mut_ptr(),
+            1,
+            self.aes256 as usize,
+        );
+    }
+
+    /// Folds `data` into `hash`, a partial final block is zero padded
+    fn ghash(&self, key: &[u32; 4], hash: &mut [u32; 4], data: &[u8]) {
+        let whole = data.len() / BLOCK_LEN;
+        if whole > 0 {
+            asm_ghash(key.as_ptr(), hash.as_mut_ptr(), data.as_ptr(), whole);
+        }
+        let tail = &data[whole * BLOCK_LEN..];
+        if !tail.is_empty() {
+            let mut block = [0; BLOCK_LEN];
+            block[..tail.len()].copy_from_slice(tail);
+            asm_ghash(key.as_ptr(), hash.as_mut_ptr(), block.as_ptr(), 1);
+        }
+    }
+
+    /// Xors the key stream from counter block 2 on into `data`
+    fn apply_keystream(&self, nonce: &[u32; 3], data: &mut [u8]) {
+        let mut counters = [0u32; 4 * BATCH_BLOCKS];
+        let mut counter: u32 = 2;
+        let mut fill = |counters: &mut [u32]| {
+            for block in counters.chunks_exact_mut(4) {
+                block[..3].copy_from_slice(nonce);
+                block[3] = counter.to_be();
+                counter = counter.wrapping_add(1);
+            }
+        };
+
+        let mut batches = data.chunks_exact_mut(BATCH_BLOCKS * BLOCK_LEN);
+        for batch in &mut batches {
+            fill(&mut counters);
+            asm_aes_ctr(
+                self.schedule.as_ptr(),
+                counters.as_ptr(),
+                batch.as_mut_ptr(),
+                BATCH_BLOCKS / 4,
+                self.aes256 as usize,
+            );
+        }
+        let rest = batches.into_remainder();
+        let mut chunks = rest.chunks_exact_mut(CHUNK_LEN);
+        for chunk in &mut chunks {
+            let counters = &mut counters[..16];
+            fill(counters);
+            asm_aes_ctr(
+                self.schedule.as_ptr(),
+                counters.as_ptr(),
+                chunk.as_mut_ptr(),
+                1,
+                self.aes256 as usize,
+            );
+        }
+
+        // The last few bytes go through a copy so no more than the data is
+        // written
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let mut chunk = Zeroizing::new([0; CHUNK_LEN]);
+            chunk[..tail.len()].copy_from_slice(tail);
+            let mut last = [0; 16];
+            fill(&mut last);
+            self.encrypt_chunk(&last, &mut chunk);
+            tail.copy_from_slice(&chunk[..tail.len()]);
+        }
+    }
+}
+
+//--------------------------------------------------------------------------------------------------
+// Public Code
+//--------------------------------------------------------------------------------------------------
+
+impl AesGcm {
+    /// Expands a 16 or 32 byte `key`, `None` for any other length
+    pub fn new(key: &[u8]) -> Option<Self> {
+        let mut words = Zeroizing::new([0u32; 8]);
+        for (word, chunk) in words.iter_mut().zip(key.chunks_exact(4)) {
+            *word =
+                u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        let mut cipher = AesGcm {
+            schedule: [0; 60],
+            aes256: key.len() == 32,
+        };
+        match key.len() {
+            16 => {
+                asm_aes128_expand(words.as_ptr(), cipher.schedule.as_mut_ptr())
+            }
+            32 => {
+                asm_aes256_expand(words.as_ptr(), cipher.schedule.as_mut_ptr())
+            }
+            _ => return None,
+        }
+        Some(cipher)
+    }
+
+    /// Checks `tag` over `aad` and the ciphertext in `buffer` then decrypts
+    /// it in place, leaving `buffer` untouched if the tag is wrong
+    pub fn decrypt_in_place_detached(
+        &self,
+        nonce: &[u8; 12],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &[u8; BLOCK_LEN],
+    ) -> bool {
+        let mut nonce_words = [0; 3];
+        for (word, chunk) in nonce_words.iter_mut().zip(nonce.chunks_exact(4)) {
+            *word =
+                u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+
+        // H is the zero block encrypted and the tag mask is counter block 1
+        let mut blocks = [0u32; 16];
+        blocks[4..7].copy_from_slice(&nonce_words);
+        blocks[7] = 1u32.to_be();
+        let mut keys = Zeroizing::new([0; CHUNK_LEN]);
+        self.encrypt_chunk(&blocks, &mut keys);
+        let mut hash_key = Zeroizing::new([0u32; 4]);
+        words(&keys[..BLOCK_LEN], &mut hash_key);
+
+        let mut hash = Zeroizing::new([0u32; 4]);
+        self.ghash(&hash_key, &mut hash, aad);
+        self.ghash(&hash_key, &mut hash, buffer);
+        let mut lengths = [0; BLOCK_LEN];
+        lengths[..8].copy_from_slice(&(8 * aad.len() as u64).to_be_bytes());
+        lengths[8..].copy_from_slice(&(8 * buffer.len() as u64).to_be_bytes());
+        self.ghash(&hash_key, &mut hash, &lengths);
+        hash_key.zeroize();
+
+        // Compare every byte whatever the first difference
+        let mut difference = 0;
+        for (i, word) in hash.iter().enumerate() {
+            for (j, byte) in word.to_ne_bytes().iter().enumerate() {
+                let k = 4 * i + j;
+                difference |= byte ^ keys[BLOCK_LEN + k] ^ tag[k];
+            }
+        }
+        if difference != 0 {
+            return false;
+        }
+
+        self.apply_keystream(&nonce_words, buffer);
+        true
+    }
+}
+
+impl Drop for AesGcm {
+    fn drop(&mut self) {
+        self.schedule.zeroize();
+    }
+}
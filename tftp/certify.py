@@ -0,0 +1,81 @@
+# Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
+
+import argparse
+import struct
+from keys import RSA_PADDINGS, Signer, algorithm, key_id
+
+# Key certificate, must match src/verification/certificate.rs
+CERTIFICATE_MAGIC = b"SNTLCERT"
+CERTIFICATE_VERSION = 1
+
+# Must match src/verification/keyring.rs
+KEY_USAGES = {"image": 1 << 0, "revocation": 1 << 1, "certificate": 1 << 2}
+
+
+def build_certificate(
+    sig_alg, issuer_public_bytes, key_alg, subject_public_bytes, usage
+):
+    return struct.pack(
+        "<8sHBBI8s8s",
+        CERTIFICATE_MAGIC,
+        CERTIFICATE_VERSION,
+        sig_alg,
+        key_alg,
+        usage,
+        key_id(issuer_public_bytes),
+        key_id(subject_public_bytes),
+    ) + subject_public_bytes
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(
+        description="Certify a key with a key from the keyring"
+    )
+    parser.add_argument(
+        "issuer", help="key name as given to generate_*_keys.py"
+    )
+    parser.add_argument(
+        "subject", help="key name as given to generate_*_keys.py"
+    )
+    parser.add_argument(
+        "--usage",
+        action="append",
+        choices=KEY_USAGES,
+        default=[],
+        help="role the subject may take, repeat for several",
+    )
+    parser.add_argument(
+        "--rsa-padding",
+        choices=RSA_PADDINGS,
+        default="pss",
+        help="padding the issuer signs with when it is RSA",
+    )
+    parser.add_argument(
+        "--subject-rsa-padding",
+        choices=RSA_PADDINGS,
+        default="pss",
+        help="padding the subject will sign with when it is RSA",
+    )
+    args = parser.parse_args()
+
+    issuer = Signer(f"{args.issuer}_", args.rsa_padding)
+    with open(f"{args.subject}_public_key.pem", "rb") as file:
+        subject_public_bytes = file.read()
+
+    usage = 0
+    for item in args.usage or ["image"]:
+        usage |= KEY_USAGES[item]
+
+    certificate = build_certificate(
+        issuer.alg,
+        issuer.public_bytes,
+        algorithm(subject_public_bytes, args.subject_rsa_padding),
+        subject_public_bytes,
+        usage,
+    )
+    certificate += issuer.sign(certificate)
+    with open(f"{args.subject}_certificate.bin", "wb") as file:
+        file.write(certificate)
+    print(f"Issuer: {issuer.key_id().hex()}")
+    print(f"Subject: {key_id(subject_public_bytes).hex()}")
+    print(f"Usage: {hex(usage)}")
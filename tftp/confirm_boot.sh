@@ -0,0 +1,22 @@
+#!/bin/sh
+
+# Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
+
+# Run on the booted system once it is up, e.g. from the last init script. It
+# clears the `confirmed` word of SentinelBoot's anti-rollback record, see
+# src/verification/rollback.rs, so the next boot raises the minimum security
+# version to this kernel's. Clearing bits needs no flash erase.
+#
+# On QEMU virt both pflash banks are one MTD device, the record is at the
+# start of the second bank.
+# Being writable from here, the record could as easily be lowered, so on QEMU
+# it only guards against booting an old kernel by accident.
+
+set -e
+
+MTD=${1:-/dev/mtd0}
+RECORD=${2:-$((0x2000000))}
+CONFIRMED=$((RECORD + 0x20))
+
+head -c 4 /dev/zero | dd of="$MTD" bs=4 seek=$((CONFIRMED / 4)) count=1 conv=notrunc 2>/dev/null
+echo "Boot confirmed"
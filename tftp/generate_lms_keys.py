@@ -0,0 +1,31 @@
+import argparse
+from lms import OTS_WIDTHS, LMS_HEIGHTS, PrivateKey
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(
+        description="Generate a stateful HSS/LMS key pair"
+    )
+    # Optional name so several keys can live side by side for the keyring
+    parser.add_argument("name", nargs="?")
+    # The default allows 2^15 signatures of 2804 bytes, which fit the 4K
+    # header alongside certificates
+    parser.add_argument(
+        "--heights",
+        default="10,5",
+        help="comma separated LMS tree height of each level, top first",
+    )
+    parser.add_argument("--w", type=int, default=8, choices=sorted(OTS_WIDTHS))
+    args = parser.parse_args()
+    prefix = f"{args.name}_" if args.name else ""
+    heights = [int(x) for x in args.heights.split(",")]
+    for height in heights:
+        if height not in LMS_HEIGHTS:
+            parser.error(f"height must be one of {sorted(LMS_HEIGHTS)}")
+
+    print("Building the Merkle trees, large trees take several minutes...")
+    private_key = PrivateKey.generate(
+        f"{prefix}private_key.pem", heights, args.w
+    )
+
+    with open(f"{prefix}public_key.pem", "wb") as file:
+        file.write(private_key.public())
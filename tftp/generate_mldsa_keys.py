@@ -0,0 +1,29 @@
+import argparse
+from cryptography.hazmat.primitives.asymmetric.ed25519 import (
+    Ed25519PrivateKey,
+)
+from cryptography.hazmat.primitives.asymmetric.mldsa import MLDSA65PrivateKey
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Generate an ML-DSA-65 key")
+    # Optional name so several keys can live side by side for the keyring
+    parser.add_argument("name", nargs="?")
+    parser.add_argument(
+        "--hybrid",
+        action="store_true",
+        help="pair it with an Ed25519 key, both of which must sign",
+    )
+    args = parser.parse_args()
+    prefix = f"{args.name}_" if args.name else ""
+
+    keys = [MLDSA65PrivateKey.generate()]
+    if args.hybrid:
+        keys.insert(0, Ed25519PrivateKey.generate())
+
+    with open(f"{prefix}public_key.pem", "wb") as file:
+        for key in keys:
+            file.write(key.public_key().public_bytes_raw())
+
+    with open(f"{prefix}private_key.pem", "wb") as file:
+        for key in keys:
+            file.write(key.private_bytes_raw())
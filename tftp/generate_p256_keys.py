@@ -0,0 +1,22 @@
+import sys
+from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric import ec
+
+private_key = ec.generate_private_key(ec.SECP256R1())
+public_key = private_key.public_key()
+
+if __name__ == "__main__":
+    # Optional name so several keys can live side by side for the keyring
+    prefix = f"{sys.argv[1]}_" if len(sys.argv) > 1 else ""
+
+    with open(f"{prefix}public_key.pem", "wb") as file:
+        file.write(
+            public_key.public_bytes(
+                serialization.Encoding.X962,
+                serialization.PublicFormat.UncompressedPoint,
+            )
+        )
+
+    with open(f"{prefix}private_key.pem", "wb") as file:
+        private_value = private_key.private_numbers().private_value
+        file.write(private_value.to_bytes(32, "big"))
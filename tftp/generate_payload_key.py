@@ -0,0 +1,9 @@
+import os
+
+# Build-time key for encrypted kernels, embedded in SentinelBoot by
+# gen_helper.py and used by hash.py --encrypt
+KEY_LEN = 32
+
+if __name__ == "__main__":
+    with open("payload_key", "wb") as file:
+        file.write(os.urandom(KEY_LEN))
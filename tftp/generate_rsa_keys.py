@@ -0,0 +1,31 @@
+import argparse
+from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric import rsa
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Generate an RSA key pair")
+    # Optional name so several keys can live side by side for the keyring
+    parser.add_argument("name", nargs="?")
+    parser.add_argument("--bits", type=int, default=3072)
+    args = parser.parse_args()
+    prefix = f"{args.name}_" if args.name else ""
+
+    private_key = rsa.generate_private_key(
+        public_exponent=65537, key_size=args.bits
+    )
+
+    with open(f"{prefix}public_key.pem", "wb") as file:
+        file.write(
+            private_key.public_key().public_bytes(
+                serialization.Encoding.DER, serialization.PublicFormat.PKCS1
+            )
+        )
+
+    with open(f"{prefix}private_key.pem", "wb") as file:
+        file.write(
+            private_key.private_bytes(
+                serialization.Encoding.DER,
+                serialization.PrivateFormat.TraditionalOpenSSL,
+                serialization.NoEncryption(),
+            )
+        )
@@ -1,68 +1,285 @@
 # Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
 
-import sys
-import base64
-from Crypto.Hash import SHA256
-from cryptography.hazmat.primitives import serialization
-from cryptography.hazmat.primitives.asymmetric.ed25519 import (
-    Ed25519PrivateKey,
-    Ed25519PublicKey,
-)
-import pefile
-import pprint
+import argparse
+import gzip
+import hashlib
+import os
+import struct
+import subprocess
+from cryptography.exceptions import InvalidSignature
+from cryptography.hazmat.primitives.ciphers.aead import AESGCM, ChaCha20Poly1305
+from keys import RSA_PADDINGS, SIG_ALG_HSS_SHA256, Signer, key_id
 
 # 4KiB blocks
-buffer_size = 4096
-sha256_hash = SHA256.new()
+BUFFER_SIZE = 4096
 
-if __name__ == "__main__":
-    with open("private_key.pem", "rb") as file:
-        private_key = Ed25519PrivateKey.from_private_bytes(file.read())
-    privatePem = private_key.private_bytes(
-        encoding=serialization.Encoding.Raw,
-        format=serialization.PrivateFormat.Raw,
-        encryption_algorithm=serialization.NoEncryption(),
-    )
-    print("Private key:", end=" ")
-    for item in privatePem:
-        print(hex(item)[2:], end=" ")
-    print()
+# Signed image header, must match src/verification/header.rs
+HEADER_MAGIC = b"SNTLBOOT"
+HEADER_VERSION = 1
+# Distance between bsp::memory::map::kernel::SIGNATURE and KERNEL
+HEADER_LEN = 0x1000
+HEADER_FIXED_LEN = 0x68
+# Digest name: (ID, hashlib name)
+HASH_ALGS = {
+    "sha256": (1, "sha256"),
+    "sha384": (2, "sha384"),
+    "sha512": (3, "sha512"),
+    "sha3-256": (4, "sha3_256"),
+    "sm3": (5, "sm3"),
+}
 
-    with open("public_key.pem", "rb") as file:
-        public_key = Ed25519PublicKey.from_public_bytes(file.read())
-    publicPem = public_key.public_bytes(
-        encoding=serialization.Encoding.Raw,
-        format=serialization.PublicFormat.Raw,
-    )
-    print("Public key:", end=" ")
-    for item in publicPem:
-        print(hex(item)[2:], end=" ")
-    print()
+# Protected TLV records, must match src/verification/header.rs
+TLV_COMPONENT = 0x0001
+TLV_SECURITY_VERSION = 0x0002
+TLV_CERTIFICATE = 0x0003
+TLV_ENCRYPTION = 0x0004
+TLV_COMPRESSION = 0x0005
+
+# Kernel ciphers, must match src/verification/encryption.rs
+CIPHERS = {
+    "chacha20-poly1305": (1, ChaCha20Poly1305),
+    "aes-256-gcm": (2, AESGCM),
+    "aes-128-gcm": (3, AESGCM),
+}
+# Ciphers keyed with the first 16 bytes of the 32 byte key
+HALF_KEY_CIPHERS = {"aes-128-gcm"}
+KEY_SOURCE_BUILD = 1
+KEY_SOURCE_DEVICE = 2
+NONCE_LEN = 12
+TAG_LEN = 16
+
+# Kernel compression formats and their suffixes, must match
+# src/verification/compression.rs
+COMPRESSIONS = {
+    "gzip": (1, ".gz"),
+    "lz4": (2, ".lz4"),
+    "zstd": (3, ".zst"),
+}
+# zstd frames keep a window the loader has to allocate, 2^13 is the most
+# ZSTD_WINDOW_MAX accepts
+ZSTD_WINDOW_LOG = 13
+
+# Manifest components, must match src/verification/manifest.rs
+COMPONENT_KERNEL = 1
+COMPONENT_DTB = 2
+COMPONENT_RAMFS = 3
+
+# bsp::memory::map::kernel::{KERNEL, DTB, RAMFS}
+BOARDS = {
+    "qemu": (0x80200000, 0x84A00000, 0x85000000),
+    "visionfive": (0x40200000, 0x44A00000, 0x45000000),
+    "unmatched": (0x40200000, 0x44A00000, 0x45000000),
+}
 
-    pe = pefile.PE(sys.argv[1])
-    pprint.pprint(pe.OPTIONAL_HEADER.dump_dict())
-    size = pe.OPTIONAL_HEADER.dump_dict()["AddressOfEntryPoint"]["Value"]
-    buffer_size = min(buffer_size, size)
-    print(hex(size))
+
+def hash_file(path, hash_alg):
+    size = os.path.getsize(path)
+    buffer_size = min(BUFFER_SIZE, size)
+    hasher = hashlib.new(HASH_ALGS[hash_alg][1])
     byte_count = 0
-    with open(sys.argv[1], "rb") as binary:
+    with open(path, "rb") as binary:
         while buffer_size != 0:
             data = binary.read(buffer_size)
             if not data:
                 break
             byte_count += buffer_size
             buffer_size = min(buffer_size, size - byte_count)
-            sha256_hash.update(data)
+            hasher.update(data)
 
-    print(f"\nProcessed {byte_count} bytes\n")
-    hashed_data = sha256_hash.digest()
+    print(f"\nProcessed {byte_count} bytes of {path}\n")
+    hashed_data = hasher.digest()
     print("Hash:", end=" ")
     for item in hashed_data:
         print(hex(item)[2:], end=" ")
     print()
     print(f"Length: {len(hashed_data)}\n")
+    return size, hashed_data
+
+
+def tlv(tag, value):
+    return struct.pack("<HH", tag, len(value)) + value
+
+
+def component(kind, load_address, length, digest):
+    return tlv(
+        TLV_COMPONENT,
+        struct.pack("<IIQQ64s", kind, 0, load_address, length, digest),
+    )
+
+
+def encrypt_file(path, cipher, key, key_source):
+    """Encrypts `path` to `path`.enc, returning it and the TLV record"""
+    cipher_id, aead = CIPHERS[cipher]
+    if cipher in HALF_KEY_CIPHERS:
+        key = key[:16]
+    nonce = os.urandom(NONCE_LEN)
+    with open(path, "rb") as file:
+        sealed = aead(key).encrypt(nonce, file.read(), None)
+    with open(path + ".enc", "wb") as file:
+        file.write(sealed[:-TAG_LEN])
+    record = struct.pack(
+        "<BBH12s16s", cipher_id, key_source, 0, nonce, sealed[-TAG_LEN:]
+    )
+    return path + ".enc", tlv(TLV_ENCRYPTION, record)
+
+
+def compress_file(path, compression):
+    """Compresses `path` to `path` plus the format's suffix, returning it and
+    the TLV record"""
+    compression_id, suffix = COMPRESSIONS[compression]
+    with open(path, "rb") as file:
+        data = file.read()
+    if compression == "gzip":
+        packed = gzip.compress(data, compresslevel=9, mtime=0)
+    else:
+        if compression == "lz4":
+            command = ["lz4", "-9"]
+        else:
+            command = ["zstd", "-19", f"--zstd=wlog={ZSTD_WINDOW_LOG}"]
+        command += ["-c", "-q", path]
+        packed = subprocess.run(command, check=True, capture_output=True).stdout
+    with open(path + suffix, "wb") as file:
+        file.write(packed)
+    record = struct.pack("<B7xQ", compression_id, len(data))
+    return path + suffix, tlv(TLV_COMPRESSION, record)
+
 
-    signature = private_key.sign(hashed_data)
+def build_header(
+    sig_alg, hash_alg, public_bytes, payload_len, payload_hash, tlvs=b""
+):
+    header = struct.pack(
+        "<8sHBBIII8sQ64s",
+        HEADER_MAGIC,
+        HEADER_VERSION,
+        sig_alg,
+        HASH_ALGS[hash_alg][0],
+        HEADER_LEN,
+        HEADER_FIXED_LEN + len(tlvs),
+        0,
+        key_id(public_bytes),
+        payload_len,
+        payload_hash,
+    )
+    assert len(header) == HEADER_FIXED_LEN
+    return header + tlvs
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Sign images for SentinelBoot")
+    parser.add_argument("board", choices=BOARDS)
+    parser.add_argument("kernel")
+    parser.add_argument("dtb")
+    parser.add_argument("ramfs")
+    parser.add_argument(
+        "--key",
+        help="key name as given to generate_*_keys.py",
+    )
+    parser.add_argument(
+        "--hash",
+        choices=HASH_ALGS,
+        default="sha256",
+        help="digest of the kernel, DTB and initramfs",
+    )
+    parser.add_argument(
+        "--rsa-padding",
+        choices=RSA_PADDINGS,
+        default="pss",
+        help="padding to sign with when the key is RSA",
+    )
+    parser.add_argument(
+        "--certificate",
+        action="append",
+        default=[],
+        help="certificate chain file from certify.py, repeat for each link",
+    )
+    parser.add_argument(
+        "--security-version",
+        type=int,
+        default=0,
+        help="anti-rollback version, raise when fixing a vulnerability",
+    )
+    parser.add_argument(
+        "--compress",
+        choices=COMPRESSIONS,
+        help="compress the kernel, it is decompressed once it verifies",
+    )
+    parser.add_argument(
+        "--encrypt",
+        choices=CIPHERS,
+        help="encrypt the kernel, with the build key unless --device-key",
+    )
+    parser.add_argument(
+        "--payload-key",
+        default="payload_key",
+        help="build key from generate_payload_key.py",
+    )
+    parser.add_argument(
+        "--device-key",
+        help="device key from uds.py, the kernel then only boots on that device",
+    )
+    args = parser.parse_args()
+    kernel_address, dtb_address, ramfs_address = BOARDS[args.board]
+    prefix = f"{args.key}_" if args.key else ""
+
+    signer = Signer(prefix, args.rsa_padding)
+    # HSS private keys are the whole signing state, far too long to print
+    if signer.alg != SIG_ALG_HSS_SHA256:
+        print("Private key:", end=" ")
+        for item in signer.private_bytes:
+            print(hex(item)[2:], end=" ")
+        print()
+
+    print("Public key:", end=" ")
+    for item in signer.public_bytes:
+        print(hex(item)[2:], end=" ")
+    print()
+
+    # The ciphertext is signed, the kernel is decrypted once it verifies and
+    # then decompressed, so it is compressed first
+    kernel = args.kernel
+    temporary = []
+    compression = b""
+    if args.compress:
+        kernel, compression = compress_file(kernel, args.compress)
+        temporary.append(kernel)
+        print(f"Kernel compressed with {args.compress}")
+    encryption = b""
+    if args.encrypt:
+        if args.device_key:
+            with open(args.device_key, "rb") as file:
+                key, key_source = file.read(), KEY_SOURCE_DEVICE
+        else:
+            with open(args.payload_key, "rb") as file:
+                key, key_source = file.read(), KEY_SOURCE_BUILD
+        kernel, encryption = encrypt_file(kernel, args.encrypt, key, key_source)
+        temporary.append(kernel)
+        print(f"Kernel encrypted with {args.encrypt}")
+
+    # The whole of every file is signed so no byte can be altered
+    kernel_size, kernel_hash = hash_file(kernel, args.hash)
+    dtb_size, dtb_hash = hash_file(args.dtb, args.hash)
+    ramfs_size, ramfs_hash = hash_file(args.ramfs, args.hash)
+
+    print(f"Security version: {args.security_version}")
+    tlvs = (
+        tlv(TLV_SECURITY_VERSION, struct.pack("<I", args.security_version))
+        + component(COMPONENT_KERNEL, kernel_address, kernel_size, kernel_hash)
+        + component(COMPONENT_DTB, dtb_address, dtb_size, dtb_hash)
+        + component(COMPONENT_RAMFS, ramfs_address, ramfs_size, ramfs_hash)
+    )
+    tlvs += encryption + compression
+    for path in args.certificate:
+        with open(path, "rb") as file:
+            tlvs += tlv(TLV_CERTIFICATE, file.read())
+    header = build_header(
+        signer.alg,
+        args.hash,
+        signer.public_bytes,
+        kernel_size,
+        kernel_hash,
+        tlvs,
+    )
+    signature = signer.sign(header)
     print("Signature:", end=" ")
     for item in signature:
         print(hex(item)[2:], end=" ")
@@ -70,15 +287,17 @@ if __name__ == "__main__":
     print()
 
     try:
-        public_key.verify(signature, hashed_data)
+        signer.verify(signature, header)
         print("Signature is valid.")
-    except (ValueError, TypeError):
+    except (InvalidSignature, ValueError, TypeError):
         print("Signature is invalid or key mismatch.")
 
-    with open(sys.argv[1], "rb") as old, open(
-        sys.argv[1] + "_signed", "wb"
-    ) as new:
+    assert len(header) + len(signature) <= HEADER_LEN
+    with open(kernel, "rb") as old, open(args.kernel + "_signed", "wb") as new:
+        new.write(header)
         new.write(signature)
-        new.write(bytearray(256 - len(signature)))
+        new.write(bytearray(HEADER_LEN - len(header) - len(signature)))
         for chunk in iter(lambda: old.read(1024), b""):
             new.write(chunk)
+    for path in temporary:
+        os.remove(path)
@@ -0,0 +1,161 @@
+# Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
+
+# Raw key files shared by the signing scripts. Ed25519 keys are stored as the
+# 32 byte seed and public key, P-256 keys as the 32 byte private scalar and
+# the 65 byte uncompressed SEC1 public key, so the public key length tells
+# the algorithm apart. RSA keys are DER encoded PKCS#1, their padding is
+# chosen when signing and must be the one the key is trusted for. HSS keys
+# are the 60 byte public key and the stateful private key of lms.py. ML-DSA-65
+# keys are the 32 byte seed and 1952 byte public key, hybrid keys the Ed25519
+# followed by the ML-DSA-65 seeds and public keys.
+
+from Crypto.Hash import SHA256
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import ec, mldsa, padding
+from cryptography.hazmat.primitives.asymmetric.ed25519 import (
+    Ed25519PrivateKey,
+)
+from cryptography.hazmat.primitives.asymmetric.utils import (
+    decode_dss_signature,
+    encode_dss_signature,
+)
+import lms
+
+# Must match SignatureAlgorithm in src/verification/header.rs
+SIG_ALG_ED25519 = 1
+SIG_ALG_ECDSA_P256_SHA256 = 2
+SIG_ALG_RSA_PKCS1V15_SHA256 = 3
+SIG_ALG_RSA_PSS_SHA256 = 4
+SIG_ALG_HSS_SHA256 = 5
+SIG_ALG_ML_DSA_65 = 6
+SIG_ALG_ED25519_ML_DSA_65 = 7
+
+PUBLIC_KEY_LENS = {
+    32: SIG_ALG_ED25519,
+    lms.PUBLIC_KEY_LEN: SIG_ALG_HSS_SHA256,
+    65: SIG_ALG_ECDSA_P256_SHA256,
+    1952: SIG_ALG_ML_DSA_65,
+    32 + 1952: SIG_ALG_ED25519_ML_DSA_65,
+}
+RSA_PADDINGS = {
+    "pss": SIG_ALG_RSA_PSS_SHA256,
+    "pkcs1v15": SIG_ALG_RSA_PKCS1V15_SHA256,
+}
+
+
+def key_id(public_bytes):
+    return SHA256.new(public_bytes).digest()[:8]
+
+
+def algorithm(public_bytes, rsa_padding="pss"):
+    if len(public_bytes) in PUBLIC_KEY_LENS:
+        return PUBLIC_KEY_LENS[len(public_bytes)]
+    # DER SEQUENCE
+    if public_bytes[0] == 0x30:
+        return RSA_PADDINGS[rsa_padding]
+    raise ValueError("unrecognised public key")
+
+
+class Signer:
+    def __init__(self, prefix="", rsa_padding="pss"):
+        with open(f"{prefix}public_key.pem", "rb") as file:
+            self.public_bytes = file.read()
+        self.alg = algorithm(self.public_bytes, rsa_padding)
+        if self.alg == SIG_ALG_HSS_SHA256:
+            # Loaded as a whole since every signature rewrites it
+            self.key = lms.PrivateKey.load(f"{prefix}private_key.pem")
+            assert self.public() == self.public_bytes, "key pair mismatch"
+            return
+        with open(f"{prefix}private_key.pem", "rb") as file:
+            self.private_bytes = file.read()
+        if self.alg == SIG_ALG_ED25519:
+            self.key = Ed25519PrivateKey.from_private_bytes(self.private_bytes)
+        elif self.alg == SIG_ALG_ECDSA_P256_SHA256:
+            self.key = ec.derive_private_key(
+                int.from_bytes(self.private_bytes, "big"), ec.SECP256R1()
+            )
+        elif self.alg == SIG_ALG_ML_DSA_65:
+            self.key = mldsa.MLDSA65PrivateKey.from_seed_bytes(
+                self.private_bytes
+            )
+        elif self.alg == SIG_ALG_ED25519_ML_DSA_65:
+            self.key = (
+                Ed25519PrivateKey.from_private_bytes(self.private_bytes[:32]),
+                mldsa.MLDSA65PrivateKey.from_seed_bytes(
+                    self.private_bytes[32:]
+                ),
+            )
+        else:
+            self.key = serialization.load_der_private_key(
+                self.private_bytes, None
+            )
+        assert self.public() == self.public_bytes, "key pair mismatch"
+
+    def public(self):
+        if self.alg == SIG_ALG_HSS_SHA256:
+            return self.key.public()
+        if self.alg in (SIG_ALG_ED25519, SIG_ALG_ML_DSA_65):
+            return self.key.public_key().public_bytes_raw()
+        if self.alg == SIG_ALG_ED25519_ML_DSA_65:
+            return b"".join(
+                x.public_key().public_bytes_raw() for x in self.key
+            )
+        if self.alg == SIG_ALG_ECDSA_P256_SHA256:
+            return self.key.public_key().public_bytes(
+                serialization.Encoding.X962,
+                serialization.PublicFormat.UncompressedPoint,
+            )
+        return self.key.public_key().public_bytes(
+            serialization.Encoding.DER, serialization.PublicFormat.PKCS1
+        )
+
+    def key_id(self):
+        return key_id(self.public_bytes)
+
+    def rsa_padding(self):
+        if self.alg == SIG_ALG_RSA_PKCS1V15_SHA256:
+            return padding.PKCS1v15()
+        # SentinelBoot expects a salt as long as the digest
+        return padding.PSS(
+            mgf=padding.MGF1(hashes.SHA256()),
+            salt_length=padding.PSS.DIGEST_LENGTH,
+        )
+
+    def sign(self, data):
+        if self.alg in (
+            SIG_ALG_HSS_SHA256,
+            SIG_ALG_ED25519,
+            SIG_ALG_ML_DSA_65,
+        ):
+            return self.key.sign(data)
+        if self.alg == SIG_ALG_ED25519_ML_DSA_65:
+            # Both over the same bytes, SentinelBoot requires both to verify
+            return b"".join(x.sign(data) for x in self.key)
+        if self.alg == SIG_ALG_ECDSA_P256_SHA256:
+            # SentinelBoot takes the fixed size r || s encoding
+            der = self.key.sign(data, ec.ECDSA(hashes.SHA256()))
+            r, s = decode_dss_signature(der)
+            return r.to_bytes(32, "big") + s.to_bytes(32, "big")
+        return self.key.sign(data, self.rsa_padding(), hashes.SHA256())
+
+    def verify(self, signature, data):
+        if self.alg == SIG_ALG_HSS_SHA256:
+            lms.verify(self.public_bytes, signature, data)
+            return
+        if self.alg in (SIG_ALG_ED25519, SIG_ALG_ML_DSA_65):
+            self.key.public_key().verify(signature, data)
+            return
+        if self.alg == SIG_ALG_ED25519_ML_DSA_65:
+            self.key[0].public_key().verify(signature[:64], data)
+            self.key[1].public_key().verify(signature[64:], data)
+            return
+        if self.alg == SIG_ALG_ECDSA_P256_SHA256:
+            der = encode_dss_signature(
+                int.from_bytes(signature[:32], "big"),
+                int.from_bytes(signature[32:], "big"),
+            )
+            self.key.public_key().verify(der, data, ec.ECDSA(hashes.SHA256()))
+            return
+        self.key.public_key().verify(
+            signature, data, self.rsa_padding(), hashes.SHA256()
+        )
@@ -0,0 +1,290 @@
+# Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
+
+# HSS/LMS hash-based signatures per RFC 8554 with SHA-256, matching
+# src/verification/lms.rs. Signing is stateful, every leaf may sign exactly
+# once, so the private key file records the next unused leaf of each level
+# and is rewritten before a signature is handed out. Never restore it from a
+# backup or copy it between machines, reusing a leaf lets others forge
+# signatures. Private values are derived from a per tree seed as in
+# Appendix A of the RFC, and the Merkle trees are cached in the key file so
+# that signing only has to rebuild a lower tree once it runs out.
+
+import hashlib, json, os
+from cryptography.exceptions import InvalidSignature
+
+N = 32
+D_PBLC = b"\x80\x80"
+D_MESG = b"\x81\x81"
+D_LEAF = b"\x82\x82"
+D_INTR = b"\x83\x83"
+
+# LM-OTS type: (w, p, ls)
+OTS_TYPES = {
+    1: (1, 265, 7),
+    2: (2, 133, 6),
+    3: (4, 67, 4),
+    4: (8, 34, 0),
+}
+OTS_WIDTHS = {w: x for x, (w, _, _) in OTS_TYPES.items()}
+# LMS type: tree height
+LMS_TYPES = {5: 5, 6: 10, 7: 15, 8: 20, 9: 25}
+LMS_HEIGHTS = {h: x for x, h in LMS_TYPES.items()}
+
+PUBLIC_KEY_LEN = 60
+
+
+def u32(x):
+    return x.to_bytes(4, "big")
+
+
+def u16(x):
+    return x.to_bytes(2, "big")
+
+
+def H(*parts):
+    return hashlib.sha256(b"".join(parts)).digest()
+
+
+def coef(s, i, w):
+    return (s[i * w // 8] >> (8 - (w * (i % (8 // w)) + w))) & ((1 << w) - 1)
+
+
+def digits(digest, ots_type):
+    w, _, ls = OTS_TYPES[ots_type]
+    max = (1 << w) - 1
+    checksum = sum(max - coef(digest, i, w) for i in range(N * 8 // w))
+    return digest + u16(checksum << ls)
+
+
+def chain(I, q, i, tmp, start, end):
+    for j in range(start, end):
+        tmp = H(I, u32(q), u16(i), bytes([j]), tmp)
+    return tmp
+
+
+def ots_secret(I, seed, q, i):
+    return H(I, u32(q), u16(i), b"\xff", seed)
+
+
+def ots_public(I, seed, q, ots_type):
+    w, p, _ = OTS_TYPES[ots_type]
+    y = b"".join(
+        chain(I, q, i, ots_secret(I, seed, q, i), 0, (1 << w) - 1)
+        for i in range(p)
+    )
+    return H(I, u32(q), D_PBLC, y)
+
+
+def ots_sign(I, seed, q, ots_type, message):
+    w, p, _ = OTS_TYPES[ots_type]
+    C = os.urandom(N)
+    Q = digits(H(I, u32(q), D_MESG, C, message), ots_type)
+    y = b"".join(
+        chain(I, q, i, ots_secret(I, seed, q, i), 0, coef(Q, i, w))
+        for i in range(p)
+    )
+    return u32(ots_type) + C + y
+
+
+def ots_candidate(I, q, ots_type, signature, message):
+    w, p, _ = OTS_TYPES[ots_type]
+    C = signature[4 : 4 + N]
+    Q = digits(H(I, u32(q), D_MESG, C, message), ots_type)
+    y = signature[4 + N :]
+    z = b"".join(
+        chain(I, q, i, y[i * N : (i + 1) * N], coef(Q, i, w), (1 << w) - 1)
+        for i in range(p)
+    )
+    return H(I, u32(q), D_PBLC, z)
+
+
+class Tree:
+    """One LMS key pair, its Merkle tree and the next unused leaf"""
+
+    def __init__(self, lms_type, ots_type, I, seed, q=0, nodes=None):
+        self.lms_type = lms_type
+        self.ots_type = ots_type
+        self.I = I
+        self.seed = seed
+        self.q = q
+        self.height = LMS_TYPES[lms_type]
+        self.nodes = nodes or self.build()
+
+    def build(self):
+        leaves = 1 << self.height
+        nodes = [b""] * (2 * leaves)
+        for q in range(leaves):
+            K = ots_public(self.I, self.seed, q, self.ots_type)
+            nodes[leaves + q] = H(self.I, u32(leaves + q), D_LEAF, K)
+        for r in range(leaves - 1, 0, -1):
+            nodes[r] = H(
+                self.I, u32(r), D_INTR, nodes[2 * r], nodes[2 * r + 1]
+            )
+        return nodes
+
+    def public(self):
+        return u32(self.lms_type) + u32(self.ots_type) + self.I + self.nodes[1]
+
+    def exhausted(self):
+        return self.q >= 1 << self.height
+
+    def child(self, lms_type, ots_type):
+        # Distinct from every chain index so no private value is reused
+        seed = H(self.I, u32(self.q), u16(0xFFFF), b"\xff", self.seed)
+        I = H(self.I, u32(self.q), u16(0xFFFE), b"\xff", self.seed)[:16]
+        return Tree(lms_type, ots_type, I, seed)
+
+    def sign(self, message):
+        assert not self.exhausted(), "LMS tree exhausted"
+        q = self.q
+        self.q += 1
+        r = (1 << self.height) + q
+        path = b"".join(self.nodes[(r >> i) ^ 1] for i in range(self.height))
+        ots = ots_sign(self.I, self.seed, q, self.ots_type, message)
+        return u32(q) + ots + u32(self.lms_type) + path
+
+    def to_json(self):
+        return {
+            "lms_type": self.lms_type,
+            "ots_type": self.ots_type,
+            "I": self.I.hex(),
+            "seed": self.seed.hex(),
+            "q": self.q,
+            "nodes": [x.hex() for x in self.nodes[1:]],
+        }
+
+    @staticmethod
+    def from_json(state):
+        return Tree(
+            state["lms_type"],
+            state["ots_type"],
+            bytes.fromhex(state["I"]),
+            bytes.fromhex(state["seed"]),
+            state["q"],
+            [b""] + [bytes.fromhex(x) for x in state["nodes"]],
+        )
+
+
+class PrivateKey:
+    """HSS private key, one tree per level with the signature each parent
+    made over its child's public key"""
+
+    def __init__(self, path, trees, signed_keys):
+        self.path = path
+        self.trees = trees
+        self.signed_keys = signed_keys
+
+    @staticmethod
+    def generate(path, heights, w):
+        ots_type = OTS_WIDTHS[w]
+        lms_types = [LMS_HEIGHTS[x] for x in heights]
+        top = Tree(lms_types[0], ots_type, os.urandom(16), os.urandom(N))
+        key = PrivateKey(path, [top], [])
+        for lms_type in lms_types[1:]:
+            key.grow(lms_type)
+        key.save()
+        return key
+
+    @staticmethod
+    def load(path):
+        with open(path) as file:
+            state = json.load(file)
+        return PrivateKey(
+            path,
+            [Tree.from_json(x) for x in state["trees"]],
+            [bytes.fromhex(x) for x in state["signed_keys"]],
+        )
+
+    def save(self):
+        state = {
+            "trees": [x.to_json() for x in self.trees],
+            "signed_keys": [x.hex() for x in self.signed_keys],
+        }
+        # Written to the side first so an interrupted save cannot lose the
+        # record of leaves already used
+        with open(f"{self.path}.tmp", "w") as file:
+            json.dump(state, file)
+            file.flush()
+            os.fsync(file.fileno())
+        os.replace(f"{self.path}.tmp", self.path)
+
+    def grow(self, lms_type):
+        parent = self.trees[-1]
+        child = parent.child(lms_type, parent.ots_type)
+        self.signed_keys.append(parent.sign(child.public()))
+        self.trees.append(child)
+
+    def public(self):
+        return u32(len(self.trees)) + self.trees[0].public()
+
+    def sign(self, message):
+        # Replace exhausted lower trees with fresh ones from the level above
+        lms_types = [x.lms_type for x in self.trees]
+        level = len(self.trees) - 1
+        while self.trees[level].exhausted():
+            if level == 0:
+                raise ValueError("HSS key exhausted, generate a new one")
+            level -= 1
+        if level < len(self.trees) - 1:
+            del self.trees[level + 1 :]
+            del self.signed_keys[level:]
+            for lms_type in lms_types[level + 1 :]:
+                self.grow(lms_type)
+
+        signature = self.trees[-1].sign(message)
+        self.save()
+        links = b"".join(
+            x + y.public() for x, y in zip(self.signed_keys, self.trees[1:])
+        )
+        return u32(len(self.trees) - 1) + links + signature
+
+
+def lms_signature_len(raw):
+    _, p, _ = OTS_TYPES[int.from_bytes(raw[4:8], "big")]
+    ots_len = 4 + N + p * N
+    height = LMS_TYPES[int.from_bytes(raw[4 + ots_len : 8 + ots_len], "big")]
+    return 4 + ots_len + 4 + height * N
+
+
+def verify_lms(public_key, signature, message):
+    lms_type = int.from_bytes(public_key[0:4], "big")
+    ots_type = int.from_bytes(public_key[4:8], "big")
+    I = public_key[8:24]
+    _, p, _ = OTS_TYPES[ots_type]
+    ots_end = 4 + 4 + N + p * N
+    if (
+        int.from_bytes(signature[4:8], "big") != ots_type
+        or int.from_bytes(signature[ots_end : ots_end + 4], "big") != lms_type
+        or len(signature) != lms_signature_len(signature)
+    ):
+        raise InvalidSignature
+    q = int.from_bytes(signature[0:4], "big")
+    height = LMS_TYPES[lms_type]
+    K = ots_candidate(I, q, ots_type, signature[4:ots_end], message)
+    r = (1 << height) + q
+    tmp = H(I, u32(r), D_LEAF, K)
+    path = signature[ots_end + 4 :]
+    for i in range(height):
+        sibling = path[i * N : (i + 1) * N]
+        if r & 1:
+            tmp = H(I, u32(r // 2), D_INTR, sibling, tmp)
+        else:
+            tmp = H(I, u32(r // 2), D_INTR, tmp, sibling)
+        r //= 2
+    if tmp != public_key[24:]:
+        raise InvalidSignature
+
+
+def verify(public_key, signature, message):
+    levels = int.from_bytes(public_key[0:4], "big")
+    if int.from_bytes(signature[0:4], "big") != levels - 1:
+        raise InvalidSignature
+    key = public_key[4:]
+    offset = 4
+    for _ in range(levels - 1):
+        end = offset + lms_signature_len(signature[offset:])
+        child = signature[end : end + PUBLIC_KEY_LEN - 4]
+        verify_lms(key, signature[offset:end], child)
+        key = child
+        offset = end + PUBLIC_KEY_LEN - 4
+    verify_lms(key, signature[offset:], message)
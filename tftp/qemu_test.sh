@@ -27,12 +27,16 @@ cp ./sentinel_boot /tftpboot/boot
 (cd /tftpboot/boot && gzip --decompress Image_signed.gz)
 (cd /tftpboot/boot && gzip --decompress rootfs.cpio.gz)
 
+# Anti-rollback floor, QEMU requires the backing file to fill the bank
+[ -f ./tftp/floor.img ] || truncate -s 32M ./tftp/floor.img
+
 printf -v QEMU_CMDLINE '%s' 'qemu-system-riscv64 -M virt ' \
-	'-cpu rv64,v=true,vlen=1024,rvv_ma_all_1s=true,rvv_ta_all_1s=true,zvbb=true,zvbc=true,zvknha=true '\
+	'-cpu rv64,v=true,vlen=1024,rvv_ma_all_1s=true,rvv_ta_all_1s=true,zvbb=true,zvbc=true,zvknhb=true,zvkned=true,zvkg=true '\
 	'-smp 1 -m 512 -nographic ' \
 	'-display none -serial pipe:/tmp/guest -s ' \
 	'-netdev tap,id=mynet0,ifname=tap0,script=no,downscript=no ' \
 	'-device e1000,netdev=mynet0,mac=52:55:00:d1:55:01 ' \
+	'-drive if=pflash,unit=1,format=raw,file=./tftp/floor.img ' \
 	'-kernel ./tftp/u-boot.bin'
 
 wait_for_line() {
@@ -61,7 +65,7 @@ printf "tftp 0x80100000 \${serverip}:sentinel_boot\n" >/tmp/guest.in
 
 wait_for_line "Bytes transferred" /tmp/guest.out
 echo "✅ Kernel transferred"
-printf "tftp 0x801fff00 \${serverip}:Image_signed\n" >/tmp/guest.in
+printf "tftp 0x801ff000 \${serverip}:Image_signed\n" >/tmp/guest.in
 
 wait_for_line "Bytes transferred" /tmp/guest.out
 echo "✅ Kernel transferred"
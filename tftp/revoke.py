@@ -0,0 +1,78 @@
+# Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
+
+import argparse
+import struct
+from Crypto.Hash import SHA256
+from keys import RSA_PADDINGS, Signer
+
+# Revocation update, must match src/verification/revocation.rs
+REVOCATION_MAGIC = b"SNTLRVKL"
+REVOCATION_VERSION = 1
+REVOCATION_MAX_LEN = 0x1000
+
+
+def fingerprint(public_bytes):
+    return SHA256.new(public_bytes).digest()
+
+
+def build_revocation(sig_alg, signer_public_bytes, sequence, fingerprints):
+    update = struct.pack(
+        "<8sHBBI8sII",
+        REVOCATION_MAGIC,
+        REVOCATION_VERSION,
+        sig_alg,
+        0,
+        len(fingerprints),
+        fingerprint(signer_public_bytes)[:8],
+        sequence,
+        0,
+    )
+    return update + b"".join(fingerprints)
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Sign a revocation update")
+    parser.add_argument(
+        "signer", help="key name as given to generate_*_keys.py"
+    )
+    parser.add_argument(
+        "revoked",
+        nargs="+",
+        help="revoked public key file or fingerprint",
+    )
+    parser.add_argument(
+        "--sequence",
+        type=int,
+        required=True,
+        help="higher than every earlier update, boards refuse older ones",
+    )
+    parser.add_argument(
+        "--rsa-padding",
+        choices=RSA_PADDINGS,
+        default="pss",
+        help="padding to sign with when the key is RSA",
+    )
+    args = parser.parse_args()
+
+    signer = Signer(f"{args.signer}_", args.rsa_padding)
+    public_bytes = signer.public_bytes
+
+    fingerprints = []
+    for item in args.revoked:
+        try:
+            with open(item, "rb") as file:
+                fingerprints.append(fingerprint(file.read()))
+        except FileNotFoundError:
+            fingerprints.append(bytes.fromhex(item))
+        assert len(fingerprints[-1]) == 32
+        print(f"Revoking: {fingerprints[-1].hex()}")
+
+    update = build_revocation(
+        signer.alg, public_bytes, args.sequence, fingerprints
+    )
+    signature = signer.sign(update)
+    assert len(update) + len(signature) <= REVOCATION_MAX_LEN
+    with open("revocation_signed", "wb") as file:
+        file.write(update)
+        file.write(signature)
+    print(f"Signed by: {fingerprint(public_bytes)[:8].hex()}")
@@ -0,0 +1,72 @@
+# Copyright (c) 2023-2024 Lawrence Hunter <lawrence.hunter@outlook.com
+
+import argparse
+import hashlib
+import hmac
+import os
+import struct
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+from keys import key_id
+
+# UDS record and derivation, must match src/verification/dice.rs
+UDS_MAGIC = b"SNTLUDS\0"
+UDS_LEN = 32
+KEY_SALT = b"SentinelBoot DICE"
+KEY_INFO = b"Key Pair"
+PAYLOAD_KEY_INFO = b"Payload Key"
+
+
+def kdf(ikm, salt, info):
+    prk = hmac.new(salt, ikm, hashlib.sha512).digest()
+    return hmac.new(prk, info + b"\x01", hashlib.sha512).digest()[:32]
+
+
+def layer_public_key(uds):
+    seed = kdf(uds, KEY_SALT, KEY_INFO)
+    private_key = Ed25519PrivateKey.from_private_bytes(seed)
+    return private_key.public_key().public_bytes_raw()
+
+
+def payload_key(uds):
+    """Key for kernels encrypted to the device provisioned with `uds`"""
+    return kdf(uds, KEY_SALT, PAYLOAD_KEY_INFO)
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(
+        description="Provision a device secret for SentinelBoot's DICE layer"
+    )
+    parser.add_argument(
+        "--secret",
+        help="existing secret file to wrap rather than a fresh random one",
+    )
+    parser.add_argument(
+        "--output",
+        required=True,
+        help="record to program into the board's device secret storage",
+    )
+    parser.add_argument(
+        "--device-key",
+        default="device_key",
+        help="key for hash.py --device-key, the UDS itself is not needed again",
+    )
+    args = parser.parse_args()
+
+    if args.secret:
+        with open(args.secret, "rb") as f:
+            uds = f.read()
+        assert len(uds) == UDS_LEN, f"secret must be {UDS_LEN} bytes"
+    else:
+        uds = os.urandom(UDS_LEN)
+
+    record = struct.pack("<8s", UDS_MAGIC) + uds
+    with open(args.output, "wb") as f:
+        f.write(record)
+
+    with open(args.device_key, "wb") as f:
+        f.write(payload_key(uds))
+
+    # Record this to recognise the device's attestation certificates
+    public_key = layer_public_key(uds)
+    print(f"Device ID: {key_id(public_key).hex()}")
+    print(f"Layer public key: {public_key.hex()}")